@@ -25,3 +25,28 @@ fn bench_addr_v6_canonical_str_parse(b: &mut test::Bencher) {
 fn bench_addr_v8_canonical_str_parse(b: &mut test::Bencher) {
     b.iter(|| MacAddr::from_str("12-34-56-78-9A-BC-DE-F0"))
 }
+
+#[bench]
+fn bench_v6_colon_str_parse(b: &mut test::Bencher) {
+    b.iter(|| MacAddr6::from_str(test::black_box("12:34:56:78:9a:bc")))
+}
+
+#[bench]
+fn bench_v6_cisco_str_parse(b: &mut test::Bencher) {
+    b.iter(|| MacAddr6::from_str(test::black_box("1234.5678.9abc")))
+}
+
+#[bench]
+fn bench_v6_bare_str_parse(b: &mut test::Bencher) {
+    b.iter(|| MacAddr6::from_str(test::black_box("123456789abc")))
+}
+
+#[bench]
+fn bench_v6_lenient_str_parse(b: &mut test::Bencher) {
+    b.iter(|| MacAddr6::parse_lenient(test::black_box(" 12:34-56:78-9a:bc\n")))
+}
+
+#[bench]
+fn bench_v6_invalid_str_parse(b: &mut test::Bencher) {
+    b.iter(|| MacAddr6::from_str(test::black_box("12-34-56-78-9A-BZ")))
+}
@@ -0,0 +1,15 @@
+//! Snapshot of the IEEE Registration Authority MA-L, MA-M, MA-S, IAB and CID registries
+//! used by the `"oui-db"` feature of the [macaddr] crate.
+//!
+//! It is kept in a separate crate, so the users not enabling the feature don't download the data.
+//! The snapshot format is an implementation detail of the `macaddr::vendor` module
+//! and can change in any release; use that module instead of this crate directly.
+//!
+//! [macaddr]: https://docs.rs/macaddr
+
+#![no_std]
+#![forbid(unsafe_code)]
+
+/// Snapshot generated by the `scripts/generate-oui-db.py` script of the `macaddr` repository,
+/// see it for the format description.
+pub static DATABASE: &[u8] = include_bytes!("oui.bin");
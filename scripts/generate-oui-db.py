@@ -0,0 +1,62 @@
+#!/usr/bin/env python3
+"""Generates the `macaddr-oui/src/oui.bin` snapshot of the IEEE Registration Authority registry.
+
+Usage:
+
+    curl -L -o oui.csv https://standards-oui.ieee.org/oui/oui.csv
+    curl -L -o mam.csv https://standards-oui.ieee.org/oui28/mam.csv
+    curl -L -o oui36.csv https://standards-oui.ieee.org/oui36/oui36.csv
+    curl -L -o iab.csv https://standards-oui.ieee.org/iab/iab.csv
+    curl -L -o cid.csv https://standards-oui.ieee.org/cid/cid.csv
+    scripts/generate-oui-db.py oui.csv mam.csv oui36.csv iab.csv cid.csv > macaddr-oui/src/oui.bin
+
+The snapshot starts with the `MOUI` magic and the big-endian `u32` number of entries,
+followed by the entries sorted in the ascending order and the organization names.
+
+Each entry is a big-endian `u64`, holding the first 36 bits of the assigned prefix in the top bits,
+then the 4-bit block code (0 for the 24-bit, 1 for the 28-bit and 2 for the 36-bit prefixes)
+and the 24-bit offset of the organization name from the start of the names.
+
+Names are deduplicated and stored as the length byte followed by the UTF-8 bytes.
+"""
+
+import csv
+import struct
+import sys
+
+CODES = {6: 0, 7: 1, 9: 2}
+
+
+def main(paths):
+    entries = {}
+    names = {}
+    blob = bytearray()
+
+    for path in paths:
+        with open(path, encoding="utf-8", newline="") as f:
+            for row in list(csv.reader(f))[1:]:
+                assignment, name = row[1].strip().upper(), " ".join(row[2].split())
+                code = CODES[len(assignment)]
+                prefix = int(assignment.ljust(9, "0"), 16)
+
+                encoded = name.encode("utf-8")[:255].decode("utf-8", "ignore").encode("utf-8")
+                if encoded not in names:
+                    names[encoded] = len(blob)
+                    blob.append(len(encoded))
+                    blob.extend(encoded)
+
+                entries.setdefault((prefix, code), names[encoded])
+
+    assert len(blob) < 1 << 24, "names do not fit into the 24-bit offsets"
+
+    out = bytearray(b"MOUI")
+    out.extend(struct.pack(">I", len(entries)))
+    for (prefix, code), offset in sorted(entries.items()):
+        out.extend(struct.pack(">Q", prefix << 28 | code << 24 | offset))
+    out.extend(blob)
+
+    sys.stdout.buffer.write(out)
+
+
+if __name__ == "__main__":
+    main(sys.argv[1:])
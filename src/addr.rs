@@ -1,15 +1,35 @@
-use core::{fmt, str::FromStr};
+use core::{cmp::Ordering, convert::TryFrom, fmt, ops::Range, str::FromStr};
 
-use crate::{parser, MacAddr6, MacAddr8, ParseError};
+#[cfg(feature = "alloc")]
+use alloc::string::{String, ToString};
+
+use crate::{
+    display::{
+        self, BufferTooSmall, DisplayBare, DisplayColon, DisplayDotted, DisplayDottedOctets, DisplayFormat,
+        DisplayHyphen,
+    },
+    find, parser, MacAddr6, MacAddr8, MacFormat, ParseError, ParseOptions,
+};
 
 /// A MAC address, either in *EUI-48* or *EUI-64* format.
 #[derive(Debug, Hash, Eq, PartialEq, Ord, PartialOrd, Copy, Clone)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    rkyv(derive(Debug, Hash, Eq, PartialEq, Ord, PartialOrd), compare(PartialEq))
+)]
 pub enum MacAddr {
     V6(MacAddr6),
     V8(MacAddr8),
 }
 
 impl MacAddr {
+    /// Maximum length of the `MacAddr` address written by the [`MacAddr::write_str`] method,
+    /// which is the length of the `MacAddr8` address.
+    ///
+    /// [`MacAddr::write_str`]: #method.write_str
+    pub const MAX_DISPLAY_LEN: usize = 23;
+
     /// Returns `true` if the address is `MacAddr6` address.
     ///
     /// ## Example
@@ -46,6 +66,279 @@ impl MacAddr {
         }
     }
 
+    /// Parses a `MacAddr` address from the ASCII text without checking it for UTF-8 validity first.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr;
+    /// let addr = MacAddr::parse_ascii(b"AC:DE:48:00:11:22:33:44");
+    ///
+    /// assert!(addr.unwrap().is_v8());
+    /// ```
+    pub fn parse_ascii(s: &[u8]) -> Result<MacAddr, ParseError> {
+        parser::Parser::from_ascii(s, ParseOptions::new()).read_addr()
+    }
+
+    /// Parses a `MacAddr` address from the string, accepting only the notations enabled in `options`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::{LetterCase, MacAddr, ParseOptions};
+    /// let options = ParseOptions::new().case(LetterCase::Lower);
+    ///
+    /// assert!(MacAddr::parse_with("ac:de:48:00:11:22:33:44", &options).is_ok());
+    /// assert!(MacAddr::parse_with("AC:DE:48:00:11:22:33:44", &options).is_err());
+    /// ```
+    pub fn parse_with(s: &str, options: &ParseOptions) -> Result<MacAddr, ParseError> {
+        parser::Parser::with_options(s, *options).read_addr()
+    }
+
+    /// Parses a `MacAddr` address from the string, tolerating the common formatting noise.
+    ///
+    /// See [`MacAddr6::parse_lenient`] for the accepted input.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::{MacAddr, MacAddr6};
+    /// let addr = MacAddr::parse_lenient("\tAC-DE:48-23:45-67 ");
+    ///
+    /// assert_eq!(addr, Ok(MacAddr::from([0xAC, 0xDE, 0x48, 0x23, 0x45, 0x67])));
+    /// ```
+    ///
+    /// [`MacAddr6::parse_lenient`]: struct.MacAddr6.html#method.parse_lenient
+    pub fn parse_lenient(s: &str) -> Result<MacAddr, ParseError> {
+        parser::Parser::lenient(s).read_addr()
+    }
+
+    /// Searches for the first `MacAddr` address in the free-form text.
+    ///
+    /// *EUI-64* addresses are preferred over the *EUI-48* ones,
+    /// see [`MacAddr6::find_in_str`] for the matching rules.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::{MacAddr, MacAddr8};
+    /// let text = "eui64 ac:de:48:00:11:22:33:44";
+    /// let addr = MacAddr8::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44);
+    ///
+    /// assert_eq!(MacAddr::find_in_str(text), Some((6..29, MacAddr::V8(addr))));
+    /// ```
+    ///
+    /// [`MacAddr6::find_in_str`]: struct.MacAddr6.html#method.find_in_str
+    pub fn find_in_str(s: &str) -> Option<(Range<usize>, MacAddr)> {
+        find::find(s, 0, MacAddr::match_at)
+    }
+
+    /// Returns an iterator over all `MacAddr` addresses in the free-form text.
+    ///
+    /// See [`MacAddr::find_in_str`] for the matching rules.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr;
+    /// let text = "ac:de:48:00:11:22 is not ac:de:48:00:11:22:33:44";
+    ///
+    /// let addrs: Vec<MacAddr> = MacAddr::find_all_in_str(text).map(|(_, addr)| addr).collect();
+    ///
+    /// assert_eq!(addrs.len(), 2);
+    /// assert!(addrs[0].is_v6());
+    /// assert!(addrs[1].is_v8());
+    /// ```
+    ///
+    /// [`MacAddr::find_in_str`]: #method.find_in_str
+    pub fn find_all_in_str(s: &str) -> impl Iterator<Item = (Range<usize>, MacAddr)> + '_ {
+        find::find_all(s, MacAddr::match_at)
+    }
+
+    fn match_at(s: &str, start: usize) -> Option<(Range<usize>, MacAddr)> {
+        if let Some((range, addr)) = find::match_at(s, start, 16, parser::Parser::read_v8_prefix) {
+            return Some((range, MacAddr::V8(addr)));
+        }
+
+        find::match_at(s, start, 12, parser::Parser::read_v6_prefix).map(|(range, addr)| (range, MacAddr::V6(addr)))
+    }
+
+    /// Writes the address in the colon-separated uppercase notation, as in `AC:DE:48:00:11:22`,
+    /// into the start of the `buf` buffer without allocating or using the `core::fmt` machinery.
+    ///
+    /// Returns the written part of the buffer or [`BufferTooSmall`] error
+    /// if the buffer is shorter than the written address.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::{MacAddr, MacAddr6};
+    /// let addr = MacAddr::from(MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22));
+    /// let mut buf = [0; MacAddr::MAX_DISPLAY_LEN];
+    ///
+    /// assert_eq!(addr.write_str(&mut buf), Ok("AC:DE:48:00:11:22"));
+    /// assert!(addr.write_str(&mut buf[..4]).is_err());
+    /// ```
+    ///
+    /// [`BufferTooSmall`]: struct.BufferTooSmall.html
+    pub fn write_str<'b>(&self, buf: &'b mut [u8]) -> Result<&'b str, BufferTooSmall> {
+        display::write_colon(buf, self.as_bytes())
+    }
+
+    /// Returns an object displaying the address with colons between every byte, as in `AC:DE:48:00:11:22`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::{MacAddr, MacAddr6};
+    /// let addr = MacAddr::from(MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22));
+    ///
+    /// assert_eq!(addr.display_colon().to_string(), "AC:DE:48:00:11:22");
+    /// assert_eq!(format!("{:#}", addr.display_colon()), "ac:de:48:00:11:22");
+    /// ```
+    pub fn display_colon(&self) -> DisplayColon<'_> {
+        DisplayColon(self.as_bytes())
+    }
+
+    /// Returns an object displaying the address with hyphens between every byte, as in `AC-DE-48-00-11-22`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::{MacAddr, MacAddr6};
+    /// let addr = MacAddr::from(MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22));
+    ///
+    /// assert_eq!(addr.display_hyphen().to_string(), "AC-DE-48-00-11-22");
+    /// ```
+    pub fn display_hyphen(&self) -> DisplayHyphen<'_> {
+        DisplayHyphen(self.as_bytes())
+    }
+
+    /// Returns an object displaying the address with dots between the 16-bit groups, as in `ACDE.4800.1122`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::{MacAddr, MacAddr6};
+    /// let addr = MacAddr::from(MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22));
+    ///
+    /// assert_eq!(addr.display_dotted().to_string(), "ACDE.4800.1122");
+    /// ```
+    pub fn display_dotted(&self) -> DisplayDotted<'_> {
+        DisplayDotted(self.as_bytes())
+    }
+
+    /// Returns an object displaying the address as the bare hex digits, as in `ACDE48001122`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::{MacAddr, MacAddr6};
+    /// let addr = MacAddr::from(MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22));
+    ///
+    /// assert_eq!(addr.display_bare().to_string(), "ACDE48001122");
+    /// ```
+    pub fn display_bare(&self) -> DisplayBare<'_> {
+        DisplayBare(self.as_bytes())
+    }
+
+    /// Returns the string with the address in the IEEE 802 canonical notation,
+    /// with hyphens between every byte, as in `AC-DE-48-00-11-22`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::{MacAddr, MacAddr6};
+    /// let addr = MacAddr::from(MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22));
+    ///
+    /// assert_eq!(addr.to_canonical_string(), "AC-DE-48-00-11-22");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_canonical_string(&self) -> String {
+        self.display_hyphen().to_string()
+    }
+
+    /// Returns the string with the address with colons between every byte, as in `AC:DE:48:00:11:22`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::{MacAddr, MacAddr6};
+    /// let addr = MacAddr::from(MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22));
+    ///
+    /// assert_eq!(addr.to_colon_string(), "AC:DE:48:00:11:22");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_colon_string(&self) -> String {
+        self.display_colon().to_string()
+    }
+
+    /// Returns the string with the address as the bare hex digits, as in `ACDE48001122`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::{MacAddr, MacAddr6};
+    /// let addr = MacAddr::from(MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22));
+    ///
+    /// assert_eq!(addr.to_hex_string(), "ACDE48001122");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_hex_string(&self) -> String {
+        self.display_bare().to_string()
+    }
+
+    /// Returns the string with the address with dots between the 16-bit groups, as in `ACDE.4800.1122`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::{MacAddr, MacAddr6};
+    /// let addr = MacAddr::from(MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22));
+    ///
+    /// assert_eq!(addr.to_dotted_string(), "ACDE.4800.1122");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_dotted_string(&self) -> String {
+        self.display_dotted().to_string()
+    }
+
+    /// Returns an object displaying the address in the custom notation.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::{MacAddr, MacAddr6, MacFormat};
+    /// let addr = MacAddr::from(MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22));
+    ///
+    /// assert_eq!(addr.format(&MacFormat::DOTTED).to_string(), "ACDE.4800.1122");
+    /// ```
+    pub fn format(&self, format: &MacFormat) -> DisplayFormat<'_> {
+        DisplayFormat {
+            bytes: self.as_bytes(),
+            format: *format,
+        }
+    }
+
+    /// Returns an object displaying the address with dots between every byte, as in `AC.DE.48.00.11.22`.
+    ///
+    /// Displayed address can be parsed back with [`MacAddr::parse_lenient`].
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::{MacAddr, MacAddr6};
+    /// let addr = MacAddr::from(MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22));
+    ///
+    /// assert_eq!(addr.display_dotted_octets().to_string(), "AC.DE.48.00.11.22");
+    /// assert_eq!(MacAddr::parse_lenient("AC.DE.48.00.11.22"), Ok(addr));
+    /// ```
+    ///
+    /// [`MacAddr::parse_lenient`]: #method.parse_lenient
+    pub fn display_dotted_octets(&self) -> DisplayDottedOctets<'_> {
+        DisplayDottedOctets(self.as_bytes())
+    }
+
     /// Converts a `MacAddr` address to a byte slice.
     ///
     /// Length of the returned slice is depends on the enum member used.
@@ -78,6 +371,72 @@ impl From<MacAddr8> for MacAddr {
     }
 }
 
+/// Implements the comparisons between `MacAddr` and the concrete address type in both directions,
+/// consistent with the `MacAddr` ordering, where any `V6` address is less than any `V8` one.
+macro_rules! impl_variant_cmp {
+    ($(#[$attr:meta])* $name:ident) => {
+        $(#[$attr])*
+        impl PartialEq<$name> for MacAddr {
+            fn eq(&self, other: &$name) -> bool {
+                *self == MacAddr::from(*other)
+            }
+        }
+
+        impl PartialEq<MacAddr> for $name {
+            fn eq(&self, other: &MacAddr) -> bool {
+                MacAddr::from(*self) == *other
+            }
+        }
+
+        impl PartialOrd<$name> for MacAddr {
+            fn partial_cmp(&self, other: &$name) -> Option<Ordering> {
+                self.partial_cmp(&MacAddr::from(*other))
+            }
+        }
+
+        impl PartialOrd<MacAddr> for $name {
+            fn partial_cmp(&self, other: &MacAddr) -> Option<Ordering> {
+                MacAddr::from(*self).partial_cmp(other)
+            }
+        }
+    };
+}
+
+impl_variant_cmp!(
+    /// `MacAddr` can be compared with the `MacAddr6` and `MacAddr8` addresses directly,
+    /// an address is never equal to the other variant.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use macaddr::{MacAddr, MacAddr6, MacAddr8};
+    /// let addr = MacAddr::from([0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22]);
+    ///
+    /// assert_eq!(addr, MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22));
+    /// assert_eq!(MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22), addr);
+    /// assert_ne!(addr, MacAddr8::nil());
+    /// assert!(addr < MacAddr8::nil());
+    /// ```
+    MacAddr6
+);
+impl_variant_cmp!(MacAddr8);
+
+/// `MacAddr` can be parsed from any notation accepted by [MacAddr6] and [MacAddr8],
+/// the enum member is detected from the amount of bytes in the string.
+///
+/// # Example
+///
+/// ```
+/// # use macaddr::MacAddr;
+/// let addr: MacAddr = "AB-0D-EF-12-34-56".parse().unwrap();
+/// assert!(addr.is_v6());
+///
+/// let addr: MacAddr = "ab0d.ef12.3456.789a".parse().unwrap();
+/// assert!(addr.is_v8());
+/// ```
+///
+/// [MacAddr6]: struct.MacAddr6.html
+/// [MacAddr8]: struct.MacAddr8.html
 impl FromStr for MacAddr {
     type Err = ParseError;
 
@@ -86,6 +445,23 @@ impl FromStr for MacAddr {
     }
 }
 
+impl<'a> TryFrom<&'a str> for MacAddr {
+    type Error = ParseError;
+
+    fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+        parser::Parser::new(s).read_addr()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl TryFrom<String> for MacAddr {
+    type Error = ParseError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        parser::Parser::new(&s).read_addr()
+    }
+}
+
 impl From<[u8; 6]> for MacAddr {
     fn from(bytes: [u8; 6]) -> Self {
         MacAddr::V6(MacAddr6::from(bytes))
@@ -98,6 +474,270 @@ impl From<[u8; 8]> for MacAddr {
     }
 }
 
+impl<'a> TryFrom<&'a [u8]> for MacAddr {
+    type Error = ParseError;
+
+    fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        match bytes.len() {
+            6 => MacAddr6::try_from(bytes).map(MacAddr::V6),
+            8 => MacAddr8::try_from(bytes).map(MacAddr::V8),
+            len => Err(ParseError::InvalidLength(len)),
+        }
+    }
+}
+
+#[cfg(feature = "prost")]
+impl From<MacAddr> for bytes::Bytes {
+    fn from(addr: MacAddr) -> Self {
+        bytes::Bytes::copy_from_slice(addr.as_bytes())
+    }
+}
+
+/// Converts the protobuf `bytes` field value, detecting the variant from its length
+/// and failing with [`ParseError::InvalidLength`] if it is neither 6 nor 8 bytes long.
+#[cfg(feature = "prost")]
+impl TryFrom<bytes::Bytes> for MacAddr {
+    type Error = ParseError;
+
+    fn try_from(bytes: bytes::Bytes) -> Result<Self, Self::Error> {
+        MacAddr::try_from(bytes.as_ref())
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for MacAddr {
+    fn format(&self, f: defmt::Formatter) {
+        match self {
+            MacAddr::V6(v6) => defmt::Format::format(v6, f),
+            MacAddr::V8(v8) => defmt::Format::format(v8, f),
+        }
+    }
+}
+
+#[cfg(feature = "ufmt")]
+impl ufmt::uDisplay for MacAddr {
+    fn fmt<W: ufmt::uWrite + ?Sized>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error> {
+        match self {
+            MacAddr::V6(v6) => ufmt::uDisplay::fmt(v6, f),
+            MacAddr::V8(v8) => ufmt::uDisplay::fmt(v8, f),
+        }
+    }
+}
+
+#[cfg(feature = "ufmt")]
+impl ufmt::uDebug for MacAddr {
+    fn fmt<W: ufmt::uWrite + ?Sized>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error> {
+        match self {
+            MacAddr::V6(v6) => ufmt::uwrite!(f, "V6({:?})", v6),
+            MacAddr::V8(v8) => ufmt::uwrite!(f, "V8({:?})", v8),
+        }
+    }
+}
+
+/// Addresses are serialized as the Borsh enum, the variant index (`0` for `V6` and `1` for `V8`)
+/// followed by the address bytes.
+#[cfg(feature = "borsh")]
+impl borsh::BorshSerialize for MacAddr {
+    fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+        match self {
+            MacAddr::V6(v6) => {
+                writer.write_all(&[0])?;
+                v6.serialize(writer)
+            }
+            MacAddr::V8(v8) => {
+                writer.write_all(&[1])?;
+                v8.serialize(writer)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl borsh::BorshDeserialize for MacAddr {
+    fn deserialize_reader<R: borsh::io::Read>(reader: &mut R) -> borsh::io::Result<Self> {
+        match u8::deserialize_reader(reader)? {
+            0 => MacAddr6::deserialize_reader(reader).map(MacAddr::V6),
+            1 => MacAddr8::deserialize_reader(reader).map(MacAddr::V8),
+            _ => Err(borsh::io::Error::new(
+                borsh::io::ErrorKind::InvalidData,
+                "Unexpected variant index of the MAC address",
+            )),
+        }
+    }
+}
+
+/// Addresses are written as the variant index byte (`0` for `V6` and `1` for `V8`)
+/// followed by the address bytes.
+#[cfg(feature = "speedy")]
+impl<'a, C: speedy::Context> speedy::Readable<'a, C> for MacAddr {
+    fn read_from<R: speedy::Reader<'a, C>>(reader: &mut R) -> Result<Self, C::Error> {
+        match reader.read_u8()? {
+            0 => reader.read_value().map(MacAddr::V6),
+            1 => reader.read_value().map(MacAddr::V8),
+            _ => Err(speedy::Error::custom("Unexpected variant index of the MAC address").into()),
+        }
+    }
+
+    fn minimum_bytes_needed() -> usize {
+        1 + 6
+    }
+}
+
+#[cfg(feature = "speedy")]
+impl<C: speedy::Context> speedy::Writable<C> for MacAddr {
+    fn write_to<W: ?Sized + speedy::Writer<C>>(&self, writer: &mut W) -> Result<(), C::Error> {
+        match self {
+            MacAddr::V6(v6) => {
+                writer.write_u8(0)?;
+                writer.write_value(v6)
+            }
+            MacAddr::V8(v8) => {
+                writer.write_u8(1)?;
+                writer.write_value(v8)
+            }
+        }
+    }
+
+    fn bytes_needed(&self) -> Result<usize, C::Error> {
+        Ok(1 + self.as_bytes().len())
+    }
+}
+
+/// Addresses are encoded as the variant index byte (`0` for `V6` and `1` for `V8`)
+/// followed by the address bytes, regardless of the integer encoding configured.
+#[cfg(feature = "bincode")]
+impl bincode::Encode for MacAddr {
+    fn encode<E: bincode::enc::Encoder>(&self, encoder: &mut E) -> Result<(), bincode::error::EncodeError> {
+        match self {
+            MacAddr::V6(v6) => {
+                0u8.encode(encoder)?;
+                v6.encode(encoder)
+            }
+            MacAddr::V8(v8) => {
+                1u8.encode(encoder)?;
+                v8.encode(encoder)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "bincode")]
+impl<Context> bincode::Decode<Context> for MacAddr {
+    fn decode<D: bincode::de::Decoder<Context = Context>>(
+        decoder: &mut D,
+    ) -> Result<Self, bincode::error::DecodeError> {
+        match u8::decode(decoder)? {
+            0 => MacAddr6::decode(decoder).map(MacAddr::V6),
+            1 => MacAddr8::decode(decoder).map(MacAddr::V8),
+            found => Err(bincode::error::DecodeError::UnexpectedVariant {
+                type_name: "MacAddr",
+                allowed: &bincode::error::AllowedEnumVariants::Range { min: 0, max: 1 },
+                found: u32::from(found),
+            }),
+        }
+    }
+}
+
+#[cfg(feature = "bincode")]
+bincode::impl_borrow_decode!(MacAddr);
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for MacAddr {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        if u.arbitrary()? {
+            u.arbitrary().map(MacAddr::V8)
+        } else {
+            u.arbitrary().map(MacAddr::V6)
+        }
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (1 + 6, Some(1 + 8))
+    }
+}
+
+/// Addresses are shrunk towards the nil address of the same variant.
+#[cfg(feature = "quickcheck")]
+impl quickcheck::Arbitrary for MacAddr {
+    fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+        if bool::arbitrary(g) {
+            MacAddr::V8(MacAddr8::arbitrary(g))
+        } else {
+            MacAddr::V6(MacAddr6::arbitrary(g))
+        }
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        match self {
+            MacAddr::V6(v6) => Box::new(v6.shrink().map(MacAddr::V6)),
+            MacAddr::V8(v8) => Box::new(v8.shrink().map(MacAddr::V8)),
+        }
+    }
+}
+
+/// Addresses are stored in the PostgreSQL `MACADDR` and `MACADDR8` columns,
+/// depending on the variant.
+#[cfg(feature = "postgres")]
+impl<'a> postgres_types::FromSql<'a> for MacAddr {
+    fn from_sql(
+        ty: &postgres_types::Type,
+        raw: &'a [u8],
+    ) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+        match *ty {
+            postgres_types::Type::MACADDR => Ok(MacAddr::V6(MacAddr6::from_sql(ty, raw)?)),
+            _ => Ok(MacAddr::V8(MacAddr8::from_sql(ty, raw)?)),
+        }
+    }
+
+    postgres_types::accepts!(MACADDR, MACADDR8);
+}
+
+#[cfg(feature = "postgres")]
+impl postgres_types::ToSql for MacAddr {
+    fn to_sql(
+        &self,
+        ty: &postgres_types::Type,
+        out: &mut bytes::BytesMut,
+    ) -> Result<postgres_types::IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        match (self, ty) {
+            (MacAddr::V6(v6), &postgres_types::Type::MACADDR) => v6.to_sql(ty, out),
+            (MacAddr::V8(v8), &postgres_types::Type::MACADDR8) => v8.to_sql(ty, out),
+            _ => Err(format!("{} address can't be stored in the {} column", self, ty).into()),
+        }
+    }
+
+    postgres_types::accepts!(MACADDR, MACADDR8);
+    postgres_types::to_sql_checked!();
+}
+
+/// Addresses are stored in SQLite as the 6 or 8 bytes blobs, depending on the variant;
+/// the text values in any notation accepted by the `FromStr` implementation are read too.
+#[cfg(feature = "rusqlite")]
+impl rusqlite::types::ToSql for MacAddr {
+    fn to_sql(&self) -> rusqlite::Result<rusqlite::types::ToSqlOutput<'_>> {
+        Ok(rusqlite::types::ToSqlOutput::Borrowed(rusqlite::types::ValueRef::Blob(self.as_bytes())))
+    }
+}
+
+#[cfg(feature = "rusqlite")]
+impl rusqlite::types::FromSql for MacAddr {
+    fn column_result(value: rusqlite::types::ValueRef<'_>) -> rusqlite::types::FromSqlResult<Self> {
+        match value {
+            rusqlite::types::ValueRef::Blob(blob) => {
+                MacAddr::try_from(blob).map_err(|_| rusqlite::types::FromSqlError::InvalidBlobSize {
+                    // Reporting the closest of the supported sizes.
+                    expected_size: if blob.len() < 7 { 6 } else { 8 },
+                    blob_size: blob.len(),
+                })
+            }
+            rusqlite::types::ValueRef::Text(text) => {
+                MacAddr::parse_ascii(text).map_err(|err| rusqlite::types::FromSqlError::Other(Box::new(err)))
+            }
+            _ => Err(rusqlite::types::FromSqlError::InvalidType),
+        }
+    }
+}
+
 impl fmt::Display for MacAddr {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
@@ -106,3 +746,39 @@ impl fmt::Display for MacAddr {
         }
     }
 }
+
+impl fmt::LowerHex for MacAddr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MacAddr::V6(v6) => fmt::LowerHex::fmt(v6, f),
+            MacAddr::V8(v8) => fmt::LowerHex::fmt(v8, f),
+        }
+    }
+}
+
+impl fmt::UpperHex for MacAddr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MacAddr::V6(v6) => fmt::UpperHex::fmt(v6, f),
+            MacAddr::V8(v8) => fmt::UpperHex::fmt(v8, f),
+        }
+    }
+}
+
+impl fmt::Binary for MacAddr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MacAddr::V6(v6) => fmt::Binary::fmt(v6, f),
+            MacAddr::V8(v8) => fmt::Binary::fmt(v8, f),
+        }
+    }
+}
+
+impl fmt::Octal for MacAddr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MacAddr::V6(v6) => fmt::Octal::fmt(v6, f),
+            MacAddr::V8(v8) => fmt::Octal::fmt(v8, f),
+        }
+    }
+}
@@ -1,14 +1,69 @@
-use core::{fmt, str::FromStr};
+use core::{
+    convert::TryFrom,
+    fmt,
+    hash::{Hash, Hasher},
+    net::{Ipv4Addr, Ipv6Addr},
+    ops::Range,
+    str::FromStr,
+};
 
-use crate::parser;
+#[cfg(feature = "alloc")]
+use alloc::string::{String, ToString};
+
+use crate::{
+    block,
+    display::{
+        self, BufferTooSmall, DisplayBare, DisplayColon, DisplayDotted, DisplayDottedOctets, DisplayFormat,
+        DisplayHyphen, DisplaySnmp,
+    },
+    find, parser, AssignmentBlock, IanaAssignment, MacAddr6Range, MacAddr8, MacFormat, ParseError, ParseOptions,
+    SlapQuadrant,
+};
+
+/// Offset basis of the 64-bit FNV-1a hash function.
+const FNV_OFFSET_BASIS: u64 = 0xCBF2_9CE4_8422_2325;
+
+/// Prime of the 64-bit FNV-1a hash function.
+const FNV_PRIME: u64 = 0x0000_0100_0000_01B3;
+
+/// Continues the 64-bit FNV-1a `hash` with the `bytes`.
+const fn fnv1a(mut hash: u64, bytes: &[u8]) -> u64 {
+    let mut idx = 0;
+    while idx < bytes.len() {
+        hash ^= bytes[idx] as u64;
+        hash = hash.wrapping_mul(FNV_PRIME);
+        idx += 1;
+    }
+
+    hash
+}
 
 /// MAC address in *EUI-48* format.
 #[repr(C)]
-#[derive(Debug, Default, Hash, Eq, PartialEq, Ord, PartialOrd, Copy, Clone)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Default, Eq, PartialEq, Ord, PartialOrd, Copy, Clone)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    rkyv(derive(Debug, Hash, Eq, PartialEq, Ord, PartialOrd), compare(PartialEq))
+)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::KnownLayout, zerocopy::Immutable, zerocopy::Unaligned)
+)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[cfg_attr(
+    feature = "diesel",
+    derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow),
+    diesel(sql_type = diesel::sql_types::MacAddr)
+)]
 pub struct MacAddr6([u8; 6]);
 
 impl MacAddr6 {
+    /// Maximum length of the `MacAddr6` address written by the [`MacAddr6::write_str`] method.
+    ///
+    /// [`MacAddr6::write_str`]: #method.write_str
+    pub const MAX_DISPLAY_LEN: usize = 17;
+
     /// Creates a new `MacAddr6` address from the bytes.
     ///
     /// ## Example
@@ -138,92 +193,2332 @@ impl MacAddr6 {
         self.0[0] & 1 << 1 == 2
     }
 
-    /// Converts a `MacAddr6` address to a byte slice.
+    /// Returns `true` if the address is in one of the ranges reserved for the documentation examples by RFC 7042,
+    /// `00:00:5E:00:53:00` to `00:00:5E:00:53:FF` for the unicast
+    /// and `01:00:5E:90:10:00` to `01:00:5E:90:10:FF` for the multicast addresses.
     ///
     /// ## Example
     ///
     /// ```rust
     /// # use macaddr::MacAddr6;
-    /// let addr = MacAddr6::new(0xAC, 0xDE, 0x48, 0x23, 0x45, 0x67);
+    /// let addr = MacAddr6::new(0x00, 0x00, 0x5E, 0x00, 0x53, 0x01);
     ///
-    /// assert_eq!(addr.as_bytes(), &[0xAC, 0xDE, 0x48, 0x23, 0x45, 0x67]);
+    /// assert_eq!(addr.is_documentation(), true);
+    /// assert_eq!(MacAddr6::new(0x00, 0x00, 0x5E, 0x00, 0x54, 0x01).is_documentation(), false);
     /// ```
-    pub fn as_bytes(&self) -> &[u8] {
-        &self.0
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    pub const fn is_documentation(&self) -> bool {
+        matches!(self.0, [0x00, 0x00, 0x5E, 0x00, 0x53, _] | [0x01, 0x00, 0x5E, 0x90, 0x10, _])
     }
 
-    /// Consumes `MacAddr6` address and returns raw bytes array.
+    /// Returns `true` if the address is locally administered and unicast,
+    /// like the randomized addresses used by the mobile devices to prevent the tracking.
+    ///
+    /// Such addresses can't be attributed to a vendor and are usually changing over time,
+    /// but manually assigned locally administered addresses are matched too.
     ///
     /// ## Example
     ///
     /// ```rust
     /// # use macaddr::MacAddr6;
-    /// let addr = MacAddr6::new(0xAC, 0xDE, 0x48, 0x23, 0x45, 0x67);
+    /// assert_eq!(MacAddr6::new(0xDA, 0xA1, 0x19, 0x6B, 0x22, 0x0E).is_randomized(), true);
+    /// assert_eq!(MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22).is_randomized(), false);
+    /// ```
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    pub const fn is_randomized(&self) -> bool {
+        self.is_local() && self.is_unicast()
+    }
+
+    /// Returns `true` if the address is in the `01:00:5E:00:00:00/25` range
+    /// of the IPv4 multicast group addresses (RFC 1112, section 6.4).
     ///
-    /// assert_eq!(addr.into_array(), [0xAC, 0xDE, 0x48, 0x23, 0x45, 0x67]);
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// let addr = MacAddr6::new(0x01, 0x00, 0x5E, 0x00, 0x00, 0xFB);
+    ///
+    /// assert_eq!(addr.is_ipv4_multicast(), true);
+    /// assert_eq!(MacAddr6::new(0x01, 0x00, 0x5E, 0x80, 0x00, 0xFB).is_ipv4_multicast(), false);
     /// ```
-    pub const fn into_array(self) -> [u8; 6] {
-        self.0
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    pub const fn is_ipv4_multicast(&self) -> bool {
+        matches!(self.0, [0x01, 0x00, 0x5E, d, _, _] if d & 0x80 == 0)
     }
-}
 
-impl FromStr for MacAddr6 {
-    type Err = parser::ParseError;
+    /// Returns `true` if the address is in the `33:33:00:00:00:00/16` range
+    /// of the IPv6 multicast group addresses (RFC 2464, section 7).
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// let addr = MacAddr6::new(0x33, 0x33, 0x00, 0x00, 0x00, 0x01);
+    ///
+    /// assert_eq!(addr.is_ipv6_multicast(), true);
+    /// ```
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    pub const fn is_ipv6_multicast(&self) -> bool {
+        matches!(self.0, [0x33, 0x33, _, _, _, _])
+    }
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        parser::Parser::new(s).read_v6_addr()
+    /// Returns `true` if the address is the IEEE 802.1D Bridge Group address `01:80:C2:00:00:00`,
+    /// the destination of the Spanning Tree Protocol BPDUs.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::{consts, MacAddr6};
+    /// assert_eq!(consts::STP.is_stp(), true);
+    /// assert_eq!(consts::LLDP_NEAREST_BRIDGE.is_stp(), false);
+    /// ```
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    pub const fn is_stp(&self) -> bool {
+        matches!(self.0, [0x01, 0x80, 0xC2, 0x00, 0x00, 0x00])
     }
-}
 
-impl From<[u8; 6]> for MacAddr6 {
-    fn from(bytes: [u8; 6]) -> Self {
-        MacAddr6(bytes)
+    /// Returns `true` if the address is one of the IEEE 802.1AB LLDP destinations:
+    /// Nearest Bridge `01:80:C2:00:00:0E`, Nearest non-TPMR Bridge `01:80:C2:00:00:03`
+    /// or Nearest Customer Bridge `01:80:C2:00:00:00`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// assert_eq!(MacAddr6::new(0x01, 0x80, 0xC2, 0x00, 0x00, 0x0E).is_lldp(), true);
+    /// assert_eq!(MacAddr6::new(0x01, 0x80, 0xC2, 0x00, 0x00, 0x01).is_lldp(), false);
+    /// ```
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    pub const fn is_lldp(&self) -> bool {
+        matches!(self.0, [0x01, 0x80, 0xC2, 0x00, 0x00, 0x00 | 0x03 | 0x0E])
     }
-}
 
-impl AsRef<[u8]> for MacAddr6 {
-    fn as_ref(&self) -> &[u8] {
-        &self.0
+    /// Returns `true` if the address is one of the IEEE 1588 PTP over Ethernet destinations:
+    /// primary `01:1B:19:00:00:00` or peer delay `01:80:C2:00:00:0E`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// assert_eq!(MacAddr6::new(0x01, 0x1B, 0x19, 0x00, 0x00, 0x00).is_ptp(), true);
+    /// assert_eq!(MacAddr6::new(0x01, 0x80, 0xC2, 0x00, 0x00, 0x0E).is_ptp(), true);
+    /// ```
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    pub const fn is_ptp(&self) -> bool {
+        matches!(self.0, [0x01, 0x1B, 0x19, 0x00, 0x00, 0x00] | [0x01, 0x80, 0xC2, 0x00, 0x00, 0x0E])
     }
-}
 
-impl AsMut<[u8]> for MacAddr6 {
-    fn as_mut(&mut self) -> &mut [u8] {
-        &mut self.0
+    /// Returns `true` if the address is the VRRP virtual router address,
+    /// `00:00:5E:00:01:xx` for IPv4 or `00:00:5E:00:02:xx` for IPv6 (RFC 5798, section 7.3).
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// assert_eq!(MacAddr6::new(0x00, 0x00, 0x5E, 0x00, 0x01, 0x2A).is_vrrp(), true);
+    /// assert_eq!(MacAddr6::new(0x00, 0x00, 0x5E, 0x00, 0x03, 0x2A).is_vrrp(), false);
+    /// ```
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    pub const fn is_vrrp(&self) -> bool {
+        matches!(self.0, [0x00, 0x00, 0x5E, 0x00, 0x01 | 0x02, _])
     }
-}
 
-/// `MacAddr6` can be displayed in different formats.
-///
-/// # Example
-///
-/// ```
-/// # use macaddr::MacAddr6;
-/// let addr = MacAddr6::new(0xab, 0x0d, 0xef, 0x12, 0x34, 0x56);
-///
-/// assert_eq!(&format!("{}",    addr), "AB:0D:EF:12:34:56");
-/// assert_eq!(&format!("{:-}",  addr), "AB-0D-EF-12-34-56");
-/// assert_eq!(&format!("{:#}",  addr), "AB0.DEF.123.456");
-/// ```
-impl fmt::Display for MacAddr6 {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        if f.sign_minus() {
-            f.write_fmt(format_args!(
-                "{:02X}-{:02X}-{:02X}-{:02X}-{:02X}-{:02X}",
-                self.0[0], self.0[1], self.0[2], self.0[3], self.0[4], self.0[5],
-            ))
-        } else if f.alternate() {
-            let p1 = u16::from(self.0[0]) * 16 + u16::from(self.0[1] / 16);
-            let p2 = u16::from(self.0[1] % 16) * 256 + u16::from(self.0[2]);
-            let p3 = u16::from(self.0[3]) * 16 + u16::from(self.0[4] / 16);
-            let p4 = u16::from(self.0[4] % 16) * 256 + u16::from(self.0[5]);
-
-            f.write_fmt(format_args!("{:03X}.{:03X}.{:03X}.{:03X}", p1, p2, p3, p4,))
+    /// Returns the Virtual Router Identifier of the VRRP virtual router address.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// assert_eq!(MacAddr6::new(0x00, 0x00, 0x5E, 0x00, 0x01, 0x2A).vrrp_group_id(), Some(42));
+    /// assert_eq!(MacAddr6::broadcast().vrrp_group_id(), None);
+    /// ```
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    pub const fn vrrp_group_id(&self) -> Option<u8> {
+        if self.is_vrrp() {
+            Some(self.0[5])
         } else {
-            f.write_fmt(format_args!(
-                "{:02X}:{:02X}:{:02X}:{:02X}:{:02X}:{:02X}",
-                self.0[0], self.0[1], self.0[2], self.0[3], self.0[4], self.0[5],
-            ))
+            None
+        }
+    }
+
+    /// Returns `true` if the address is the Cisco HSRP virtual router address:
+    /// `00:00:0C:07:AC:xx` for version 1, `00:00:0C:9F:Fx:xx` for version 2
+    /// or `00:05:73:A0:0x:xx` for IPv6.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// assert_eq!(MacAddr6::new(0x00, 0x00, 0x0C, 0x07, 0xAC, 0x01).is_hsrp(), true);
+    /// assert_eq!(MacAddr6::new(0x00, 0x00, 0x0C, 0x9F, 0xF1, 0x23).is_hsrp(), true);
+    /// assert_eq!(MacAddr6::new(0x00, 0x00, 0x0C, 0x07, 0xAD, 0x01).is_hsrp(), false);
+    /// ```
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    pub const fn is_hsrp(&self) -> bool {
+        match self.0 {
+            [0x00, 0x00, 0x0C, 0x07, 0xAC, _] => true,
+            [0x00, 0x00, 0x0C, 0x9F, e, _] => e & 0xF0 == 0xF0,
+            [0x00, 0x05, 0x73, 0xA0, e, _] => e & 0xF0 == 0x00,
+            _ => false,
+        }
+    }
+
+    /// Returns the HSRP group number of the Cisco HSRP virtual router address,
+    /// which is up to 255 for version 1 and up to 4095 for version 2 and IPv6.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// assert_eq!(MacAddr6::new(0x00, 0x00, 0x0C, 0x07, 0xAC, 0x2A).hsrp_group(), Some(42));
+    /// assert_eq!(MacAddr6::new(0x00, 0x00, 0x0C, 0x9F, 0xF1, 0x23).hsrp_group(), Some(0x123));
+    /// assert_eq!(MacAddr6::broadcast().hsrp_group(), None);
+    /// ```
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    pub const fn hsrp_group(&self) -> Option<u16> {
+        match self.0 {
+            [0x00, 0x00, 0x0C, 0x07, 0xAC, f] => Some(f as u16),
+            [.., e, f] if self.is_hsrp() => Some(u16::from_be_bytes([e & 0x0F, f])),
+            _ => None,
+        }
+    }
+
+    /// Creates the VRRP virtual router address `00:00:5E:00:01:xx` for IPv4 (RFC 5798, section 7.3)
+    /// with the Virtual Router Identifier, which is the inverse of the [`vrrp_group_id`](#method.vrrp_group_id) method.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// let addr = MacAddr6::vrrp(42);
+    ///
+    /// assert_eq!(addr, MacAddr6::new(0x00, 0x00, 0x5E, 0x00, 0x01, 0x2A));
+    /// assert_eq!(addr.vrrp_group_id(), Some(42));
+    /// ```
+    pub const fn vrrp(group: u8) -> MacAddr6 {
+        MacAddr6::new(0x00, 0x00, 0x5E, 0x00, 0x01, group)
+    }
+
+    /// Creates the VRRP virtual router address `00:00:5E:00:02:xx` for IPv6 (RFC 5798, section 7.3)
+    /// with the Virtual Router Identifier.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// assert_eq!(MacAddr6::vrrp6(42), MacAddr6::new(0x00, 0x00, 0x5E, 0x00, 0x02, 0x2A));
+    /// ```
+    pub const fn vrrp6(group: u8) -> MacAddr6 {
+        MacAddr6::new(0x00, 0x00, 0x5E, 0x00, 0x02, group)
+    }
+
+    /// Creates the CARP virtual router address with the Virtual Host ID, which is the same
+    /// as the VRRP address for IPv4 `00:00:5E:00:01:xx`, as used by the OpenBSD and FreeBSD CARP implementations.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// assert_eq!(MacAddr6::carp(7), MacAddr6::vrrp(7));
+    /// ```
+    pub const fn carp(vhid: u8) -> MacAddr6 {
+        MacAddr6::vrrp(vhid)
+    }
+
+    /// Creates the Cisco HSRP version 1 virtual router address `00:00:0C:07:AC:xx` with the group number.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// let addr = MacAddr6::hsrp_v1(42);
+    ///
+    /// assert_eq!(addr, MacAddr6::new(0x00, 0x00, 0x0C, 0x07, 0xAC, 0x2A));
+    /// assert_eq!(addr.hsrp_group(), Some(42));
+    /// ```
+    pub const fn hsrp_v1(group: u8) -> MacAddr6 {
+        MacAddr6::new(0x00, 0x00, 0x0C, 0x07, 0xAC, group)
+    }
+
+    /// Creates the Cisco HSRP version 2 virtual router address `00:00:0C:9F:Fx:xx` for IPv4 with the group number,
+    /// returning `None` if it is greater than 4095.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// let addr = MacAddr6::hsrp_v2(0x123);
+    ///
+    /// assert_eq!(addr, Some(MacAddr6::new(0x00, 0x00, 0x0C, 0x9F, 0xF1, 0x23)));
+    /// assert_eq!(addr.and_then(|addr| addr.hsrp_group()), Some(0x123));
+    /// assert_eq!(MacAddr6::hsrp_v2(4096), None);
+    /// ```
+    pub const fn hsrp_v2(group: u16) -> Option<MacAddr6> {
+        let [e, f] = group.to_be_bytes();
+        if e > 0x0F {
+            return None;
+        }
+
+        Some(MacAddr6::new(0x00, 0x00, 0x0C, 0x9F, 0xF0 | e, f))
+    }
+
+    /// Creates the Cisco HSRP version 2 virtual router address `00:05:73:A0:0x:xx` for IPv6 with the group number,
+    /// returning `None` if it is greater than 4095.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// assert_eq!(MacAddr6::hsrp_ipv6(0x123), Some(MacAddr6::new(0x00, 0x05, 0x73, 0xA0, 0x01, 0x23)));
+    /// assert_eq!(MacAddr6::hsrp_ipv6(4096), None);
+    /// ```
+    pub const fn hsrp_ipv6(group: u16) -> Option<MacAddr6> {
+        let [e, f] = group.to_be_bytes();
+        if e > 0x0F {
+            return None;
         }
+
+        Some(MacAddr6::new(0x00, 0x05, 0x73, 0xA0, e, f))
+    }
+
+    /// Generates a random address with the `rng` random number generator.
+    ///
+    /// Any address can be generated, including the multicast ones;
+    /// see the [`random_local_unicast`](#method.random_local_unicast) method for the interface addresses.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// # let mut rng = rand::rngs::mock::StepRng::new(0, 1);
+    /// let addr = MacAddr6::random(&mut rng);
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn random<R: rand::Rng + ?Sized>(rng: &mut R) -> MacAddr6 {
+        let mut bytes = [0; 6];
+        rng.fill_bytes(&mut bytes);
+
+        MacAddr6(bytes)
+    }
+
+    /// Generates a random locally administered unicast address with the `rng` random number generator,
+    /// as used for the randomized interface addresses.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// # let mut rng = rand::rngs::mock::StepRng::new(0, 1);
+    /// let addr = MacAddr6::random_local_unicast(&mut rng);
+    ///
+    /// assert!(addr.is_local());
+    /// assert!(addr.is_unicast());
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn random_local_unicast<R: rand::Rng + ?Sized>(rng: &mut R) -> MacAddr6 {
+        let mut addr = MacAddr6::random(rng);
+        addr.0[0] = addr.0[0] & !1 | 1 << 1;
+
+        addr
+    }
+
+    /// Returns the address with the NIC-specific part replaced by the random bytes
+    /// from the `rng` random number generator, keeping the Organizationally Unique Identifier (OUI).
+    ///
+    /// Randomized address still looks like the vendor assigned one, as with the `macchanger --ending` command,
+    /// so it is not detected by the [`is_randomized`](#method.is_randomized) method.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// # let mut rng = rand::rngs::mock::StepRng::new(0, 1);
+    /// let addr = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22);
+    /// let randomized = addr.randomize_preserving_oui(&mut rng);
+    ///
+    /// assert_eq!(randomized.oui(), addr.oui());
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn randomize_preserving_oui<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> MacAddr6 {
+        let mut nic = [0; 3];
+        rng.fill_bytes(&mut nic);
+
+        self.with_nic(nic)
+    }
+
+    /// Derives a stable locally administered unicast address from the `namespace` and the `name`,
+    /// as the persistent interface addresses assigned to the virtual machines or containers.
+    ///
+    /// The inputs are hashed with the 64-bit FNV-1a function, and the same inputs always result
+    /// in the same address, across the platforms and the crate versions.
+    /// The hash is not cryptographic, so the address should not be derived from the secrets.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// let addr = MacAddr6::derive_local(b"vm", b"guest-1");
+    ///
+    /// assert_eq!(addr, MacAddr6::new(0x7E, 0x27, 0x9C, 0x21, 0x3B, 0x86));
+    /// assert!(addr.is_local() && addr.is_unicast());
+    /// assert_ne!(addr, MacAddr6::derive_local(b"vm", b"guest-2"));
+    /// assert_ne!(addr, MacAddr6::derive_local(b"vmg", b"uest-1"));
+    /// ```
+    pub const fn derive_local(namespace: &[u8], name: &[u8]) -> MacAddr6 {
+        // Namespace length is hashed first, so the inputs can't be shifted from one part to another.
+        let hash = fnv1a(FNV_OFFSET_BASIS, &(namespace.len() as u64).to_le_bytes());
+        let hash = fnv1a(hash, namespace);
+        let hash = fnv1a(hash, name);
+
+        // Higher bits of the FNV hash are better mixed.
+        let [a, b, c, d, e, f, _, _] = hash.to_be_bytes();
+
+        MacAddr6::new(a & !1 | 1 << 1, b, c, d, e, f)
+    }
+
+    /// Parses a `MacAddr6` address from the string in a `const` context.
+    ///
+    /// Accepts the same notations as the `FromStr` implementation.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the string is not a valid `MacAddr6` address,
+    /// which fails the compilation when used to define a `const` item.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// const ADDR: MacAddr6 = MacAddr6::parse_const("AC:DE:48:00:11:22");
+    ///
+    /// assert_eq!(ADDR, MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22));
+    /// ```
+    pub const fn parse_const(s: &str) -> MacAddr6 {
+        parser::unwrap_const(parser::Parser::new(s).read_v6_addr())
+    }
+
+    /// Parses a `MacAddr6` address from the string in a `const` context,
+    /// returning an error instead of panicking.
+    ///
+    /// Accepts the same notations as the `FromStr` implementation.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::{MacAddr6, ParseError};
+    /// const ADDR: Result<MacAddr6, ParseError> = MacAddr6::try_parse_const("AC:DE:48:00:11:22");
+    /// const INVALID: Result<MacAddr6, ParseError> = MacAddr6::try_parse_const("AC:DE:48");
+    ///
+    /// assert_eq!(ADDR, Ok(MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22)));
+    /// assert_eq!(INVALID, Err(ParseError::InvalidLength(8)));
+    /// ```
+    pub const fn try_parse_const(s: &str) -> Result<MacAddr6, ParseError> {
+        parser::Parser::new(s).read_v6_addr()
+    }
+
+    /// Parses a `MacAddr6` address from the ASCII text without checking it for UTF-8 validity first.
+    ///
+    /// Accepts the same notations as the `FromStr` implementation.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// let addr = MacAddr6::parse_ascii(b"AC:DE:48:00:11:22");
+    ///
+    /// assert_eq!(addr, Ok(MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22)));
+    /// ```
+    pub fn parse_ascii(s: &[u8]) -> Result<MacAddr6, ParseError> {
+        parser::Parser::from_ascii(s, ParseOptions::new()).read_v6_addr()
+    }
+
+    /// Parses a `MacAddr6` address from the string, accepting only the notations enabled in `options`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::{MacAddr6, ParseOptions};
+    /// let options = ParseOptions::new().bare(false);
+    ///
+    /// assert!(MacAddr6::parse_with("AC-DE-48-00-11-22", &options).is_ok());
+    /// assert!(MacAddr6::parse_with(&"AC-DE-48-00-11-22".replace('-', ""), &options).is_err());
+    /// ```
+    pub fn parse_with(s: &str, options: &ParseOptions) -> Result<MacAddr6, ParseError> {
+        parser::Parser::with_options(s, *options).read_v6_addr()
+    }
+
+    /// Parses a `MacAddr6` address from the string, tolerating the common formatting noise.
+    ///
+    /// Unlike the `FromStr` implementation, surrounding whitespace is ignored
+    /// and any of the `-`, `:` and `.` delimiters (or none) might be used between each byte,
+    /// as in the `AC.DE.48.00.11.22` notation with dots between every byte.
+    /// Trailing interface annotation starting with `%`, as in `01-23-45-67-89-AB%12`, is stripped.
+    /// Bytes without zero padding, as in `0:a:95:9d:68:16`, are accepted between the `-` and `:` delimiters.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// let addr = MacAddr6::parse_lenient(" AC:DE-48:00.11:22\n");
+    ///
+    /// assert_eq!(addr, Ok(MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22)));
+    /// ```
+    pub fn parse_lenient(s: &str) -> Result<MacAddr6, ParseError> {
+        parser::Parser::lenient(s).read_v6_addr()
+    }
+
+    /// Parses a `MacAddr6` address written in the bit-reversed (non-canonical) form,
+    /// as used by the Token Ring and some FDDI equipment.
+    ///
+    /// Accepts the same notations as the `FromStr` implementation
+    /// and reverses the bits order in each byte.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// let addr = MacAddr6::parse_bit_reversed("35:7B:12:00:88:44");
+    ///
+    /// assert_eq!(addr, Ok(MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22)));
+    /// ```
+    pub fn parse_bit_reversed(s: &str) -> Result<MacAddr6, ParseError> {
+        parser::Parser::new(s).read_v6_addr().map(|addr| addr.to_bit_reversed())
+    }
+
+    /// Parses a `MacAddr6` address from the DHCP client identifier (RFC 2132, section 9.14),
+    /// as written in the DHCP servers lease files.
+    ///
+    /// Client identifier consists of the hardware type octet, which should be `01` (Ethernet),
+    /// followed by the address in any notation supported by the `FromStr` implementation.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::{MacAddr6, ParseError};
+    /// let addr = MacAddr6::parse_dhcp_client_id("01:ac:de:48:00:11:22");
+    ///
+    /// assert_eq!(addr, Ok(MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22)));
+    /// assert_eq!(MacAddr6::parse_dhcp_client_id("ac:de:48:00:11:22"), Err(ParseError::InvalidCharacter('a', 0)));
+    /// ```
+    pub fn parse_dhcp_client_id(s: &str) -> Result<MacAddr6, ParseError> {
+        parser::Parser::new(s).read_v6_client_id()
+    }
+
+    /// Parses the DHCP client identifier written by the ISC DHCP server into the `uid` statements
+    /// of the `dhcpd.leases` file, as in `"\001\254\336H\000\021\""`.
+    ///
+    /// Identifier bytes are decoded as with the [`from_dhcp_client_id`](#method.from_dhcp_client_id) method,
+    /// identifiers not holding the Ethernet address are reported as the [`ParseError::InvalidLength`] error.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// let addr = MacAddr6::parse_isc_client_id(r#""\001\254\336H\000\021\"""#);
+    ///
+    /// assert_eq!(addr, Ok(MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22)));
+    /// assert!(MacAddr6::parse_isc_client_id(r#""host.example.com""#).is_err());
+    /// ```
+    ///
+    /// [`ParseError::InvalidLength`]: enum.ParseError.html#variant.InvalidLength
+    pub fn parse_isc_client_id(s: &str) -> Result<MacAddr6, ParseError> {
+        // Longest identifier is the RFC 4361 one with the DUID-LLT.
+        let mut buf = [0; 19];
+        let bytes = parser::unescape_isc_string(s, &mut buf)?;
+
+        MacAddr6::from_dhcp_client_id(bytes).ok_or(ParseError::InvalidLength(s.len()))
+    }
+
+    /// Parses a `MacAddr6` address in the notations used by the SNMP tools for the `MacAddress`
+    /// and the `PhysAddress` textual conventions of [RFC 2579].
+    ///
+    /// Accepts the display hint notation with colons between the bytes, the leading zeros of which
+    /// may be omitted, as in `0:1a:2b:3c:4d:5e`, and the raw `OCTET STRING` notation with spaces between
+    /// the bytes, as in `00 1A 2B 3C 4D 5E`, optionally followed by a single space as printed by Net-SNMP.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// let addr = MacAddr6::new(0x00, 0x1A, 0x2B, 0x3C, 0x04, 0x5E);
+    ///
+    /// assert_eq!(MacAddr6::parse_snmp("0:1a:2b:3c:4:5e"), Ok(addr));
+    /// assert_eq!(MacAddr6::parse_snmp("00:1A:2B:3C:04:5E"), Ok(addr));
+    /// assert_eq!(MacAddr6::parse_snmp("00 1A 2B 3C 04 5E "), Ok(addr));
+    /// assert!(MacAddr6::parse_snmp("0 1A 2B 3C 4 5E").is_err());
+    /// ```
+    ///
+    /// [RFC 2579]: https://www.rfc-editor.org/rfc/rfc2579
+    pub fn parse_snmp(s: &str) -> Result<MacAddr6, ParseError> {
+        parser::parse_snmp(s)
+    }
+
+    /// Creates the address from the SNMP `MacAddress` or `PhysAddress` `OCTET STRING` value,
+    /// returning `None` if it is not 6 bytes long.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// let octets = [0x00, 0x1A, 0x2B, 0x3C, 0x4D, 0x5E];
+    ///
+    /// assert_eq!(MacAddr6::from_snmp_octets(&octets), Some(MacAddr6::from(octets)));
+    /// assert_eq!(MacAddr6::from_snmp_octets(&[]), None);
+    /// ```
+    pub const fn from_snmp_octets(octets: &[u8]) -> Option<MacAddr6> {
+        match octets {
+            [a, b, c, d, e, f] => Some(MacAddr6::new(*a, *b, *c, *d, *e, *f)),
+            _ => None,
+        }
+    }
+
+    /// Searches for the first `MacAddr6` address in the free-form text.
+    ///
+    /// Returns the byte range of the address in the text along with the parsed address.
+    ///
+    /// Addresses in any notation supported by the `FromStr` implementation are matched,
+    /// except for the bare hex form, which is indistinguishable from the plain numbers.
+    /// Matched address should not be a part of a longer word or a longer address.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// let text = "link/ether ac:de:48:00:11:22 brd ff:ff:ff:ff:ff:ff";
+    /// let addr = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22);
+    ///
+    /// assert_eq!(MacAddr6::find_in_str(text), Some((11..28, addr)));
+    /// assert_eq!(MacAddr6::find_in_str("no addresses here"), None);
+    /// ```
+    pub fn find_in_str(s: &str) -> Option<(Range<usize>, MacAddr6)> {
+        find::find(s, 0, MacAddr6::match_at)
+    }
+
+    /// Returns an iterator over all `MacAddr6` addresses in the free-form text.
+    ///
+    /// See [`MacAddr6::find_in_str`] for the matching rules.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// let text = "link/ether ac:de:48:00:11:22 brd ff:ff:ff:ff:ff:ff";
+    /// let addrs: Vec<MacAddr6> = MacAddr6::find_all_in_str(text).map(|(_, addr)| addr).collect();
+    ///
+    /// assert_eq!(addrs, vec![MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22), MacAddr6::broadcast()]);
+    /// ```
+    ///
+    /// [`MacAddr6::find_in_str`]: #method.find_in_str
+    pub fn find_all_in_str(s: &str) -> impl Iterator<Item = (Range<usize>, MacAddr6)> + '_ {
+        find::find_all(s, MacAddr6::match_at)
+    }
+
+    fn match_at(s: &str, start: usize) -> Option<(Range<usize>, MacAddr6)> {
+        find::match_at(s, start, 12, parser::Parser::read_v6_prefix)
+    }
+
+    /// Returns the address with the bits order reversed in each byte.
+    ///
+    /// Converts the canonical form into the bit-reversed one, used by the Token Ring
+    /// and some FDDI equipment, and vice versa.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// let addr = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22);
+    ///
+    /// assert_eq!(addr.to_bit_reversed(), MacAddr6::new(0x35, 0x7B, 0x12, 0x00, 0x88, 0x44));
+    /// assert_eq!(addr.to_bit_reversed().to_bit_reversed(), addr);
+    /// ```
+    pub const fn to_bit_reversed(&self) -> MacAddr6 {
+        let [a, b, c, d, e, f] = self.0;
+
+        MacAddr6::new(
+            a.reverse_bits(),
+            b.reverse_bits(),
+            c.reverse_bits(),
+            d.reverse_bits(),
+            e.reverse_bits(),
+            f.reverse_bits(),
+        )
+    }
+
+    /// Converts the address into the *EUI-64* format by inserting the `FF:FE` bytes
+    /// between the OUI and the NIC-specific halves.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::{MacAddr6, MacAddr8};
+    /// let addr = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22);
+    ///
+    /// assert_eq!(addr.to_eui64(), MacAddr8::new(0xAC, 0xDE, 0x48, 0xFF, 0xFE, 0x00, 0x11, 0x22));
+    /// assert_eq!(addr.to_eui64().to_eui48(), Some(addr));
+    /// ```
+    pub const fn to_eui64(&self) -> MacAddr8 {
+        let [a, b, c, d, e, f] = self.0;
+
+        MacAddr8::new(a, b, c, 0xFF, 0xFE, d, e, f)
+    }
+
+    /// Converts the address into the modified *EUI-64* format used for the IPv6 interface identifiers
+    /// (RFC 4291, Appendix A), which is the *EUI-64* address with the universal/local bit inverted.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::{MacAddr6, MacAddr8};
+    /// let addr = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22);
+    ///
+    /// assert_eq!(addr.to_modified_eui64(), MacAddr8::new(0xAE, 0xDE, 0x48, 0xFF, 0xFE, 0x00, 0x11, 0x22));
+    /// ```
+    pub const fn to_modified_eui64(&self) -> MacAddr8 {
+        let [a, b, c, d, e, f] = self.0;
+
+        MacAddr8::new(a ^ 0x02, b, c, 0xFF, 0xFE, d, e, f)
+    }
+
+    /// Returns the IPv6 link-local address in the `fe80::/64` prefix, with the interface identifier
+    /// derived from the address by the modified *EUI-64* construction (RFC 4291, Appendix A).
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// # use std::net::Ipv6Addr;
+    /// let addr = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22);
+    /// let ip: Ipv6Addr = "fe80::aede:48ff:fe00:1122".parse().unwrap();
+    ///
+    /// assert_eq!(addr.to_link_local_ipv6(), ip);
+    /// assert_eq!(MacAddr6::from_link_local(addr.to_link_local_ipv6()), Some(addr));
+    /// ```
+    pub const fn to_link_local_ipv6(&self) -> Ipv6Addr {
+        let [a, b, c, d, e, f, g, h] = self.to_modified_eui64().into_array();
+
+        Ipv6Addr::new(
+            0xFE80,
+            0,
+            0,
+            0,
+            u16::from_be_bytes([a, b]),
+            u16::from_be_bytes([c, d]),
+            u16::from_be_bytes([e, f]),
+            u16::from_be_bytes([g, h]),
+        )
+    }
+
+    /// Returns the multicast address the IPv4 multicast group is mapped to (RFC 1112, section 6.4),
+    /// made of the `01:00:5E` prefix and the low-order 23 bits of the group address.
+    ///
+    /// Returns `None` if the IPv4 address is not a multicast one, i.e. it is not in the `224.0.0.0/4` range.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// # use std::net::Ipv4Addr;
+    /// let addr = MacAddr6::from_ipv4_multicast(Ipv4Addr::new(224, 0, 0, 251));
+    ///
+    /// assert_eq!(addr, Some(MacAddr6::new(0x01, 0x00, 0x5E, 0x00, 0x00, 0xFB)));
+    /// assert_eq!(MacAddr6::from_ipv4_multicast(Ipv4Addr::new(239, 128, 0, 251)), addr);
+    /// assert_eq!(MacAddr6::from_ipv4_multicast(Ipv4Addr::new(192, 168, 0, 1)), None);
+    /// ```
+    pub const fn from_ipv4_multicast(ip: Ipv4Addr) -> Option<MacAddr6> {
+        if !ip.is_multicast() {
+            return None;
+        }
+
+        let [_, b, c, d] = ip.octets();
+        Some(MacAddr6::new(0x01, 0x00, 0x5E, b & 0x7F, c, d))
+    }
+
+    /// Returns an iterator over the 32 IPv4 multicast groups mapped to this address (RFC 1112, section 6.4),
+    /// in the ascending order, as the five high-order bits of the group address are not preserved by the mapping.
+    ///
+    /// Iterator is empty if the address is not in the `01:00:5E:00:00:00/25` range.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// # use std::net::Ipv4Addr;
+    /// let addr = MacAddr6::new(0x01, 0x00, 0x5E, 0x00, 0x00, 0xFB);
+    /// let groups = addr.ipv4_groups().collect::<Vec<_>>();
+    ///
+    /// assert_eq!(groups.len(), 32);
+    /// assert_eq!(groups[0], Ipv4Addr::new(224, 0, 0, 251));
+    /// assert_eq!(groups[1], Ipv4Addr::new(224, 128, 0, 251));
+    /// assert_eq!(groups[31], Ipv4Addr::new(239, 128, 0, 251));
+    /// assert!(groups.iter().all(|ip| MacAddr6::from_ipv4_multicast(*ip) == Some(addr)));
+    /// assert_eq!(MacAddr6::broadcast().ipv4_groups().count(), 0);
+    /// ```
+    pub fn ipv4_groups(&self) -> impl Iterator<Item = Ipv4Addr> {
+        let [_, _, _, b, c, d] = self.0;
+        let count = if self.is_ipv4_multicast() { 32 } else { 0 };
+
+        // Lowest bit of the index selects the high-order bit of the second octet.
+        (0..count).map(move |idx: u8| Ipv4Addr::new(224 + (idx >> 1), b | (idx & 1) << 7, c, d))
+    }
+
+    /// Returns the multicast address the IPv6 multicast group is mapped to (RFC 2464, section 7),
+    /// made of the `33:33` prefix and the low-order 32 bits of the group address.
+    ///
+    /// Returns `None` if the IPv6 address is not a multicast one, i.e. it is not in the `ff00::/8` range.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// # use std::net::Ipv6Addr;
+    /// let ip: Ipv6Addr = "ff02::1:ff00:1122".parse().unwrap();
+    ///
+    /// assert_eq!(MacAddr6::from_ipv6_multicast(ip), Some(MacAddr6::new(0x33, 0x33, 0xFF, 0x00, 0x11, 0x22)));
+    /// assert_eq!(MacAddr6::from_ipv6_multicast(Ipv6Addr::LOCALHOST), None);
+    /// ```
+    pub const fn from_ipv6_multicast(ip: Ipv6Addr) -> Option<MacAddr6> {
+        match ip.octets() {
+            [0xFF, .., c, d, e, f] => Some(MacAddr6::new(0x33, 0x33, c, d, e, f)),
+            _ => None,
+        }
+    }
+
+    /// Extracts the `MacAddr6` address embedded into the IPv6 link-local address
+    /// by the modified *EUI-64* interface identifier construction (RFC 4291, Appendix A).
+    ///
+    /// Returns `None` if the address is not in the `fe80::/64` prefix
+    /// or if its interface identifier is not derived from a MAC address,
+    /// i.e. it does not contain the `FF:FE` bytes in the middle.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// # use std::net::Ipv6Addr;
+    /// let ip: Ipv6Addr = "fe80::aede:48ff:fe00:1122".parse().unwrap();
+    /// let addr = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22);
+    ///
+    /// assert_eq!(MacAddr6::from_link_local(ip), Some(addr));
+    /// assert_eq!(MacAddr6::from_link_local("fe80::1".parse().unwrap()), None);
+    /// ```
+    pub const fn from_link_local(ip: Ipv6Addr) -> Option<MacAddr6> {
+        match ip.octets() {
+            [0xFE, 0x80, 0, 0, 0, 0, 0, 0, a, b, c, 0xFF, 0xFE, d, e, f] => {
+                Some(MacAddr6::new(a ^ 0x02, b, c, d, e, f))
+            }
+            _ => None,
+        }
+    }
+
+    /// Extracts the `MacAddr6` address from the DHCP `chaddr` field (RFC 2131, section 2),
+    /// given the `hlen` hardware address length field of the same message.
+    ///
+    /// Returns `None` if the hardware address is not 6 bytes long.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// let mut chaddr = [0; 16];
+    /// chaddr[..6].copy_from_slice(&[0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22]);
+    ///
+    /// assert_eq!(MacAddr6::from_dhcp_chaddr(&chaddr, 6), Some(MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22)));
+    /// assert_eq!(MacAddr6::from_dhcp_chaddr(&chaddr, 16), None);
+    /// ```
+    pub const fn from_dhcp_chaddr(chaddr: &[u8; 16], hlen: u8) -> Option<MacAddr6> {
+        match (hlen, chaddr) {
+            (6, [a, b, c, d, e, f, ..]) => Some(MacAddr6::new(*a, *b, *c, *d, *e, *f)),
+            _ => None,
+        }
+    }
+
+    /// Returns the DHCPv6 DUID-LL identifier based on the address, as defined by [RFC 8415],
+    /// for the `hardware_type` from the IANA ARP parameters registry, which is `1` for Ethernet.
+    ///
+    /// Identifier is made of the big-endian DUID type `3`, the hardware type and the address bytes.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// let addr = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22);
+    ///
+    /// assert_eq!(addr.to_duid_ll(1), [0x00, 0x03, 0x00, 0x01, 0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22]);
+    /// ```
+    ///
+    /// [RFC 8415]: https://www.rfc-editor.org/rfc/rfc8415#section-11.4
+    pub const fn to_duid_ll(&self, hardware_type: u16) -> [u8; 10] {
+        let [g, h] = hardware_type.to_be_bytes();
+        let [a, b, c, d, e, f] = self.0;
+
+        [0x00, 0x03, g, h, a, b, c, d, e, f]
+    }
+
+    /// Returns the DHCPv6 DUID-LLT identifier based on the address, as defined by [RFC 8415],
+    /// for the `hardware_type` from the IANA ARP parameters registry, which is `1` for Ethernet,
+    /// and the `time` the identifier is generated at, in seconds since midnight UTC, January 1, 2000,
+    /// modulo 2<sup>32</sup>.
+    ///
+    /// Identifier is made of the big-endian DUID type `1`, the hardware type, the time and the address bytes.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// let addr = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22);
+    ///
+    /// assert_eq!(
+    ///     addr.to_duid_llt(1, 0x2E53_9D80),
+    ///     [0x00, 0x01, 0x00, 0x01, 0x2E, 0x53, 0x9D, 0x80, 0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22],
+    /// );
+    /// ```
+    ///
+    /// [RFC 8415]: https://www.rfc-editor.org/rfc/rfc8415#section-11.2
+    pub const fn to_duid_llt(&self, hardware_type: u16, time: u32) -> [u8; 14] {
+        let [g, h] = hardware_type.to_be_bytes();
+        let [i, j, k, l] = time.to_be_bytes();
+        let [a, b, c, d, e, f] = self.0;
+
+        [0x00, 0x01, g, h, i, j, k, l, a, b, c, d, e, f]
+    }
+
+    /// Extracts the address from the DHCPv6 DUID-LL or DUID-LLT identifier, as defined by [RFC 8415],
+    /// returning `None` for the other DUID types and the link-layer addresses which are not 6 bytes long.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// let addr = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22);
+    ///
+    /// assert_eq!(MacAddr6::from_duid(&addr.to_duid_ll(1)), Some(addr));
+    /// assert_eq!(MacAddr6::from_duid(&addr.to_duid_llt(1, 0x2E53_9D80)), Some(addr));
+    /// assert_eq!(MacAddr6::from_duid(&[0x00, 0x04, 0x00, 0x01, 0x02]), None);
+    /// ```
+    ///
+    /// [RFC 8415]: https://www.rfc-editor.org/rfc/rfc8415#section-11
+    pub const fn from_duid(duid: &[u8]) -> Option<MacAddr6> {
+        match duid {
+            [0x00, 0x03, _, _, a, b, c, d, e, f] | [0x00, 0x01, _, _, _, _, _, _, a, b, c, d, e, f] => {
+                Some(MacAddr6::new(*a, *b, *c, *d, *e, *f))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the DHCP client identifier (option 61) bytes of the address, as defined by [RFC 2132],
+    /// which are the Ethernet hardware type `01` followed by the address bytes.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// let addr = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22);
+    ///
+    /// assert_eq!(addr.to_dhcp_client_id(), [0x01, 0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22]);
+    /// // Same notation as in the Kea lease files and the `parse_dhcp_client_id` method.
+    /// assert_eq!(format!("01:{:#}", addr), "01:ac:de:48:00:11:22");
+    /// ```
+    ///
+    /// [RFC 2132]: https://www.rfc-editor.org/rfc/rfc2132#section-9.14
+    pub const fn to_dhcp_client_id(&self) -> [u8; 7] {
+        let [a, b, c, d, e, f] = self.0;
+
+        [0x01, a, b, c, d, e, f]
+    }
+
+    /// Extracts the address from the DHCP client identifier (option 61) bytes.
+    ///
+    /// Besides the [RFC 2132] identifiers made of the Ethernet hardware type `01` and the address,
+    /// the [RFC 4361] ones made of the `FF` type, the IAID and the DUID-LL or DUID-LLT identifier
+    /// are accepted as with the [`from_duid`](#method.from_duid) method.
+    /// Returns `None` for the rest of the identifiers, such as the textual host names.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// let addr = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22);
+    /// assert_eq!(MacAddr6::from_dhcp_client_id(&addr.to_dhcp_client_id()), Some(addr));
+    ///
+    /// let mut client_id = vec![0xFF, 0x00, 0x00, 0x00, 0x01];
+    /// client_id.extend_from_slice(&addr.to_duid_ll(1));
+    /// assert_eq!(MacAddr6::from_dhcp_client_id(&client_id), Some(addr));
+    ///
+    /// assert_eq!(MacAddr6::from_dhcp_client_id(b"\x00host"), None);
+    /// ```
+    ///
+    /// [RFC 2132]: https://www.rfc-editor.org/rfc/rfc2132#section-9.14
+    /// [RFC 4361]: https://www.rfc-editor.org/rfc/rfc4361#section-6.1
+    pub const fn from_dhcp_client_id(client_id: &[u8]) -> Option<MacAddr6> {
+        match client_id {
+            [0x01, a, b, c, d, e, f] => Some(MacAddr6::new(*a, *b, *c, *d, *e, *f)),
+            [0xFF, _, _, _, _, duid @ ..] => MacAddr6::from_duid(duid),
+            _ => None,
+        }
+    }
+
+    /// Returns the LLDP Chassis ID TLV value of the address, as defined by IEEE 802.1AB,
+    /// which is the MAC address subtype `4` followed by the address bytes.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// let addr = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22);
+    ///
+    /// assert_eq!(addr.to_lldp_chassis_id(), [0x04, 0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22]);
+    /// ```
+    pub const fn to_lldp_chassis_id(&self) -> [u8; 7] {
+        let [a, b, c, d, e, f] = self.0;
+
+        [0x04, a, b, c, d, e, f]
+    }
+
+    /// Parses the LLDP Chassis ID TLV value of the MAC address subtype `4`, as defined by IEEE 802.1AB.
+    ///
+    /// Returns `None` for the other subtypes or if the address is not 6 bytes long.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// let addr = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22);
+    ///
+    /// assert_eq!(MacAddr6::from_lldp_subtype4(&addr.to_lldp_chassis_id()), Some(addr));
+    /// assert_eq!(MacAddr6::from_lldp_subtype4(&addr.to_lldp_port_id()), None);
+    /// assert_eq!(MacAddr6::from_lldp_subtype4(b"\x07switch-1"), None);
+    /// ```
+    pub const fn from_lldp_subtype4(value: &[u8]) -> Option<MacAddr6> {
+        match value {
+            [0x04, a, b, c, d, e, f] => Some(MacAddr6::new(*a, *b, *c, *d, *e, *f)),
+            _ => None,
+        }
+    }
+
+    /// Returns the LLDP Port ID TLV value of the address, as defined by IEEE 802.1AB,
+    /// which is the MAC address subtype `3` followed by the address bytes.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// let addr = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22);
+    ///
+    /// assert_eq!(addr.to_lldp_port_id(), [0x03, 0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22]);
+    /// ```
+    pub const fn to_lldp_port_id(&self) -> [u8; 7] {
+        let [a, b, c, d, e, f] = self.0;
+
+        [0x03, a, b, c, d, e, f]
+    }
+
+    /// Parses the LLDP Port ID TLV value of the MAC address subtype `3`, as defined by IEEE 802.1AB.
+    ///
+    /// Returns `None` for the other subtypes or if the address is not 6 bytes long.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// let addr = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22);
+    ///
+    /// assert_eq!(MacAddr6::from_lldp_port_id(&addr.to_lldp_port_id()), Some(addr));
+    /// assert_eq!(MacAddr6::from_lldp_port_id(b"\x05eth0"), None);
+    /// ```
+    pub const fn from_lldp_port_id(value: &[u8]) -> Option<MacAddr6> {
+        match value {
+            [0x03, a, b, c, d, e, f] => Some(MacAddr6::new(*a, *b, *c, *d, *e, *f)),
+            _ => None,
+        }
+    }
+
+    /// Returns an iterator over the `MacAddr6` addresses packed one after another in the byte buffer,
+    /// as in the binary address tables dumped from the switches.
+    ///
+    /// Buffer length is expected to be divisible by 6, trailing bytes not forming a whole address are ignored.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// let bytes = [0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF];
+    /// let addrs: Vec<MacAddr6> = MacAddr6::iter_from_bytes(&bytes).collect();
+    ///
+    /// assert_eq!(addrs, vec![MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22), MacAddr6::broadcast()]);
+    /// assert_eq!(MacAddr6::iter_from_bytes(&bytes[..10]).count(), 1);
+    /// ```
+    pub fn iter_from_bytes(bytes: &[u8]) -> impl Iterator<Item = MacAddr6> + '_ {
+        bytes.chunks_exact(6).map(|chunk| {
+            let mut addr = [0; 6];
+            addr.copy_from_slice(chunk);
+            MacAddr6(addr)
+        })
+    }
+
+    /// Writes the address in the colon-separated uppercase notation, as in `AC:DE:48:00:11:22`,
+    /// into the start of the `buf` buffer without allocating or using the `core::fmt` machinery.
+    ///
+    /// Returns the written part of the buffer or [`BufferTooSmall`] error
+    /// if the buffer is shorter than the written address.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// let addr = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22);
+    /// let mut buf = [0; MacAddr6::MAX_DISPLAY_LEN];
+    ///
+    /// assert_eq!(addr.write_str(&mut buf), Ok("AC:DE:48:00:11:22"));
+    /// assert!(addr.write_str(&mut buf[..4]).is_err());
+    /// ```
+    ///
+    /// [`BufferTooSmall`]: struct.BufferTooSmall.html
+    pub fn write_str<'b>(&self, buf: &'b mut [u8]) -> Result<&'b str, BufferTooSmall> {
+        display::write_colon(buf, &self.0)
+    }
+
+    /// Returns an object displaying the address with colons between every byte, as in `AC:DE:48:00:11:22`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// let addr = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22);
+    ///
+    /// assert_eq!(addr.display_colon().to_string(), "AC:DE:48:00:11:22");
+    /// assert_eq!(format!("{:#}", addr.display_colon()), "ac:de:48:00:11:22");
+    /// ```
+    pub fn display_colon(&self) -> DisplayColon<'_> {
+        DisplayColon(&self.0)
+    }
+
+    /// Returns an object displaying the address with hyphens between every byte, as in `AC-DE-48-00-11-22`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// let addr = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22);
+    ///
+    /// assert_eq!(addr.display_hyphen().to_string(), "AC-DE-48-00-11-22");
+    /// ```
+    pub fn display_hyphen(&self) -> DisplayHyphen<'_> {
+        DisplayHyphen(&self.0)
+    }
+
+    /// Returns an object displaying the address with dots between the 16-bit groups, as in `ACDE.4800.1122`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// let addr = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22);
+    ///
+    /// assert_eq!(addr.display_dotted().to_string(), "ACDE.4800.1122");
+    /// ```
+    pub fn display_dotted(&self) -> DisplayDotted<'_> {
+        DisplayDotted(&self.0)
+    }
+
+    /// Returns an object displaying the address as the bare hex digits, as in `ACDE48001122`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// let addr = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22);
+    ///
+    /// assert_eq!(addr.display_bare().to_string(), "ACDE48001122");
+    /// ```
+    pub fn display_bare(&self) -> DisplayBare<'_> {
+        DisplayBare(&self.0)
+    }
+
+    /// Returns the string with the address in the IEEE 802 canonical notation,
+    /// with hyphens between every byte, as in `AC-DE-48-00-11-22`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// let addr = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22);
+    ///
+    /// assert_eq!(addr.to_canonical_string(), "AC-DE-48-00-11-22");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_canonical_string(&self) -> String {
+        self.display_hyphen().to_string()
+    }
+
+    /// Returns the string with the address with colons between every byte, as in `AC:DE:48:00:11:22`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// let addr = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22);
+    ///
+    /// assert_eq!(addr.to_colon_string(), "AC:DE:48:00:11:22");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_colon_string(&self) -> String {
+        self.display_colon().to_string()
+    }
+
+    /// Returns the string with the address as the bare hex digits, as in `ACDE48001122`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// let addr = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22);
+    ///
+    /// assert_eq!(addr.to_hex_string(), "ACDE48001122");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_hex_string(&self) -> String {
+        self.display_bare().to_string()
+    }
+
+    /// Returns the string with the address with dots between the 16-bit groups, as in `ACDE.4800.1122`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// let addr = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22);
+    ///
+    /// assert_eq!(addr.to_dotted_string(), "ACDE.4800.1122");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_dotted_string(&self) -> String {
+        self.display_dotted().to_string()
+    }
+
+    /// Returns an object displaying the address in the custom notation.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::{MacAddr6, MacFormat};
+    /// let addr = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22);
+    ///
+    /// assert_eq!(addr.format(&MacFormat::DOTTED).to_string(), "ACDE.4800.1122");
+    /// ```
+    pub fn format(&self, format: &MacFormat) -> DisplayFormat<'_> {
+        DisplayFormat {
+            bytes: &self.0,
+            format: *format,
+        }
+    }
+
+    /// Returns an object displaying the address with dots between every byte, as in `AC.DE.48.00.11.22`.
+    ///
+    /// Displayed address can be parsed back with [`MacAddr6::parse_lenient`].
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// let addr = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22);
+    ///
+    /// assert_eq!(addr.display_dotted_octets().to_string(), "AC.DE.48.00.11.22");
+    /// assert_eq!(MacAddr6::parse_lenient("AC.DE.48.00.11.22"), Ok(addr));
+    /// ```
+    ///
+    /// [`MacAddr6::parse_lenient`]: #method.parse_lenient
+    pub fn display_dotted_octets(&self) -> DisplayDottedOctets<'_> {
+        DisplayDottedOctets(&self.0)
+    }
+
+    /// Returns an object displaying the address in the SNMP `MacAddress` textual convention of [RFC 2579],
+    /// with colons between the lowercase bytes without the leading zeros, as in `0:1a:2b:3c:4d:5e`.
+    ///
+    /// Displayed address can be parsed back with [`MacAddr6::parse_snmp`].
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// let addr = MacAddr6::new(0x00, 0x1A, 0x2B, 0x3C, 0x04, 0x5E);
+    ///
+    /// assert_eq!(addr.display_snmp().to_string(), "0:1a:2b:3c:4:5e");
+    /// assert_eq!(MacAddr6::parse_snmp("0:1a:2b:3c:4:5e"), Ok(addr));
+    /// ```
+    ///
+    /// [RFC 2579]: https://www.rfc-editor.org/rfc/rfc2579
+    /// [`MacAddr6::parse_snmp`]: #method.parse_snmp
+    pub fn display_snmp(&self) -> DisplaySnmp<'_> {
+        DisplaySnmp(&self.0)
+    }
+
+    /// Returns a reference to the raw bytes array of the `MacAddr6` address.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// let addr = MacAddr6::new(0xAC, 0xDE, 0x48, 0x23, 0x45, 0x67);
+    ///
+    /// assert_eq!(addr.as_bytes(), &[0xAC, 0xDE, 0x48, 0x23, 0x45, 0x67]);
+    /// ```
+    pub const fn as_bytes(&self) -> &[u8; 6] {
+        &self.0
+    }
+
+    /// Consumes `MacAddr6` address and returns raw bytes array.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// let addr = MacAddr6::new(0xAC, 0xDE, 0x48, 0x23, 0x45, 0x67);
+    ///
+    /// assert_eq!(addr.into_array(), [0xAC, 0xDE, 0x48, 0x23, 0x45, 0x67]);
+    /// ```
+    pub const fn into_array(self) -> [u8; 6] {
+        self.0
+    }
+
+    /// Returns the address as an unsigned integer, made of its bytes in the big-endian order.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// let addr = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22);
+    ///
+    /// assert_eq!(addr.to_u64(), 0xACDE48001122);
+    /// ```
+    pub const fn to_u64(&self) -> u64 {
+        let [a, b, c, d, e, f] = self.0;
+
+        u64::from_be_bytes([0, 0, a, b, c, d, e, f])
+    }
+
+    /// Returns the key the address is hashed with, which is the same as the [`to_u64`](#method.to_u64) value.
+    ///
+    /// `Hash` implementation writes only this integer, so the identity hashers can be used
+    /// for the large hash maps keyed by the addresses instead of the default SipHash one.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// # use std::{collections::HashMap, hash::{BuildHasherDefault, Hasher}};
+    /// #[derive(Default)]
+    /// struct IdentityHasher(u64);
+    ///
+    /// impl Hasher for IdentityHasher {
+    ///     fn finish(&self) -> u64 {
+    ///         self.0
+    ///     }
+    ///
+    ///     fn write(&mut self, _bytes: &[u8]) {
+    ///         unreachable!("addresses are hashed with `write_u64`");
+    ///     }
+    ///
+    ///     fn write_u64(&mut self, key: u64) {
+    ///         self.0 = key;
+    ///     }
+    /// }
+    ///
+    /// let addr = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22);
+    /// assert_eq!(addr.as_hash_key(), 0xACDE48001122);
+    ///
+    /// let mut seen = HashMap::<_, _, BuildHasherDefault<IdentityHasher>>::default();
+    /// seen.insert(addr, 1);
+    /// assert_eq!(seen.get(&addr), Some(&1));
+    /// ```
+    pub const fn as_hash_key(&self) -> u64 {
+        self.to_u64()
+    }
+
+    /// Creates the address from an unsigned integer, made of its bytes in the big-endian order.
+    ///
+    /// Returns `None` if the value does not fit into 48 bits.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// assert_eq!(MacAddr6::from_u64(0xACDE48001122), Some(MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22)));
+    /// assert_eq!(MacAddr6::from_u64(1 << 48), None);
+    /// ```
+    pub const fn from_u64(value: u64) -> Option<MacAddr6> {
+        match value.to_be_bytes() {
+            [0, 0, a, b, c, d, e, f] => Some(MacAddr6::new(a, b, c, d, e, f)),
+            _ => None,
+        }
+    }
+
+    /// Adds `rhs` to the address treated as a 48-bit integer, returning `None` on overflow.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// let addr = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x00, 0xFF);
+    ///
+    /// assert_eq!(addr.checked_add(2), Some(MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x01, 0x01)));
+    /// assert_eq!(MacAddr6::broadcast().checked_add(1), None);
+    /// ```
+    pub const fn checked_add(self, rhs: u64) -> Option<MacAddr6> {
+        match self.to_u64().checked_add(rhs) {
+            Some(value) => MacAddr6::from_u64(value),
+            None => None,
+        }
+    }
+
+    /// Subtracts `rhs` from the address treated as a 48-bit integer, returning `None` on underflow.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// let addr = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x01, 0x01);
+    ///
+    /// assert_eq!(addr.checked_sub(2), Some(MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x00, 0xFF)));
+    /// assert_eq!(MacAddr6::nil().checked_sub(1), None);
+    /// ```
+    pub const fn checked_sub(self, rhs: u64) -> Option<MacAddr6> {
+        match self.to_u64().checked_sub(rhs) {
+            Some(value) => MacAddr6::from_u64(value),
+            None => None,
+        }
+    }
+
+    /// Adds `rhs` to the address treated as a 48-bit integer, wrapping around at the 48 bits boundary.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// assert_eq!(MacAddr6::broadcast().wrapping_add(2), MacAddr6::new(0x00, 0x00, 0x00, 0x00, 0x00, 0x01));
+    /// ```
+    pub const fn wrapping_add(self, rhs: u64) -> MacAddr6 {
+        let [_, _, a, b, c, d, e, f] = self.to_u64().wrapping_add(rhs).to_be_bytes();
+
+        MacAddr6::new(a, b, c, d, e, f)
+    }
+
+    /// Subtracts `rhs` from the address treated as a 48-bit integer, wrapping around at the 48 bits boundary.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// assert_eq!(MacAddr6::nil().wrapping_sub(1), MacAddr6::broadcast());
+    /// ```
+    pub const fn wrapping_sub(self, rhs: u64) -> MacAddr6 {
+        let [_, _, a, b, c, d, e, f] = self.to_u64().wrapping_sub(rhs).to_be_bytes();
+
+        MacAddr6::new(a, b, c, d, e, f)
+    }
+
+    /// Returns the following address, or `None` for the broadcast one.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// let addr = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0xFF);
+    ///
+    /// assert_eq!(addr.next(), Some(MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x12, 0x00)));
+    /// assert_eq!(MacAddr6::broadcast().next(), None);
+    /// ```
+    pub const fn next(self) -> Option<MacAddr6> {
+        self.checked_add(1)
+    }
+
+    /// Returns the preceding address, or `None` for the nil one.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// let addr = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x12, 0x00);
+    ///
+    /// assert_eq!(addr.prev(), Some(MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0xFF)));
+    /// assert_eq!(MacAddr6::nil().prev(), None);
+    /// ```
+    pub const fn prev(self) -> Option<MacAddr6> {
+        self.checked_sub(1)
+    }
+
+    /// Returns the signed distance from `other` to this address, treating both as the 48-bit integers,
+    /// so that `other.offset(self.offset_from(&other))` is this address.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// let first = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x00, 0xF0);
+    /// let second = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x01, 0x10);
+    ///
+    /// assert_eq!(second.offset_from(&first), 0x20);
+    /// assert_eq!(first.offset_from(&second), -0x20);
+    /// assert_eq!(MacAddr6::broadcast().offset_from(&MacAddr6::nil()), 0xFFFF_FFFF_FFFF);
+    /// ```
+    pub const fn offset_from(&self, other: &MacAddr6) -> i64 {
+        self.to_u64() as i64 - other.to_u64() as i64
+    }
+
+    /// Returns the address `delta` away from this one, treating it as a 48-bit integer,
+    /// or `None` if the result is out of the 48-bit range.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// let base = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x01, 0x00);
+    ///
+    /// assert_eq!(MacAddr6::offset(base, 4), Some(MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x01, 0x04)));
+    /// assert_eq!(base.offset(-1), Some(MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x00, 0xFF)));
+    /// assert_eq!(MacAddr6::nil().offset(-1), None);
+    /// ```
+    pub const fn offset(self, delta: i64) -> Option<MacAddr6> {
+        if delta < 0 {
+            self.checked_sub(delta.unsigned_abs())
+        } else {
+            self.checked_add(delta as u64)
+        }
+    }
+
+    /// Returns an iterator over the consecutive addresses from `start` to `end` inclusively,
+    /// which is empty if `start` is greater than `end`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// let start = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x00, 0x00);
+    /// let end = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x00, 0x0F);
+    ///
+    /// assert_eq!(MacAddr6::range_inclusive(start, end).count(), 16);
+    /// assert_eq!(MacAddr6::range_inclusive(start, end).rev().next(), Some(end));
+    /// ```
+    pub fn range_inclusive(start: MacAddr6, end: MacAddr6) -> MacAddr6Range {
+        MacAddr6Range::new(start, end)
+    }
+
+    /// Returns the Organizationally Unique Identifier (OUI), which is the first three bytes of the address.
+    ///
+    /// The multicast and the locally administered bits of the first byte are kept as is,
+    /// see the [`oui_masked`](#method.oui_masked) method to ignore them.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// let addr = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22);
+    ///
+    /// assert_eq!(addr.oui(), [0xAC, 0xDE, 0x48]);
+    /// ```
+    pub const fn oui(&self) -> [u8; 3] {
+        let [a, b, c, ..] = self.0;
+
+        [a, b, c]
+    }
+
+    /// Returns the Organizationally Unique Identifier (OUI) with the multicast
+    /// and the locally administered bits of the first byte cleared,
+    /// so the group and the local addresses derived from the vendor ones share the same OUI.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// let addr = MacAddr6::new(0xAF, 0xDE, 0x48, 0x00, 0x11, 0x22);
+    ///
+    /// assert_eq!(addr.oui(), [0xAF, 0xDE, 0x48]);
+    /// assert_eq!(addr.oui_masked(), [0xAC, 0xDE, 0x48]);
+    /// ```
+    pub const fn oui_masked(&self) -> [u8; 3] {
+        let [a, b, c, ..] = self.0;
+
+        [a & !0b11, b, c]
+    }
+
+    /// Returns the NIC-specific part of the address, which is the bytes following the OUI.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// let addr = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22);
+    ///
+    /// assert_eq!(addr.nic_specific(), [0x00, 0x11, 0x22]);
+    /// ```
+    pub const fn nic_specific(&self) -> [u8; 3] {
+        let [_, _, _, d, e, f] = self.0;
+
+        [d, e, f]
+    }
+
+    /// Returns the address with the OUI replaced and the NIC-specific part kept.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// let addr = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22);
+    ///
+    /// assert_eq!(addr.with_oui([0x00, 0x00, 0x5E]).oui(), [0x00, 0x00, 0x5E]);
+    /// assert_eq!(addr.with_oui([0x00, 0x00, 0x5E]).nic_specific(), addr.nic_specific());
+    /// ```
+    pub const fn with_oui(self, oui: [u8; 3]) -> MacAddr6 {
+        let [_, _, _, d, e, f] = self.0;
+        let [a, b, c] = oui;
+
+        MacAddr6([a, b, c, d, e, f])
+    }
+
+    /// Returns the address with the NIC-specific part replaced and the OUI kept.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// let addr = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22);
+    ///
+    /// assert_eq!(addr.with_nic([0x12; 3]), MacAddr6::new(0xAC, 0xDE, 0x48, 0x12, 0x12, 0x12));
+    /// ```
+    pub const fn with_nic(self, nic: [u8; 3]) -> MacAddr6 {
+        let [a, b, c, ..] = self.0;
+        let [d, e, f] = nic;
+
+        MacAddr6([a, b, c, d, e, f])
+    }
+
+    /// Sets the U/L bit of the first byte, making the address locally administered.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// let mut addr = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22);
+    /// addr.set_local();
+    ///
+    /// assert_eq!(addr.is_local(), true);
+    /// assert_eq!(addr.as_bytes()[0], 0xAE);
+    /// ```
+    pub fn set_local(&mut self) {
+        *self = self.with_local(true);
+    }
+
+    /// Clears the U/L bit of the first byte, making the address universally administered.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// let mut addr = MacAddr6::new(0xAE, 0xDE, 0x48, 0x00, 0x11, 0x22);
+    /// addr.set_universal();
+    ///
+    /// assert_eq!(addr.is_universal(), true);
+    /// assert_eq!(addr.as_bytes()[0], 0xAC);
+    /// ```
+    pub fn set_universal(&mut self) {
+        *self = self.with_local(false);
+    }
+
+    /// Sets the I/G bit of the first byte, making the address multicast.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// let mut addr = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22);
+    /// addr.set_multicast();
+    ///
+    /// assert_eq!(addr.is_multicast(), true);
+    /// assert_eq!(addr.as_bytes()[0], 0xAD);
+    /// ```
+    pub fn set_multicast(&mut self) {
+        *self = self.with_multicast(true);
+    }
+
+    /// Clears the I/G bit of the first byte, making the address unicast.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// let mut addr = MacAddr6::broadcast();
+    /// addr.set_unicast();
+    ///
+    /// assert_eq!(addr.is_unicast(), true);
+    /// assert_eq!(addr.as_bytes()[0], 0xFE);
+    /// ```
+    pub fn set_unicast(&mut self) {
+        *self = self.with_multicast(false);
+    }
+
+    /// Returns the address with the U/L bit of the first byte set if `local` is `true`
+    /// or cleared otherwise, keeping the rest of the bits.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// const ADDR: MacAddr6 = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22).with_local(true);
+    ///
+    /// assert_eq!(ADDR.is_local(), true);
+    /// assert_eq!(ADDR.with_local(false), MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22));
+    /// ```
+    pub const fn with_local(self, local: bool) -> MacAddr6 {
+        let mut bytes = self.0;
+        bytes[0] = bytes[0] & !(1 << 1) | (local as u8) << 1;
+
+        MacAddr6(bytes)
+    }
+
+    /// Returns the address with the I/G bit of the first byte set if `multicast` is `true`
+    /// or cleared otherwise, keeping the rest of the bits.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// const ADDR: MacAddr6 = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22).with_local(true).with_multicast(false);
+    ///
+    /// assert_eq!(ADDR.is_randomized(), true);
+    /// assert_eq!(ADDR.with_multicast(true).is_multicast(), true);
+    /// ```
+    pub const fn with_multicast(self, multicast: bool) -> MacAddr6 {
+        let mut bytes = self.0;
+        bytes[0] = bytes[0] & !1 | multicast as u8;
+
+        MacAddr6(bytes)
+    }
+
+    /// Returns `true` if the address bits selected by the `mask` are equal to the same bits of the `pattern`,
+    /// as in the OpenFlow and ACL wildcard matching.
+    ///
+    /// See the [`MacAddrMask`] type for the reusable masks.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// let pattern = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x00, 0x00);
+    /// let mask = MacAddr6::new(0xFF, 0xFF, 0xFF, 0x00, 0x00, 0x00);
+    ///
+    /// assert!(MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22).matches(pattern, mask));
+    /// assert!(!MacAddr6::new(0xAC, 0xDE, 0x49, 0x00, 0x11, 0x22).matches(pattern, mask));
+    /// assert!(MacAddr6::broadcast().matches(pattern, MacAddr6::nil()));
+    /// ```
+    ///
+    /// [`MacAddrMask`]: struct.MacAddrMask.html
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    pub const fn matches(&self, pattern: MacAddr6, mask: MacAddr6) -> bool {
+        self.to_u64() & mask.to_u64() == pattern.to_u64() & mask.to_u64()
+    }
+
+    /// Returns the size of the IEEE Registration Authority assignment the address was allocated from.
+    ///
+    /// Locally administered addresses in the Extended Local Identifier quadrant of the IEEE 802c
+    /// Structured Local Address Plan are assigned from a [Company ID], and the universally administered ones
+    /// are from an MA-S block if their OUI is one of the MA-S parents, or from an MA-L block otherwise.
+    ///
+    /// MA-M blocks are assigned from the regular OUIs, so they can only be told apart from the MA-L ones
+    /// with the embedded registry snapshot of the `"oui-db"` feature; without it they are reported as [MA-L].
+    ///
+    /// Returns `None` for the other locally administered addresses, which are not assigned by the IEEE.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::{AssignmentBlock, MacAddr6};
+    /// assert_eq!(MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22).assignment_block(), Some(AssignmentBlock::MaL));
+    /// assert_eq!(MacAddr6::new(0x00, 0x50, 0xC2, 0x00, 0x11, 0x22).assignment_block(), Some(AssignmentBlock::MaS));
+    /// assert_eq!(MacAddr6::new(0x0A, 0xDE, 0x48, 0x00, 0x11, 0x22).assignment_block(), Some(AssignmentBlock::Cid));
+    /// assert_eq!(MacAddr6::new(0x02, 0xDE, 0x48, 0x00, 0x11, 0x22).assignment_block(), None);
+    /// ```
+    ///
+    /// [Company ID]: enum.AssignmentBlock.html#variant.Cid
+    /// [MA-L]: enum.AssignmentBlock.html#variant.MaL
+    pub const fn assignment_block(&self) -> Option<AssignmentBlock> {
+        block::classify(self)
+    }
+
+    /// Returns the IEEE 802c Structured Local Address Plan quadrant of the locally administered address.
+    ///
+    /// Returns `None` for the universally administered addresses.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::{MacAddr6, SlapQuadrant};
+    /// assert_eq!(MacAddr6::new(0x02, 0x00, 0x00, 0x00, 0x00, 0x01).slap_quadrant(), Some(SlapQuadrant::Aai));
+    /// assert_eq!(MacAddr6::new(0x0A, 0xDE, 0x48, 0x00, 0x00, 0x01).slap_quadrant(), Some(SlapQuadrant::Eli));
+    /// assert_eq!(MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22).slap_quadrant(), None);
+    /// ```
+    pub const fn slap_quadrant(&self) -> Option<SlapQuadrant> {
+        if self.is_local() {
+            Some(SlapQuadrant::from_bits(self.0[0]))
+        } else {
+            None
+        }
+    }
+
+    /// Generates a random unicast address in the Administratively Assigned Identifier quadrant
+    /// of the IEEE 802c Structured Local Address Plan with the `rng` random number generator,
+    /// as recommended for the MAC address randomization.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::{MacAddr6, SlapQuadrant};
+    /// # let mut rng = rand::rngs::mock::StepRng::new(u64::MAX, 1);
+    /// let addr = MacAddr6::new_aai_random(&mut rng);
+    ///
+    /// assert!(addr.is_unicast());
+    /// assert_eq!(addr.slap_quadrant(), Some(SlapQuadrant::Aai));
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn new_aai_random<R: rand::Rng + ?Sized>(rng: &mut R) -> MacAddr6 {
+        MacAddr6::random_local_unicast(rng).with_slap_quadrant(SlapQuadrant::Aai)
+    }
+
+    /// Creates the unicast address in the Extended Local Identifier quadrant
+    /// of the IEEE 802c Structured Local Address Plan, made of the Company ID and the NIC-specific bytes.
+    ///
+    /// Company ID bits selecting the SLAP quadrant are overwritten, and so are the multicast
+    /// and the locally administered bits.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::{AssignmentBlock, MacAddr6, SlapQuadrant};
+    /// let addr = MacAddr6::new_eli([0x0A, 0xDE, 0x48], [0x00, 0x11, 0x22]);
+    ///
+    /// assert_eq!(addr, MacAddr6::new(0x0A, 0xDE, 0x48, 0x00, 0x11, 0x22));
+    /// assert_eq!(addr.assignment_block(), Some(AssignmentBlock::Cid));
+    /// ```
+    pub const fn new_eli(cid: [u8; 3], nic: [u8; 3]) -> MacAddr6 {
+        let [a, b, c] = cid;
+        let [d, e, f] = nic;
+
+        MacAddr6([a & !1 | 1 << 1, b, c, d, e, f]).with_slap_quadrant(SlapQuadrant::Eli)
+    }
+
+    /// Returns `true` if the address belongs to the IANA-managed `00:00:5E` unicast or `01:00:5E` multicast blocks
+    /// (RFC 7042, section 2).
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// assert_eq!(MacAddr6::new(0x00, 0x00, 0x5E, 0x00, 0x53, 0x01).is_iana_reserved(), true);
+    /// assert_eq!(MacAddr6::new(0x01, 0x00, 0x5E, 0x00, 0x00, 0xFB).is_iana_reserved(), true);
+    /// assert_eq!(MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22).is_iana_reserved(), false);
+    /// ```
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    pub const fn is_iana_reserved(&self) -> bool {
+        matches!(self.0, [0x00 | 0x01, 0x00, 0x5E, _, _, _])
+    }
+
+    /// Returns the protocol the address of the IANA-managed blocks is assigned for,
+    /// or `None` if the address is outside of them.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::{IanaAssignment, MacAddr6};
+    /// let vrrp = MacAddr6::new(0x00, 0x00, 0x5E, 0x00, 0x01, 0x2A);
+    /// let mpls = MacAddr6::new(0x01, 0x00, 0x5E, 0x80, 0x00, 0x01);
+    ///
+    /// assert_eq!(vrrp.iana_assignment(), Some(IanaAssignment::VrrpIpv4));
+    /// assert_eq!(mpls.iana_assignment(), Some(IanaAssignment::MplsMulticast));
+    /// assert_eq!(MacAddr6::new(0x00, 0x00, 0x5E, 0x12, 0x34, 0x56).is_iana_reserved(), true);
+    /// assert_eq!(MacAddr6::broadcast().iana_assignment(), None);
+    /// ```
+    pub const fn iana_assignment(&self) -> Option<IanaAssignment> {
+        block::classify_iana(self)
+    }
+
+    /// Returns the address with the Z and Y bits of the first byte set for the SLAP quadrant.
+    const fn with_slap_quadrant(self, quadrant: SlapQuadrant) -> MacAddr6 {
+        let mut bytes = self.0;
+        bytes[0] = bytes[0] & !SlapQuadrant::MASK | quadrant.bits();
+
+        MacAddr6(bytes)
+    }
+}
+
+/// `MacAddr6` can be parsed from the hyphen- or colon-separated hex notation,
+/// from the Cisco-style dot-separated 16-bit groups
+/// or from the bare hex digits without any delimiters.
+///
+/// # Example
+///
+/// ```
+/// # use macaddr::MacAddr6;
+/// let addr = MacAddr6::new(0xab, 0x0d, 0xef, 0x12, 0x34, 0x56);
+///
+/// assert_eq!("AB-0D-EF-12-34-56".parse(), Ok(addr));
+/// assert_eq!("ab:0d:ef:12:34:56".parse(), Ok(addr));
+/// assert_eq!("ab0d.ef12.3456".parse(), Ok(addr));
+/// assert_eq!("AB0DEF123456".parse(), Ok(addr));
+/// assert_eq!(addr.to_string().parse(), Ok(addr));
+/// ```
+impl FromStr for MacAddr6 {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parser::Parser::new(s).read_v6_addr()
+    }
+}
+
+impl<'a> TryFrom<&'a str> for MacAddr6 {
+    type Error = ParseError;
+
+    fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+        parser::Parser::new(s).read_v6_addr()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl TryFrom<String> for MacAddr6 {
+    type Error = ParseError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        parser::Parser::new(&s).read_v6_addr()
+    }
+}
+
+impl From<[u8; 6]> for MacAddr6 {
+    fn from(bytes: [u8; 6]) -> Self {
+        MacAddr6(bytes)
+    }
+}
+
+impl<'a> From<&'a [u8; 6]> for MacAddr6 {
+    fn from(bytes: &'a [u8; 6]) -> Self {
+        MacAddr6(*bytes)
+    }
+}
+
+impl From<MacAddr6> for [u8; 6] {
+    fn from(addr: MacAddr6) -> Self {
+        addr.0
+    }
+}
+
+impl From<MacAddr6> for u64 {
+    fn from(addr: MacAddr6) -> Self {
+        addr.to_u64()
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for MacAddr6 {
+    type Error = ParseError;
+
+    fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        <[u8; 6]>::try_from(bytes)
+            .map(MacAddr6)
+            .map_err(|_| ParseError::InvalidLength(bytes.len()))
+    }
+}
+
+#[cfg(feature = "prost")]
+impl From<MacAddr6> for bytes::Bytes {
+    fn from(addr: MacAddr6) -> Self {
+        bytes::Bytes::copy_from_slice(&addr.0)
+    }
+}
+
+/// Converts the protobuf `bytes` field value, failing with [`ParseError::InvalidLength`]
+/// if it is not exactly 6 bytes long.
+#[cfg(feature = "prost")]
+impl TryFrom<bytes::Bytes> for MacAddr6 {
+    type Error = ParseError;
+
+    fn try_from(bytes: bytes::Bytes) -> Result<Self, Self::Error> {
+        MacAddr6::try_from(bytes.as_ref())
+    }
+}
+
+impl AsRef<[u8]> for MacAddr6 {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl AsMut<[u8]> for MacAddr6 {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+crate::macros::impl_bytes_cmp!(
+    /// `MacAddr6` can be compared with the bytes arrays and slices directly,
+    /// slices of the other lengths are never equal to the address.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use macaddr::MacAddr6;
+    /// let addr = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22);
+    /// let bytes: &[u8] = &[0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22];
+    ///
+    /// assert!(addr == [0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22]);
+    /// assert!(addr == bytes);
+    /// assert!(addr != bytes[1..]);
+    /// assert!(addr < [0xFF; 6]);
+    /// ```
+    MacAddr6,
+    [u8; 6]
+);
+crate::macros::impl_bytes_cmp!(MacAddr6, [u8]);
+crate::macros::impl_bytes_cmp!(MacAddr6, &'a [u8], 'a);
+
+crate::macros::impl_bit_ops!(
+    /// `MacAddr6` supports the bitwise `!`, `&`, `|` and `^` operators along with their assigning forms,
+    /// applied to the address bytes, so the wildcard masks can be written as in the ACL rules.
+    ///
+    /// Right operand can be the other address, the bytes array or the big-endian integer
+    /// as returned by the `to_u64` method, the upper 16 bits of which are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use macaddr::MacAddr6;
+    /// let addr = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22);
+    /// let mask = MacAddr6::new(0xFF, 0xFF, 0xFF, 0x00, 0x00, 0x00);
+    ///
+    /// assert_eq!(addr & mask, MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x00, 0x00));
+    /// assert_eq!(addr & [0xFF, 0xFF, 0xFF, 0x00, 0x00, 0x00], addr & mask);
+    /// assert_eq!(addr ^ addr.to_u64(), MacAddr6::nil());
+    /// assert_eq!(!MacAddr6::nil(), MacAddr6::broadcast());
+    ///
+    /// let mut addr = addr;
+    /// addr |= MacAddr6::broadcast();
+    /// assert!(addr.is_broadcast());
+    /// ```
+    MacAddr6,
+    6
+);
+
+/// `MacAddr6` is debug-formatted in the IEEE 802 canonical notation, as in `MacAddr6(01-23-45-67-89-AB)`.
+///
+/// # Example
+///
+/// ```
+/// # use macaddr::MacAddr6;
+/// let addr = MacAddr6::new(0x01, 0x23, 0x45, 0x67, 0x89, 0xAB);
+///
+/// assert_eq!(&format!("{:?}", addr), "MacAddr6(01-23-45-67-89-AB)");
+/// assert_eq!(&format!("{:?}", Some(addr)), "Some(MacAddr6(01-23-45-67-89-AB))");
+/// ```
+impl fmt::Debug for MacAddr6 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("MacAddr6").field(&format_args!("{:-}", self)).finish()
+    }
+}
+
+/// `MacAddr6` is rendered by the defmt host in the same notation as with the `Display` implementation,
+/// as in `AB:0D:EF:12:34:56`.
+#[cfg(feature = "defmt")]
+impl defmt::Format for MacAddr6 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "{=u8:02X}:{=u8:02X}:{=u8:02X}:{=u8:02X}:{=u8:02X}:{=u8:02X}",
+            self.0[0], self.0[1], self.0[2], self.0[3], self.0[4], self.0[5],
+        )
+    }
+}
+
+#[cfg(feature = "ufmt")]
+impl ufmt::uDisplay for MacAddr6 {
+    fn fmt<W: ufmt::uWrite + ?Sized>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error> {
+        display::uwrite_encoded(f, "", &self.0, ':', "")
+    }
+}
+
+#[cfg(feature = "ufmt")]
+impl ufmt::uDebug for MacAddr6 {
+    fn fmt<W: ufmt::uWrite + ?Sized>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error> {
+        display::uwrite_encoded(f, "MacAddr6(", &self.0, '-', ")")
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl borsh::BorshSerialize for MacAddr6 {
+    fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+        writer.write_all(&self.0)
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl borsh::BorshDeserialize for MacAddr6 {
+    fn deserialize_reader<R: borsh::io::Read>(reader: &mut R) -> borsh::io::Result<Self> {
+        <[u8; 6]>::deserialize_reader(reader).map(MacAddr6)
+    }
+}
+
+#[cfg(feature = "speedy")]
+impl<'a, C: speedy::Context> speedy::Readable<'a, C> for MacAddr6 {
+    fn read_from<R: speedy::Reader<'a, C>>(reader: &mut R) -> Result<Self, C::Error> {
+        let mut bytes = [0; 6];
+        reader.read_bytes(&mut bytes)?;
+
+        Ok(MacAddr6(bytes))
+    }
+
+    fn minimum_bytes_needed() -> usize {
+        6
+    }
+}
+
+#[cfg(feature = "speedy")]
+impl<C: speedy::Context> speedy::Writable<C> for MacAddr6 {
+    fn write_to<W: ?Sized + speedy::Writer<C>>(&self, writer: &mut W) -> Result<(), C::Error> {
+        writer.write_bytes(&self.0)
+    }
+
+    fn bytes_needed(&self) -> Result<usize, C::Error> {
+        Ok(6)
+    }
+}
+
+#[cfg(feature = "bincode")]
+impl bincode::Encode for MacAddr6 {
+    fn encode<E: bincode::enc::Encoder>(&self, encoder: &mut E) -> Result<(), bincode::error::EncodeError> {
+        self.0.encode(encoder)
+    }
+}
+
+#[cfg(feature = "bincode")]
+impl<Context> bincode::Decode<Context> for MacAddr6 {
+    fn decode<D: bincode::de::Decoder<Context = Context>>(
+        decoder: &mut D,
+    ) -> Result<Self, bincode::error::DecodeError> {
+        <[u8; 6]>::decode(decoder).map(MacAddr6)
+    }
+}
+
+#[cfg(feature = "bincode")]
+bincode::impl_borrow_decode!(MacAddr6);
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for MacAddr6 {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        u.arbitrary().map(MacAddr6)
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (6, Some(6))
+    }
+}
+
+/// Addresses are shrunk towards the nil address.
+#[cfg(feature = "quickcheck")]
+impl quickcheck::Arbitrary for MacAddr6 {
+    fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+        MacAddr6(quickcheck::Arbitrary::arbitrary(g))
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        if self.is_nil() {
+            return quickcheck::empty_shrinker();
+        }
+
+        let shrunk = self.0.shrink().map(MacAddr6).filter(|addr| !addr.is_nil());
+        Box::new(core::iter::once(MacAddr6::nil()).chain(shrunk))
+    }
+}
+
+#[cfg(feature = "rand")]
+impl rand::distributions::Distribution<MacAddr6> for rand::distributions::Standard {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> MacAddr6 {
+        MacAddr6::random(rng)
+    }
+}
+
+/// Addresses are stored in the PostgreSQL `MACADDR` columns.
+#[cfg(feature = "postgres")]
+impl<'a> postgres_types::FromSql<'a> for MacAddr6 {
+    fn from_sql(
+        _ty: &postgres_types::Type,
+        raw: &'a [u8],
+    ) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+        Ok(MacAddr6::try_from(raw)?)
+    }
+
+    postgres_types::accepts!(MACADDR);
+}
+
+#[cfg(feature = "postgres")]
+impl postgres_types::ToSql for MacAddr6 {
+    fn to_sql(
+        &self,
+        _ty: &postgres_types::Type,
+        out: &mut bytes::BytesMut,
+    ) -> Result<postgres_types::IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        out.extend_from_slice(&self.0);
+
+        Ok(postgres_types::IsNull::No)
+    }
+
+    postgres_types::accepts!(MACADDR);
+    postgres_types::to_sql_checked!();
+}
+
+#[cfg(feature = "diesel")]
+impl diesel::deserialize::FromSql<diesel::sql_types::MacAddr, diesel::pg::Pg> for MacAddr6 {
+    fn from_sql(value: diesel::pg::PgValue<'_>) -> diesel::deserialize::Result<Self> {
+        Ok(MacAddr6::try_from(value.as_bytes())?)
+    }
+}
+
+#[cfg(feature = "diesel")]
+impl diesel::serialize::ToSql<diesel::sql_types::MacAddr, diesel::pg::Pg> for MacAddr6 {
+    fn to_sql<'b>(&'b self, out: &mut diesel::serialize::Output<'b, '_, diesel::pg::Pg>) -> diesel::serialize::Result {
+        std::io::Write::write_all(out, &self.0)?;
+
+        Ok(diesel::serialize::IsNull::No)
+    }
+}
+
+/// Addresses are stored in SQLite as the 6 bytes blobs;
+/// the text values in any notation accepted by the `FromStr` implementation are read too.
+#[cfg(feature = "rusqlite")]
+impl rusqlite::types::ToSql for MacAddr6 {
+    fn to_sql(&self) -> rusqlite::Result<rusqlite::types::ToSqlOutput<'_>> {
+        Ok(rusqlite::types::ToSqlOutput::Borrowed(rusqlite::types::ValueRef::Blob(&self.0)))
+    }
+}
+
+#[cfg(feature = "rusqlite")]
+impl rusqlite::types::FromSql for MacAddr6 {
+    fn column_result(value: rusqlite::types::ValueRef<'_>) -> rusqlite::types::FromSqlResult<Self> {
+        match value {
+            rusqlite::types::ValueRef::Blob(blob) => {
+                MacAddr6::try_from(blob).map_err(|_| rusqlite::types::FromSqlError::InvalidBlobSize {
+                    expected_size: 6,
+                    blob_size: blob.len(),
+                })
+            }
+            rusqlite::types::ValueRef::Text(text) => {
+                MacAddr6::parse_ascii(text).map_err(|err| rusqlite::types::FromSqlError::Other(Box::new(err)))
+            }
+            _ => Err(rusqlite::types::FromSqlError::InvalidType),
+        }
+    }
+}
+
+/// `MacAddr6` is hashed as the single `u64` integer returned by the [`as_hash_key`] method.
+///
+/// [`as_hash_key`]: #method.as_hash_key
+impl Hash for MacAddr6 {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u64(self.as_hash_key());
+    }
+}
+
+/// `MacAddr6` fits into the single `u64` integer, so the `nohash_hasher` maps can be keyed by it.
+#[cfg(feature = "nohash-hasher")]
+impl nohash_hasher::IsEnabled for MacAddr6 {}
+
+/// `MacAddr6` can be displayed in different formats.
+///
+/// Bytes are separated with colons by default or with hyphens if the `-` flag is set.
+/// Hex digits are uppercase by default or lowercase if the alternate `#` flag is set.
+/// Width, fill and alignment flags are respected.
+///
+/// # Example
+///
+/// ```
+/// # use macaddr::MacAddr6;
+/// let addr = MacAddr6::new(0xab, 0x0d, 0xef, 0x12, 0x34, 0x56);
+///
+/// assert_eq!(&format!("{}",    addr), "AB:0D:EF:12:34:56");
+/// assert_eq!(&format!("{:-}",  addr), "AB-0D-EF-12-34-56");
+/// assert_eq!(&format!("{:#}",  addr), "ab:0d:ef:12:34:56");
+/// assert_eq!(&format!("{:-#}", addr), "ab-0d-ef-12-34-56");
+/// assert_eq!(&format!("{:>20}", addr), "   AB:0D:EF:12:34:56");
+/// ```
+impl fmt::Display for MacAddr6 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let separator = if f.sign_minus() { '-' } else { ':' };
+
+        display::write_grouped(f, &self.0, Some(separator), 1, !f.alternate())
+    }
+}
+
+/// `MacAddr6` can be formatted as the bare hex digits, prefixed with `0x` if the alternate `#` flag is set.
+///
+/// # Example
+///
+/// ```
+/// # use macaddr::MacAddr6;
+/// let addr = MacAddr6::new(0xab, 0x0d, 0xef, 0x12, 0x34, 0x56);
+///
+/// assert_eq!(&format!("{:x}",  addr), "ab0def123456");
+/// assert_eq!(&format!("{:#x}", addr), "0xab0def123456");
+/// ```
+impl fmt::LowerHex for MacAddr6 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        display::write_hex(f, &self.0, false)
+    }
+}
+
+/// `MacAddr6` can be formatted as the bare hex digits, prefixed with `0x` if the alternate `#` flag is set.
+///
+/// # Example
+///
+/// ```
+/// # use macaddr::MacAddr6;
+/// let addr = MacAddr6::new(0xab, 0x0d, 0xef, 0x12, 0x34, 0x56);
+///
+/// assert_eq!(&format!("{:X}",  addr), "AB0DEF123456");
+/// assert_eq!(&format!("{:#X}", addr), "0xAB0DEF123456");
+/// ```
+impl fmt::UpperHex for MacAddr6 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        display::write_hex(f, &self.0, true)
+    }
+}
+
+/// `MacAddr6` can be formatted as the 48-bit binary number, prefixed with `0b` if the alternate `#` flag is set.
+///
+/// Leading zeros are always included, so the position of each bit is preserved.
+///
+/// # Example
+///
+/// ```
+/// # use macaddr::MacAddr6;
+/// let addr = MacAddr6::new(0x80, 0x00, 0x00, 0x00, 0x00, 0x03);
+///
+/// assert_eq!(&format!("{:b}",  addr), "100000000000000000000000000000000000000000000011");
+/// assert_eq!(format!("{:#b}", MacAddr6::nil()).len(), 50);
+/// ```
+impl fmt::Binary for MacAddr6 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        display::write_radix(f, &self.0, 1, "0b")
+    }
+}
+
+/// `MacAddr6` can be formatted as the octal number, prefixed with `0o` if the alternate `#` flag is set.
+///
+/// Leading zeros are always included.
+///
+/// # Example
+///
+/// ```
+/// # use macaddr::MacAddr6;
+/// let addr = MacAddr6::new(0x80, 0x00, 0x00, 0x00, 0x00, 0x03);
+///
+/// assert_eq!(&format!("{:o}",  addr), "4000000000000003");
+/// assert_eq!(&format!("{:#o}", addr), "0o4000000000000003");
+/// ```
+impl fmt::Octal for MacAddr6 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        display::write_radix(f, &self.0, 3, "0o")
     }
 }
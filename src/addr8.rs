@@ -1,14 +1,43 @@
-use core::{fmt, str::FromStr};
+use core::{
+    convert::TryFrom,
+    fmt,
+    hash::{Hash, Hasher},
+    ops::Range,
+    str::FromStr,
+};
 
-use crate::parser;
+#[cfg(feature = "alloc")]
+use alloc::string::{String, ToString};
+
+use crate::{
+    display::{
+        self, BufferTooSmall, DisplayBare, DisplayColon, DisplayDotted, DisplayDottedOctets, DisplayFormat,
+        DisplayHyphen, DisplayInterfaceId, DisplayWwn,
+    },
+    find, parser, MacAddr6, MacFormat, ParseError, ParseOptions,
+};
 
 /// MAC address in *EUI-64* format.
 #[repr(C)]
-#[derive(Debug, Default, Hash, Eq, PartialEq, Ord, PartialOrd, Copy, Clone)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Default, Eq, PartialEq, Ord, PartialOrd, Copy, Clone)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    rkyv(derive(Debug, Hash, Eq, PartialEq, Ord, PartialOrd), compare(PartialEq))
+)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::KnownLayout, zerocopy::Immutable, zerocopy::Unaligned)
+)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
 pub struct MacAddr8([u8; 8]);
 
 impl MacAddr8 {
+    /// Maximum length of the `MacAddr8` address written by the [`MacAddr8::write_str`] method.
+    ///
+    /// [`MacAddr8::write_str`]: #method.write_str
+    pub const MAX_DISPLAY_LEN: usize = 23;
+
     /// Creates a new `MacAddr8` address from the bytes.
     ///
     /// ## Example
@@ -138,90 +167,1386 @@ impl MacAddr8 {
         self.0[0] & 1 << 1 == 2
     }
 
-    /// Converts a `MacAddr8` address to a byte slice.
+    /// Returns `true` if the address is in one of the ranges reserved for the documentation examples by RFC 7042,
+    /// `00:00:5E:EF:10:00:00:00` to `00:00:5E:EF:10:00:00:FF` for the unicast
+    /// and `01:00:5E:EF:10:00:00:00` to `01:00:5E:EF:10:00:00:FF` for the multicast addresses.
     ///
     /// ## Example
     ///
     /// ```rust
     /// # use macaddr::MacAddr8;
-    /// let addr = MacAddr8::new(0xAC, 0xDE, 0x48, 0x23, 0x45, 0x67, 0x89, 0xAB);
+    /// let addr = MacAddr8::new(0x00, 0x00, 0x5E, 0xEF, 0x10, 0x00, 0x00, 0x01);
     ///
-    /// assert_eq!(addr.as_bytes(), &[0xAC, 0xDE, 0x48, 0x23, 0x45, 0x67, 0x89, 0xAB]);
+    /// assert_eq!(addr.is_documentation(), true);
+    /// assert_eq!(MacAddr8::new(0x00, 0x00, 0x5E, 0xEF, 0x11, 0x00, 0x00, 0x01).is_documentation(), false);
     /// ```
-    pub fn as_bytes(&self) -> &[u8] {
-        &self.0
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    pub const fn is_documentation(&self) -> bool {
+        matches!(self.0, [0x00 | 0x01, 0x00, 0x5E, 0xEF, 0x10, 0x00, 0x00, _])
     }
 
-    /// Consumes a `MacAddr8` address and returns raw bytes.
+    /// Returns `true` if the address is locally administered and unicast,
+    /// like the randomized addresses used by the mobile devices to prevent the tracking.
+    ///
+    /// Such addresses can't be attributed to a vendor and are usually changing over time,
+    /// but manually assigned locally administered addresses are matched too.
     ///
     /// ## Example
     ///
     /// ```rust
     /// # use macaddr::MacAddr8;
-    /// let addr = MacAddr8::new(0xAC, 0xDE, 0x48, 0x23, 0x45, 0x67, 0x89, 0xAB);
+    /// assert_eq!(MacAddr8::new(0xDA, 0xA1, 0x19, 0x6B, 0x22, 0x0E, 0x33, 0x44).is_randomized(), true);
+    /// assert_eq!(MacAddr8::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44).is_randomized(), false);
+    /// ```
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    pub const fn is_randomized(&self) -> bool {
+        self.is_local() && self.is_unicast()
+    }
+
+    /// Generates a random address with the `rng` random number generator.
     ///
-    /// assert_eq!(addr.into_array(), [0xAC, 0xDE, 0x48, 0x23, 0x45, 0x67, 0x89, 0xAB]);
+    /// Any address can be generated, including the multicast ones;
+    /// see the [`random_local_unicast`](#method.random_local_unicast) method for the interface addresses.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr8;
+    /// # let mut rng = rand::rngs::mock::StepRng::new(0, 1);
+    /// let addr = MacAddr8::random(&mut rng);
     /// ```
-    pub const fn into_array(self) -> [u8; 8] {
-        self.0
+    #[cfg(feature = "rand")]
+    pub fn random<R: rand::Rng + ?Sized>(rng: &mut R) -> MacAddr8 {
+        let mut bytes = [0; 8];
+        rng.fill_bytes(&mut bytes);
+
+        MacAddr8(bytes)
     }
-}
 
-impl FromStr for MacAddr8 {
-    type Err = parser::ParseError;
+    /// Generates a random locally administered unicast address with the `rng` random number generator,
+    /// as used for the randomized interface addresses.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr8;
+    /// # let mut rng = rand::rngs::mock::StepRng::new(0, 1);
+    /// let addr = MacAddr8::random_local_unicast(&mut rng);
+    ///
+    /// assert!(addr.is_local());
+    /// assert!(addr.is_unicast());
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn random_local_unicast<R: rand::Rng + ?Sized>(rng: &mut R) -> MacAddr8 {
+        let mut addr = MacAddr8::random(rng);
+        addr.0[0] = addr.0[0] & !1 | 1 << 1;
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        addr
+    }
+
+    /// Parses a `MacAddr8` address from the string in a `const` context.
+    ///
+    /// Accepts the same notations as the `FromStr` implementation.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the string is not a valid `MacAddr8` address,
+    /// which fails the compilation when used to define a `const` item.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr8;
+    /// const ADDR: MacAddr8 = MacAddr8::parse_const("AC:DE:48:00:11:22:33:44");
+    ///
+    /// assert_eq!(ADDR, MacAddr8::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44));
+    /// ```
+    pub const fn parse_const(s: &str) -> MacAddr8 {
+        parser::unwrap_const(parser::Parser::new(s).read_v8_addr())
+    }
+
+    /// Parses a `MacAddr8` address from the string in a `const` context,
+    /// returning an error instead of panicking.
+    ///
+    /// Accepts the same notations as the `FromStr` implementation.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::{MacAddr8, ParseError};
+    /// const ADDR: Result<MacAddr8, ParseError> = MacAddr8::try_parse_const("AC:DE:48:00:11:22:33:44");
+    /// const INVALID: Result<MacAddr8, ParseError> = MacAddr8::try_parse_const("AC:DE:48");
+    ///
+    /// assert_eq!(ADDR, Ok(MacAddr8::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44)));
+    /// assert_eq!(INVALID, Err(ParseError::InvalidLength(8)));
+    /// ```
+    pub const fn try_parse_const(s: &str) -> Result<MacAddr8, ParseError> {
         parser::Parser::new(s).read_v8_addr()
     }
-}
 
-impl From<[u8; 8]> for MacAddr8 {
-    fn from(bytes: [u8; 8]) -> Self {
-        MacAddr8(bytes)
+    /// Parses a `MacAddr8` address from the ASCII text without checking it for UTF-8 validity first.
+    ///
+    /// Accepts the same notations as the `FromStr` implementation.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr8;
+    /// let addr = MacAddr8::parse_ascii(b"AC:DE:48:00:11:22:33:44");
+    ///
+    /// assert_eq!(addr, Ok(MacAddr8::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44)));
+    /// ```
+    pub fn parse_ascii(s: &[u8]) -> Result<MacAddr8, ParseError> {
+        parser::Parser::from_ascii(s, ParseOptions::new()).read_v8_addr()
     }
-}
 
-impl AsRef<[u8]> for MacAddr8 {
-    fn as_ref(&self) -> &[u8] {
-        &self.0
+    /// Parses a `MacAddr8` address from the string, accepting only the notations enabled in `options`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::{MacAddr8, ParseOptions};
+    /// let options = ParseOptions::new().bare(false);
+    ///
+    /// assert!(MacAddr8::parse_with("AC-DE-48-00-11-22-33-44", &options).is_ok());
+    /// assert!(MacAddr8::parse_with(&"AC-DE-48-00-11-22-33-44".replace('-', ""), &options).is_err());
+    /// ```
+    pub fn parse_with(s: &str, options: &ParseOptions) -> Result<MacAddr8, ParseError> {
+        parser::Parser::with_options(s, *options).read_v8_addr()
     }
-}
 
-impl AsMut<[u8]> for MacAddr8 {
-    fn as_mut(&mut self) -> &mut [u8] {
-        &mut self.0
+    /// Parses a `MacAddr8` address from the string, tolerating the common formatting noise.
+    ///
+    /// Unlike the `FromStr` implementation, surrounding whitespace is ignored
+    /// and any of the `-`, `:` and `.` delimiters (or none) might be used between each byte,
+    /// as in the `AC.DE.48.00.11.22` notation with dots between every byte.
+    /// Trailing interface annotation starting with `%`, as in `01-23-45-67-89-AB%12`, is stripped.
+    /// Bytes without zero padding, as in `0:a:95:9d:68:16`, are accepted between the `-` and `:` delimiters.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr8;
+    /// let addr = MacAddr8::parse_lenient(" AC:DE-48:00.11:22:33-44\n");
+    ///
+    /// assert_eq!(addr, Ok(MacAddr8::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44)));
+    /// ```
+    pub fn parse_lenient(s: &str) -> Result<MacAddr8, ParseError> {
+        parser::Parser::lenient(s).read_v8_addr()
     }
-}
 
-/// `MacAddr8` can be displayed in different formats.
-///
-/// # Example
-///
-/// ```
-/// # use macaddr::MacAddr8;
-/// let addr = MacAddr8::new(0xab, 0x0d, 0xef, 0x12, 0x34, 0x56, 0x78, 0x9A);
-///
-/// assert_eq!(&format!("{}",    addr), "AB:0D:EF:12:34:56:78:9A");
-/// assert_eq!(&format!("{:-}",  addr), "AB-0D-EF-12-34-56-78-9A");
-/// assert_eq!(&format!("{:#}",  addr), "AB0D.EF12.3456.789A");
-/// ```
-impl fmt::Display for MacAddr8 {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        if f.sign_minus() {
-            f.write_fmt(format_args!(
-                "{:02X}-{:02X}-{:02X}-{:02X}-{:02X}-{:02X}-{:02X}-{:02X}",
-                self.0[0], self.0[1], self.0[2], self.0[3], self.0[4], self.0[5], self.0[6], self.0[7],
-            ))
-        } else if f.alternate() {
-            f.write_fmt(format_args!(
-                "{:02X}{:02X}.{:02X}{:02X}.{:02X}{:02X}.{:02X}{:02X}",
-                self.0[0], self.0[1], self.0[2], self.0[3], self.0[4], self.0[5], self.0[6], self.0[7],
-            ))
-        } else {
-            f.write_fmt(format_args!(
-                "{:02X}:{:02X}:{:02X}:{:02X}:{:02X}:{:02X}:{:02X}:{:02X}",
-                self.0[0], self.0[1], self.0[2], self.0[3], self.0[4], self.0[5], self.0[6], self.0[7],
-            ))
+    /// Searches for the first `MacAddr8` address in the free-form text.
+    ///
+    /// Returns the byte range of the address in the text along with the parsed address.
+    ///
+    /// Addresses in any notation supported by the `FromStr` implementation are matched,
+    /// except for the bare hex form, which is indistinguishable from the plain numbers.
+    /// Matched address should not be a part of a longer word or a longer address.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr8;
+    /// let text = "eui64 ac:de:48:00:11:22:33:44, ff:ff:ff:ff:ff:ff:ff:ff.";
+    /// let addr = MacAddr8::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44);
+    ///
+    /// assert_eq!(MacAddr8::find_in_str(text), Some((6..29, addr)));
+    /// assert_eq!(MacAddr8::find_in_str("no addresses here"), None);
+    /// ```
+    pub fn find_in_str(s: &str) -> Option<(Range<usize>, MacAddr8)> {
+        find::find(s, 0, MacAddr8::match_at)
+    }
+
+    /// Returns an iterator over all `MacAddr8` addresses in the free-form text.
+    ///
+    /// See [`MacAddr8::find_in_str`] for the matching rules.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr8;
+    /// let text = "eui64 ac:de:48:00:11:22:33:44, ff:ff:ff:ff:ff:ff:ff:ff.";
+    /// let addrs: Vec<MacAddr8> = MacAddr8::find_all_in_str(text).map(|(_, addr)| addr).collect();
+    ///
+    /// let addr = MacAddr8::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44);
+    ///
+    /// assert_eq!(addrs, vec![addr, MacAddr8::broadcast()]);
+    /// ```
+    ///
+    /// [`MacAddr8::find_in_str`]: #method.find_in_str
+    pub fn find_all_in_str(s: &str) -> impl Iterator<Item = (Range<usize>, MacAddr8)> + '_ {
+        find::find_all(s, MacAddr8::match_at)
+    }
+
+    fn match_at(s: &str, start: usize) -> Option<(Range<usize>, MacAddr8)> {
+        find::match_at(s, start, 16, parser::Parser::read_v8_prefix)
+    }
+
+    /// Converts the *EUI-64* address back into the *EUI-48* format by stripping the `FF:FE` bytes
+    /// between the OUI and the NIC-specific halves.
+    ///
+    /// Returns `None` if the address does not contain these bytes, i.e. it is not derived from an *EUI-48* address.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::{MacAddr6, MacAddr8};
+    /// let addr = MacAddr8::new(0xAC, 0xDE, 0x48, 0xFF, 0xFE, 0x00, 0x11, 0x22);
+    ///
+    /// assert_eq!(addr.to_eui48(), Some(MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22)));
+    /// assert_eq!(MacAddr8::nil().to_eui48(), None);
+    /// ```
+    pub const fn to_eui48(&self) -> Option<MacAddr6> {
+        match self.0 {
+            [a, b, c, 0xFF, 0xFE, d, e, f] => Some(MacAddr6::new(a, b, c, d, e, f)),
+            _ => None,
         }
     }
+
+    /// Returns an iterator over the `MacAddr8` addresses packed one after another in the byte buffer,
+    /// as in the binary address tables dumped from the switches.
+    ///
+    /// Buffer length is expected to be divisible by 8, trailing bytes not forming a whole address are ignored.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr8;
+    /// let bytes = [0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF];
+    /// let addrs: Vec<MacAddr8> = MacAddr8::iter_from_bytes(&bytes).collect();
+    ///
+    /// assert_eq!(addrs, vec![MacAddr8::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44), MacAddr8::broadcast()]);
+    /// assert_eq!(MacAddr8::iter_from_bytes(&bytes[..12]).count(), 1);
+    /// ```
+    pub fn iter_from_bytes(bytes: &[u8]) -> impl Iterator<Item = MacAddr8> + '_ {
+        bytes.chunks_exact(8).map(|chunk| {
+            let mut addr = [0; 8];
+            addr.copy_from_slice(chunk);
+            MacAddr8(addr)
+        })
+    }
+
+    /// Writes the address in the colon-separated uppercase notation, as in `AC:DE:48:00:11:22:33:44`,
+    /// into the start of the `buf` buffer without allocating or using the `core::fmt` machinery.
+    ///
+    /// Returns the written part of the buffer or [`BufferTooSmall`] error
+    /// if the buffer is shorter than the written address.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr8;
+    /// let addr = MacAddr8::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44);
+    /// let mut buf = [0; MacAddr8::MAX_DISPLAY_LEN];
+    ///
+    /// assert_eq!(addr.write_str(&mut buf), Ok("AC:DE:48:00:11:22:33:44"));
+    /// assert!(addr.write_str(&mut buf[..4]).is_err());
+    /// ```
+    ///
+    /// [`BufferTooSmall`]: struct.BufferTooSmall.html
+    pub fn write_str<'b>(&self, buf: &'b mut [u8]) -> Result<&'b str, BufferTooSmall> {
+        display::write_colon(buf, &self.0)
+    }
+
+    /// Returns an object displaying the address with colons between every byte, as in `AC:DE:48:00:11:22:33:44`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr8;
+    /// let addr = MacAddr8::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44);
+    ///
+    /// assert_eq!(addr.display_colon().to_string(), "AC:DE:48:00:11:22:33:44");
+    /// assert_eq!(format!("{:#}", addr.display_colon()), "ac:de:48:00:11:22:33:44");
+    /// ```
+    pub fn display_colon(&self) -> DisplayColon<'_> {
+        DisplayColon(&self.0)
+    }
+
+    /// Returns an object displaying the address with hyphens between every byte, as in `AC-DE-48-00-11-22-33-44`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr8;
+    /// let addr = MacAddr8::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44);
+    ///
+    /// assert_eq!(addr.display_hyphen().to_string(), "AC-DE-48-00-11-22-33-44");
+    /// ```
+    pub fn display_hyphen(&self) -> DisplayHyphen<'_> {
+        DisplayHyphen(&self.0)
+    }
+
+    /// Returns an object displaying the address with dots between the 16-bit groups, as in `ACDE.4800.1122.3344`.
+    ///
+    /// This notation is used by some vendors for the InfiniBand GUIDs and Fibre Channel WWNs,
+    /// usually in lowercase, which is selected by the alternate `#` flag.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr8;
+    /// let addr = MacAddr8::new(0x01, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF);
+    ///
+    /// assert_eq!(addr.display_dotted().to_string(), "0123.4567.89AB.CDEF");
+    /// assert_eq!(format!("{:#}", addr.display_dotted()), "0123.4567.89ab.cdef");
+    /// assert_eq!("0123.4567.89ab.cdef".parse(), Ok(addr));
+    /// ```
+    pub fn display_dotted(&self) -> DisplayDotted<'_> {
+        DisplayDotted(&self.0)
+    }
+
+    /// Returns an object displaying the address as the bare hex digits, as in `ACDE480011223344`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr8;
+    /// let addr = MacAddr8::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44);
+    ///
+    /// assert_eq!(addr.display_bare().to_string(), "ACDE480011223344");
+    /// ```
+    pub fn display_bare(&self) -> DisplayBare<'_> {
+        DisplayBare(&self.0)
+    }
+
+    /// Returns the string with the address in the IEEE 802 canonical notation,
+    /// with hyphens between every byte, as in `AC-DE-48-00-11-22-33-44`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr8;
+    /// let addr = MacAddr8::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44);
+    ///
+    /// assert_eq!(addr.to_canonical_string(), "AC-DE-48-00-11-22-33-44");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_canonical_string(&self) -> String {
+        self.display_hyphen().to_string()
+    }
+
+    /// Returns the string with the address with colons between every byte, as in `AC:DE:48:00:11:22:33:44`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr8;
+    /// let addr = MacAddr8::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44);
+    ///
+    /// assert_eq!(addr.to_colon_string(), "AC:DE:48:00:11:22:33:44");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_colon_string(&self) -> String {
+        self.display_colon().to_string()
+    }
+
+    /// Returns the string with the address as the bare hex digits, as in `ACDE480011223344`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr8;
+    /// let addr = MacAddr8::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44);
+    ///
+    /// assert_eq!(addr.to_hex_string(), "ACDE480011223344");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_hex_string(&self) -> String {
+        self.display_bare().to_string()
+    }
+
+    /// Returns the string with the address with dots between the 16-bit groups, as in `ACDE.4800.1122.3344`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr8;
+    /// let addr = MacAddr8::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44);
+    ///
+    /// assert_eq!(addr.to_dotted_string(), "ACDE.4800.1122.3344");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_dotted_string(&self) -> String {
+        self.display_dotted().to_string()
+    }
+
+    /// Returns an object displaying the address as the interface identifier half of the IPv6 address,
+    /// with colons between the lowercase 16-bit groups, as in `0223:45ff:fe67:89ab`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr8;
+    /// let addr = MacAddr8::new(0x02, 0x23, 0x45, 0xFF, 0xFE, 0x67, 0x89, 0xAB);
+    ///
+    /// assert_eq!(addr.display_interface_id().to_string(), "0223:45ff:fe67:89ab");
+    /// assert_eq!(format!("fe80::{}", addr.display_interface_id()).parse(), Ok(std::net::Ipv6Addr::new(
+    ///     0xFE80, 0, 0, 0, 0x0223, 0x45FF, 0xFE67, 0x89AB,
+    /// )));
+    /// ```
+    pub fn display_interface_id(&self) -> DisplayInterfaceId<'_> {
+        DisplayInterfaceId(&self.0)
+    }
+
+    /// Returns an object displaying the address as the Fibre Channel World Wide Name,
+    /// with colons between the lowercase bytes, as in `10:00:00:05:1e:7a:7a:00`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr8;
+    /// let wwn = MacAddr8::new(0x10, 0x00, 0x00, 0x05, 0x1E, 0x7A, 0x7A, 0x00);
+    ///
+    /// assert_eq!(wwn.display_wwn().to_string(), "10:00:00:05:1e:7a:7a:00");
+    /// assert_eq!("10:00:00:05:1e:7a:7a:00".parse(), Ok(wwn));
+    /// ```
+    pub fn display_wwn(&self) -> DisplayWwn<'_> {
+        DisplayWwn(&self.0)
+    }
+
+    /// Returns an object displaying the address in the custom notation.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::{MacAddr8, MacFormat};
+    /// let addr = MacAddr8::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44);
+    ///
+    /// assert_eq!(addr.format(&MacFormat::DOTTED).to_string(), "ACDE.4800.1122.3344");
+    /// ```
+    pub fn format(&self, format: &MacFormat) -> DisplayFormat<'_> {
+        DisplayFormat {
+            bytes: &self.0,
+            format: *format,
+        }
+    }
+
+    /// Returns an object displaying the address with dots between every byte, as in `AC.DE.48.00.11.22.33.44`.
+    ///
+    /// Displayed address can be parsed back with [`MacAddr8::parse_lenient`].
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr8;
+    /// let addr = MacAddr8::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44);
+    ///
+    /// assert_eq!(addr.display_dotted_octets().to_string(), "AC.DE.48.00.11.22.33.44");
+    /// assert_eq!(MacAddr8::parse_lenient("AC.DE.48.00.11.22.33.44"), Ok(addr));
+    /// ```
+    ///
+    /// [`MacAddr8::parse_lenient`]: #method.parse_lenient
+    pub fn display_dotted_octets(&self) -> DisplayDottedOctets<'_> {
+        DisplayDottedOctets(&self.0)
+    }
+
+    /// Returns a reference to the raw bytes array of the `MacAddr8` address.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr8;
+    /// let addr = MacAddr8::new(0xAC, 0xDE, 0x48, 0x23, 0x45, 0x67, 0x89, 0xAB);
+    ///
+    /// assert_eq!(addr.as_bytes(), &[0xAC, 0xDE, 0x48, 0x23, 0x45, 0x67, 0x89, 0xAB]);
+    /// ```
+    pub const fn as_bytes(&self) -> &[u8; 8] {
+        &self.0
+    }
+
+    /// Consumes a `MacAddr8` address and returns raw bytes.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr8;
+    /// let addr = MacAddr8::new(0xAC, 0xDE, 0x48, 0x23, 0x45, 0x67, 0x89, 0xAB);
+    ///
+    /// assert_eq!(addr.into_array(), [0xAC, 0xDE, 0x48, 0x23, 0x45, 0x67, 0x89, 0xAB]);
+    /// ```
+    pub const fn into_array(self) -> [u8; 8] {
+        self.0
+    }
+
+    /// Returns the address as an unsigned integer, made of its bytes in the big-endian order.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr8;
+    /// let addr = MacAddr8::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44);
+    ///
+    /// assert_eq!(addr.to_u64(), 0xACDE480011223344);
+    /// ```
+    pub const fn to_u64(&self) -> u64 {
+        u64::from_be_bytes(self.0)
+    }
+
+    /// Returns the key the address is hashed with, which is the same as the [`to_u64`](#method.to_u64) value.
+    ///
+    /// `Hash` implementation writes only this integer, so the identity hashers can be used
+    /// for the large hash maps keyed by the addresses instead of the default SipHash one.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr8;
+    /// # use std::{collections::HashMap, hash::{BuildHasherDefault, Hasher}};
+    /// #[derive(Default)]
+    /// struct IdentityHasher(u64);
+    ///
+    /// impl Hasher for IdentityHasher {
+    ///     fn finish(&self) -> u64 {
+    ///         self.0
+    ///     }
+    ///
+    ///     fn write(&mut self, _bytes: &[u8]) {
+    ///         unreachable!("addresses are hashed with `write_u64`");
+    ///     }
+    ///
+    ///     fn write_u64(&mut self, key: u64) {
+    ///         self.0 = key;
+    ///     }
+    /// }
+    ///
+    /// let addr = MacAddr8::new(0xAC, 0xDE, 0x48, 0xFF, 0xFE, 0x00, 0x11, 0x22);
+    /// assert_eq!(addr.as_hash_key(), 0xACDE48FFFE001122);
+    ///
+    /// let mut seen = HashMap::<_, _, BuildHasherDefault<IdentityHasher>>::default();
+    /// seen.insert(addr, 1);
+    /// assert_eq!(seen.get(&addr), Some(&1));
+    /// ```
+    pub const fn as_hash_key(&self) -> u64 {
+        self.to_u64()
+    }
+
+    /// Creates the address from an unsigned integer, made of its bytes in the big-endian order.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr8;
+    /// let addr = MacAddr8::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44);
+    ///
+    /// assert_eq!(MacAddr8::from_u64(0xACDE480011223344), addr);
+    /// ```
+    pub const fn from_u64(value: u64) -> MacAddr8 {
+        MacAddr8(value.to_be_bytes())
+    }
+
+    /// Adds `rhs` to the address treated as a 64-bit integer, returning `None` on overflow.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr8;
+    /// let addr = MacAddr8::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0xFF);
+    ///
+    /// assert_eq!(addr.checked_add(2), Some(MacAddr8::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x34, 0x01)));
+    /// assert_eq!(MacAddr8::broadcast().checked_add(1), None);
+    /// ```
+    pub const fn checked_add(self, rhs: u64) -> Option<MacAddr8> {
+        match self.to_u64().checked_add(rhs) {
+            Some(value) => Some(MacAddr8::from_u64(value)),
+            None => None,
+        }
+    }
+
+    /// Subtracts `rhs` from the address treated as a 64-bit integer, returning `None` on underflow.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr8;
+    /// let addr = MacAddr8::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x34, 0x01);
+    ///
+    /// assert_eq!(addr.checked_sub(2), Some(MacAddr8::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0xFF)));
+    /// assert_eq!(MacAddr8::nil().checked_sub(1), None);
+    /// ```
+    pub const fn checked_sub(self, rhs: u64) -> Option<MacAddr8> {
+        match self.to_u64().checked_sub(rhs) {
+            Some(value) => Some(MacAddr8::from_u64(value)),
+            None => None,
+        }
+    }
+
+    /// Adds `rhs` to the address treated as a 64-bit integer, wrapping around at the 64 bits boundary.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr8;
+    /// assert_eq!(MacAddr8::broadcast().wrapping_add(2), MacAddr8::new(0, 0, 0, 0, 0, 0, 0, 0x01));
+    /// ```
+    pub const fn wrapping_add(self, rhs: u64) -> MacAddr8 {
+        MacAddr8::from_u64(self.to_u64().wrapping_add(rhs))
+    }
+
+    /// Subtracts `rhs` from the address treated as a 64-bit integer, wrapping around at the 64 bits boundary.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr8;
+    /// assert_eq!(MacAddr8::nil().wrapping_sub(1), MacAddr8::broadcast());
+    /// ```
+    pub const fn wrapping_sub(self, rhs: u64) -> MacAddr8 {
+        MacAddr8::from_u64(self.to_u64().wrapping_sub(rhs))
+    }
+
+    /// Returns the following address, or `None` for the broadcast one.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr8;
+    /// let addr = MacAddr8::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0xFF);
+    ///
+    /// assert_eq!(addr.next(), Some(MacAddr8::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x34, 0x00)));
+    /// assert_eq!(MacAddr8::broadcast().next(), None);
+    /// ```
+    pub const fn next(self) -> Option<MacAddr8> {
+        self.checked_add(1)
+    }
+
+    /// Returns the preceding address, or `None` for the nil one.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr8;
+    /// let addr = MacAddr8::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x34, 0x00);
+    ///
+    /// assert_eq!(addr.prev(), Some(MacAddr8::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0xFF)));
+    /// assert_eq!(MacAddr8::nil().prev(), None);
+    /// ```
+    pub const fn prev(self) -> Option<MacAddr8> {
+        self.checked_sub(1)
+    }
+
+    /// Returns the Organizationally Unique Identifier (OUI), which is the first three bytes of the address.
+    ///
+    /// The multicast and the locally administered bits of the first byte are kept as is,
+    /// see the [`oui_masked`](#method.oui_masked) method to ignore them.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr8;
+    /// let addr = MacAddr8::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44);
+    ///
+    /// assert_eq!(addr.oui(), [0xAC, 0xDE, 0x48]);
+    /// ```
+    pub const fn oui(&self) -> [u8; 3] {
+        let [a, b, c, ..] = self.0;
+
+        [a, b, c]
+    }
+
+    /// Returns the Organizationally Unique Identifier (OUI) with the multicast
+    /// and the locally administered bits of the first byte cleared,
+    /// so the group and the local addresses derived from the vendor ones share the same OUI.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr8;
+    /// let addr = MacAddr8::new(0xAF, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44);
+    ///
+    /// assert_eq!(addr.oui(), [0xAF, 0xDE, 0x48]);
+    /// assert_eq!(addr.oui_masked(), [0xAC, 0xDE, 0x48]);
+    /// ```
+    pub const fn oui_masked(&self) -> [u8; 3] {
+        let [a, b, c, ..] = self.0;
+
+        [a & !0b11, b, c]
+    }
+
+    /// Returns the NIC-specific part of the address, which is the bytes following the OUI.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr8;
+    /// let addr = MacAddr8::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44);
+    ///
+    /// assert_eq!(addr.nic_specific(), [0x00, 0x11, 0x22, 0x33, 0x44]);
+    /// ```
+    pub const fn nic_specific(&self) -> [u8; 5] {
+        let [_, _, _, d, e, f, g, h] = self.0;
+
+        [d, e, f, g, h]
+    }
+
+    /// Returns the address with the OUI replaced and the NIC-specific part kept.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr8;
+    /// let addr = MacAddr8::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44);
+    ///
+    /// assert_eq!(addr.with_oui([0x00, 0x00, 0x5E]).oui(), [0x00, 0x00, 0x5E]);
+    /// assert_eq!(addr.with_oui([0x00, 0x00, 0x5E]).nic_specific(), addr.nic_specific());
+    /// ```
+    pub const fn with_oui(self, oui: [u8; 3]) -> MacAddr8 {
+        let [_, _, _, d, e, f, g, h] = self.0;
+        let [a, b, c] = oui;
+
+        MacAddr8([a, b, c, d, e, f, g, h])
+    }
+
+    /// Returns the address with the NIC-specific part replaced and the OUI kept.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr8;
+    /// let addr = MacAddr8::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44);
+    ///
+    /// assert_eq!(addr.with_nic([0x12; 5]), MacAddr8::new(0xAC, 0xDE, 0x48, 0x12, 0x12, 0x12, 0x12, 0x12));
+    /// ```
+    pub const fn with_nic(self, nic: [u8; 5]) -> MacAddr8 {
+        let [a, b, c, ..] = self.0;
+        let [d, e, f, g, h] = nic;
+
+        MacAddr8([a, b, c, d, e, f, g, h])
+    }
+
+    /// Sets the U/L bit of the first byte, making the address locally administered.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr8;
+    /// let mut addr = MacAddr8::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44);
+    /// addr.set_local();
+    ///
+    /// assert_eq!(addr.is_local(), true);
+    /// assert_eq!(addr.as_bytes()[0], 0xAE);
+    /// ```
+    pub fn set_local(&mut self) {
+        *self = self.with_local(true);
+    }
+
+    /// Clears the U/L bit of the first byte, making the address universally administered.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr8;
+    /// let mut addr = MacAddr8::new(0xAE, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44);
+    /// addr.set_universal();
+    ///
+    /// assert_eq!(addr.is_universal(), true);
+    /// assert_eq!(addr.as_bytes()[0], 0xAC);
+    /// ```
+    pub fn set_universal(&mut self) {
+        *self = self.with_local(false);
+    }
+
+    /// Sets the I/G bit of the first byte, making the address multicast.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr8;
+    /// let mut addr = MacAddr8::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44);
+    /// addr.set_multicast();
+    ///
+    /// assert_eq!(addr.is_multicast(), true);
+    /// assert_eq!(addr.as_bytes()[0], 0xAD);
+    /// ```
+    pub fn set_multicast(&mut self) {
+        *self = self.with_multicast(true);
+    }
+
+    /// Clears the I/G bit of the first byte, making the address unicast.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr8;
+    /// let mut addr = MacAddr8::broadcast();
+    /// addr.set_unicast();
+    ///
+    /// assert_eq!(addr.is_unicast(), true);
+    /// assert_eq!(addr.as_bytes()[0], 0xFE);
+    /// ```
+    pub fn set_unicast(&mut self) {
+        *self = self.with_multicast(false);
+    }
+
+    /// Returns the address with the U/L bit of the first byte set if `local` is `true`
+    /// or cleared otherwise, keeping the rest of the bits.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr8;
+    /// const ADDR: MacAddr8 = MacAddr8::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44).with_local(true);
+    ///
+    /// assert_eq!(ADDR.is_local(), true);
+    /// assert_eq!(ADDR.with_local(false), MacAddr8::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44));
+    /// ```
+    pub const fn with_local(self, local: bool) -> MacAddr8 {
+        let mut bytes = self.0;
+        bytes[0] = bytes[0] & !(1 << 1) | (local as u8) << 1;
+
+        MacAddr8(bytes)
+    }
+
+    /// Returns the address with the I/G bit of the first byte set if `multicast` is `true`
+    /// or cleared otherwise, keeping the rest of the bits.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr8;
+    /// const ADDR: MacAddr8 = MacAddr8::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44)
+    ///     .with_local(true)
+    ///     .with_multicast(false);
+    ///
+    /// assert_eq!(ADDR.is_randomized(), true);
+    /// assert_eq!(ADDR.with_multicast(true).is_multicast(), true);
+    /// ```
+    pub const fn with_multicast(self, multicast: bool) -> MacAddr8 {
+        let mut bytes = self.0;
+        bytes[0] = bytes[0] & !1 | multicast as u8;
+
+        MacAddr8(bytes)
+    }
+
+    /// Returns the Network Address Authority of the address treated as the Fibre Channel World Wide Name,
+    /// which is its high-order 4 bits.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr8;
+    /// assert_eq!(MacAddr8::new(0x10, 0x00, 0x00, 0x05, 0x1E, 0x7A, 0x7A, 0x00).naa(), 1);
+    /// assert_eq!(MacAddr8::new(0x50, 0x06, 0x01, 0x60, 0x3E, 0xA0, 0x1B, 0x2C).naa(), 5);
+    /// ```
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    pub const fn naa(&self) -> u8 {
+        self.0[0] >> 4
+    }
+
+    /// Returns `true` if the address is a valid 64-bit Fibre Channel World Wide Name, i.e. its
+    /// Network Address Authority is either IEEE 48-bit (`1`) with the reserved bits cleared,
+    /// IEEE Extended (`2`), Locally Assigned (`3`) or IEEE Registered (`5`).
+    ///
+    /// IEEE Registered Extended (`6`) names are 128 bits long, so they do not fit into the address.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr8;
+    /// assert_eq!(MacAddr8::new(0x10, 0x00, 0x00, 0x05, 0x1E, 0x7A, 0x7A, 0x00).is_wwn(), true);
+    /// assert_eq!(MacAddr8::new(0x20, 0x01, 0x00, 0x05, 0x1E, 0x7A, 0x7A, 0x00).is_wwn(), true);
+    /// assert_eq!(MacAddr8::new(0x10, 0x01, 0x00, 0x05, 0x1E, 0x7A, 0x7A, 0x00).is_wwn(), false);
+    /// assert_eq!(MacAddr8::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44).is_wwn(), false);
+    /// ```
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    pub const fn is_wwn(&self) -> bool {
+        matches!(self.0, [0x10, 0x00, ..] | [0x20..=0x3F, ..] | [0x50..=0x5F, ..])
+    }
+
+    /// Returns the IEEE Organizationally Unique Identifier embedded into the Fibre Channel World Wide Name
+    /// of the IEEE 48-bit (`1`), IEEE Extended (`2`), IEEE Registered (`5`) or IEEE Registered Extended (`6`)
+    /// Network Address Authority, or `None` for the rest of them.
+    ///
+    /// For the IEEE Registered Extended names the address is expected to hold their high-order 64 bits.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr8;
+    /// let wwn = MacAddr8::new(0x21, 0x00, 0x00, 0x24, 0xFF, 0x7A, 0x7A, 0x00);
+    /// assert_eq!(wwn.wwn_oui(), Some([0x00, 0x24, 0xFF]));
+    ///
+    /// let wwn = MacAddr8::new(0x50, 0x06, 0x01, 0x60, 0x3E, 0xA0, 0x1B, 0x2C);
+    /// assert_eq!(wwn.wwn_oui(), Some([0x00, 0x60, 0x16]));
+    ///
+    /// assert_eq!(MacAddr8::new(0x30, 0x00, 0x00, 0x24, 0xFF, 0x7A, 0x7A, 0x00).wwn_oui(), None);
+    /// ```
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    pub const fn wwn_oui(&self) -> Option<[u8; 3]> {
+        match self.naa() {
+            1 | 2 => {
+                let [_, _, c, d, e, ..] = self.0;
+                Some([c, d, e])
+            }
+            5 | 6 => {
+                // OUI follows the NAA nibble, so it is not byte-aligned.
+                let [_, _, _, _, _, a, b, c] = (self.to_u64() >> 36).to_be_bytes();
+                Some([a, b, c])
+            }
+            _ => None,
+        }
+    }
+}
+
+/// `MacAddr8` can be parsed from the hyphen- or colon-separated hex notation,
+/// from the Cisco-style dot-separated 16-bit groups
+/// or from the bare hex digits without any delimiters.
+///
+/// # Example
+///
+/// ```
+/// # use macaddr::MacAddr8;
+/// let addr = MacAddr8::new(0xab, 0x0d, 0xef, 0x12, 0x34, 0x56, 0x78, 0x9A);
+///
+/// assert_eq!("AB-0D-EF-12-34-56-78-9A".parse(), Ok(addr));
+/// assert_eq!("ab:0d:ef:12:34:56:78:9a".parse(), Ok(addr));
+/// assert_eq!("ab0d.ef12.3456.789a".parse(), Ok(addr));
+/// assert_eq!("AB0DEF123456789A".parse(), Ok(addr));
+/// ```
+impl FromStr for MacAddr8 {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parser::Parser::new(s).read_v8_addr()
+    }
+}
+
+impl<'a> TryFrom<&'a str> for MacAddr8 {
+    type Error = ParseError;
+
+    fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+        parser::Parser::new(s).read_v8_addr()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl TryFrom<String> for MacAddr8 {
+    type Error = ParseError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        parser::Parser::new(&s).read_v8_addr()
+    }
+}
+
+impl From<[u8; 8]> for MacAddr8 {
+    fn from(bytes: [u8; 8]) -> Self {
+        MacAddr8(bytes)
+    }
+}
+
+impl<'a> From<&'a [u8; 8]> for MacAddr8 {
+    fn from(bytes: &'a [u8; 8]) -> Self {
+        MacAddr8(*bytes)
+    }
+}
+
+impl From<MacAddr8> for [u8; 8] {
+    fn from(addr: MacAddr8) -> Self {
+        addr.0
+    }
+}
+
+impl From<u64> for MacAddr8 {
+    fn from(value: u64) -> Self {
+        MacAddr8::from_u64(value)
+    }
+}
+
+impl From<MacAddr8> for u64 {
+    fn from(addr: MacAddr8) -> Self {
+        addr.to_u64()
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for MacAddr8 {
+    type Error = ParseError;
+
+    fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        <[u8; 8]>::try_from(bytes)
+            .map(MacAddr8)
+            .map_err(|_| ParseError::InvalidLength(bytes.len()))
+    }
+}
+
+#[cfg(feature = "prost")]
+impl From<MacAddr8> for bytes::Bytes {
+    fn from(addr: MacAddr8) -> Self {
+        bytes::Bytes::copy_from_slice(&addr.0)
+    }
+}
+
+/// Converts the protobuf `bytes` field value, failing with [`ParseError::InvalidLength`]
+/// if it is not exactly 8 bytes long.
+#[cfg(feature = "prost")]
+impl TryFrom<bytes::Bytes> for MacAddr8 {
+    type Error = ParseError;
+
+    fn try_from(bytes: bytes::Bytes) -> Result<Self, Self::Error> {
+        MacAddr8::try_from(bytes.as_ref())
+    }
+}
+
+impl AsRef<[u8]> for MacAddr8 {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl AsMut<[u8]> for MacAddr8 {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+crate::macros::impl_bytes_cmp!(
+    /// `MacAddr8` can be compared with the bytes arrays and slices directly,
+    /// slices of the other lengths are never equal to the address.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use macaddr::MacAddr8;
+    /// let addr = MacAddr8::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44);
+    /// let bytes: &[u8] = &[0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44];
+    ///
+    /// assert!(addr == [0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44]);
+    /// assert!(addr == bytes);
+    /// assert!(addr != bytes[1..]);
+    /// assert!(addr < [0xFF; 8]);
+    /// ```
+    MacAddr8,
+    [u8; 8]
+);
+crate::macros::impl_bytes_cmp!(MacAddr8, [u8]);
+crate::macros::impl_bytes_cmp!(MacAddr8, &'a [u8], 'a);
+
+crate::macros::impl_bit_ops!(
+    /// `MacAddr8` supports the bitwise `!`, `&`, `|` and `^` operators along with their assigning forms,
+    /// applied to the address bytes, so the wildcard masks can be written as in the ACL rules.
+    ///
+    /// Right operand can be the other address, the bytes array or the big-endian integer
+    /// as returned by the `to_u64` method.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use macaddr::MacAddr8;
+    /// let addr = MacAddr8::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44);
+    /// let mask = MacAddr8::new(0xFF, 0xFF, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00);
+    ///
+    /// assert_eq!(addr & mask, MacAddr8::new(0xAC, 0xDE, 0x48, 0x00, 0x00, 0x00, 0x00, 0x00));
+    /// assert_eq!(addr & [0xFF, 0xFF, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00], addr & mask);
+    /// assert_eq!(addr ^ addr.to_u64(), MacAddr8::nil());
+    /// assert_eq!(!MacAddr8::nil(), MacAddr8::broadcast());
+    ///
+    /// let mut addr = addr;
+    /// addr |= MacAddr8::broadcast();
+    /// assert!(addr.is_broadcast());
+    /// ```
+    MacAddr8,
+    8
+);
+
+/// `MacAddr8` is debug-formatted in the IEEE 802 canonical notation, as in `MacAddr8(01-23-45-67-89-AB-CD-EF)`.
+///
+/// # Example
+///
+/// ```
+/// # use macaddr::MacAddr8;
+/// let addr = MacAddr8::new(0x01, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF);
+///
+/// assert_eq!(&format!("{:?}", addr), "MacAddr8(01-23-45-67-89-AB-CD-EF)");
+/// assert_eq!(&format!("{:?}", Some(addr)), "Some(MacAddr8(01-23-45-67-89-AB-CD-EF))");
+/// ```
+impl fmt::Debug for MacAddr8 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("MacAddr8").field(&format_args!("{:-}", self)).finish()
+    }
+}
+
+/// `MacAddr8` is rendered by the defmt host in the same notation as with the `Display` implementation,
+/// as in `AB:0D:EF:12:34:56:78:9A`.
+#[cfg(feature = "defmt")]
+impl defmt::Format for MacAddr8 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "{=u8:02X}:{=u8:02X}:{=u8:02X}:{=u8:02X}:{=u8:02X}:{=u8:02X}:{=u8:02X}:{=u8:02X}",
+            self.0[0], self.0[1], self.0[2], self.0[3], self.0[4], self.0[5], self.0[6], self.0[7],
+        )
+    }
+}
+
+#[cfg(feature = "ufmt")]
+impl ufmt::uDisplay for MacAddr8 {
+    fn fmt<W: ufmt::uWrite + ?Sized>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error> {
+        display::uwrite_encoded(f, "", &self.0, ':', "")
+    }
+}
+
+#[cfg(feature = "ufmt")]
+impl ufmt::uDebug for MacAddr8 {
+    fn fmt<W: ufmt::uWrite + ?Sized>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error> {
+        display::uwrite_encoded(f, "MacAddr8(", &self.0, '-', ")")
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl borsh::BorshSerialize for MacAddr8 {
+    fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+        writer.write_all(&self.0)
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl borsh::BorshDeserialize for MacAddr8 {
+    fn deserialize_reader<R: borsh::io::Read>(reader: &mut R) -> borsh::io::Result<Self> {
+        <[u8; 8]>::deserialize_reader(reader).map(MacAddr8)
+    }
+}
+
+#[cfg(feature = "speedy")]
+impl<'a, C: speedy::Context> speedy::Readable<'a, C> for MacAddr8 {
+    fn read_from<R: speedy::Reader<'a, C>>(reader: &mut R) -> Result<Self, C::Error> {
+        let mut bytes = [0; 8];
+        reader.read_bytes(&mut bytes)?;
+
+        Ok(MacAddr8(bytes))
+    }
+
+    fn minimum_bytes_needed() -> usize {
+        8
+    }
+}
+
+#[cfg(feature = "speedy")]
+impl<C: speedy::Context> speedy::Writable<C> for MacAddr8 {
+    fn write_to<W: ?Sized + speedy::Writer<C>>(&self, writer: &mut W) -> Result<(), C::Error> {
+        writer.write_bytes(&self.0)
+    }
+
+    fn bytes_needed(&self) -> Result<usize, C::Error> {
+        Ok(8)
+    }
+}
+
+#[cfg(feature = "bincode")]
+impl bincode::Encode for MacAddr8 {
+    fn encode<E: bincode::enc::Encoder>(&self, encoder: &mut E) -> Result<(), bincode::error::EncodeError> {
+        self.0.encode(encoder)
+    }
+}
+
+#[cfg(feature = "bincode")]
+impl<Context> bincode::Decode<Context> for MacAddr8 {
+    fn decode<D: bincode::de::Decoder<Context = Context>>(
+        decoder: &mut D,
+    ) -> Result<Self, bincode::error::DecodeError> {
+        <[u8; 8]>::decode(decoder).map(MacAddr8)
+    }
+}
+
+#[cfg(feature = "bincode")]
+bincode::impl_borrow_decode!(MacAddr8);
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for MacAddr8 {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        u.arbitrary().map(MacAddr8)
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (8, Some(8))
+    }
+}
+
+/// Addresses are shrunk towards the nil address.
+#[cfg(feature = "quickcheck")]
+impl quickcheck::Arbitrary for MacAddr8 {
+    fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+        MacAddr8(quickcheck::Arbitrary::arbitrary(g))
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        if self.is_nil() {
+            return quickcheck::empty_shrinker();
+        }
+
+        let shrunk = self.0.shrink().map(MacAddr8).filter(|addr| !addr.is_nil());
+        Box::new(core::iter::once(MacAddr8::nil()).chain(shrunk))
+    }
+}
+
+#[cfg(feature = "rand")]
+impl rand::distributions::Distribution<MacAddr8> for rand::distributions::Standard {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> MacAddr8 {
+        MacAddr8::random(rng)
+    }
+}
+
+/// Addresses are stored in the PostgreSQL `MACADDR8` columns.
+#[cfg(feature = "postgres")]
+impl<'a> postgres_types::FromSql<'a> for MacAddr8 {
+    fn from_sql(
+        _ty: &postgres_types::Type,
+        raw: &'a [u8],
+    ) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+        Ok(MacAddr8::try_from(raw)?)
+    }
+
+    postgres_types::accepts!(MACADDR8);
+}
+
+#[cfg(feature = "postgres")]
+impl postgres_types::ToSql for MacAddr8 {
+    fn to_sql(
+        &self,
+        _ty: &postgres_types::Type,
+        out: &mut bytes::BytesMut,
+    ) -> Result<postgres_types::IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        out.extend_from_slice(&self.0);
+
+        Ok(postgres_types::IsNull::No)
+    }
+
+    postgres_types::accepts!(MACADDR8);
+    postgres_types::to_sql_checked!();
+}
+
+/// Addresses are stored in SQLite as the 8 bytes blobs;
+/// the text values in any notation accepted by the `FromStr` implementation are read too.
+#[cfg(feature = "rusqlite")]
+impl rusqlite::types::ToSql for MacAddr8 {
+    fn to_sql(&self) -> rusqlite::Result<rusqlite::types::ToSqlOutput<'_>> {
+        Ok(rusqlite::types::ToSqlOutput::Borrowed(rusqlite::types::ValueRef::Blob(&self.0)))
+    }
+}
+
+#[cfg(feature = "rusqlite")]
+impl rusqlite::types::FromSql for MacAddr8 {
+    fn column_result(value: rusqlite::types::ValueRef<'_>) -> rusqlite::types::FromSqlResult<Self> {
+        match value {
+            rusqlite::types::ValueRef::Blob(blob) => {
+                MacAddr8::try_from(blob).map_err(|_| rusqlite::types::FromSqlError::InvalidBlobSize {
+                    expected_size: 8,
+                    blob_size: blob.len(),
+                })
+            }
+            rusqlite::types::ValueRef::Text(text) => {
+                MacAddr8::parse_ascii(text).map_err(|err| rusqlite::types::FromSqlError::Other(Box::new(err)))
+            }
+            _ => Err(rusqlite::types::FromSqlError::InvalidType),
+        }
+    }
+}
+
+/// `MacAddr8` is hashed as the single `u64` integer returned by the [`as_hash_key`] method.
+///
+/// [`as_hash_key`]: #method.as_hash_key
+impl Hash for MacAddr8 {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u64(self.as_hash_key());
+    }
+}
+
+/// `MacAddr8` fits into the single `u64` integer, so the `nohash_hasher` maps can be keyed by it.
+#[cfg(feature = "nohash-hasher")]
+impl nohash_hasher::IsEnabled for MacAddr8 {}
+
+/// `MacAddr8` can be displayed in different formats.
+///
+/// Bytes are separated with colons by default or with hyphens if the `-` flag is set.
+/// Hex digits are uppercase by default or lowercase if the alternate `#` flag is set.
+/// Width, fill and alignment flags are respected.
+///
+/// # Example
+///
+/// ```
+/// # use macaddr::MacAddr8;
+/// let addr = MacAddr8::new(0xab, 0x0d, 0xef, 0x12, 0x34, 0x56, 0x78, 0x9A);
+///
+/// assert_eq!(&format!("{}",    addr), "AB:0D:EF:12:34:56:78:9A");
+/// assert_eq!(&format!("{:-}",  addr), "AB-0D-EF-12-34-56-78-9A");
+/// assert_eq!(&format!("{:#}",  addr), "ab:0d:ef:12:34:56:78:9a");
+/// assert_eq!(&format!("{:-#}", addr), "ab-0d-ef-12-34-56-78-9a");
+/// assert_eq!(&format!("{:*<25}", addr), "AB:0D:EF:12:34:56:78:9A**");
+/// ```
+impl fmt::Display for MacAddr8 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let separator = if f.sign_minus() { '-' } else { ':' };
+
+        display::write_grouped(f, &self.0, Some(separator), 1, !f.alternate())
+    }
+}
+
+/// `MacAddr8` can be formatted as the bare hex digits, prefixed with `0x` if the alternate `#` flag is set.
+///
+/// # Example
+///
+/// ```
+/// # use macaddr::MacAddr8;
+/// let addr = MacAddr8::new(0xab, 0x0d, 0xef, 0x12, 0x34, 0x56, 0x78, 0x9A);
+///
+/// assert_eq!(&format!("{:x}",  addr), "ab0def123456789a");
+/// assert_eq!(&format!("{:#x}", addr), "0xab0def123456789a");
+/// ```
+impl fmt::LowerHex for MacAddr8 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        display::write_hex(f, &self.0, false)
+    }
+}
+
+/// `MacAddr8` can be formatted as the bare hex digits, prefixed with `0x` if the alternate `#` flag is set.
+///
+/// # Example
+///
+/// ```
+/// # use macaddr::MacAddr8;
+/// let addr = MacAddr8::new(0xab, 0x0d, 0xef, 0x12, 0x34, 0x56, 0x78, 0x9A);
+///
+/// assert_eq!(&format!("{:X}",  addr), "AB0DEF123456789A");
+/// assert_eq!(&format!("{:#X}", addr), "0xAB0DEF123456789A");
+/// ```
+impl fmt::UpperHex for MacAddr8 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        display::write_hex(f, &self.0, true)
+    }
+}
+
+/// `MacAddr8` can be formatted as the 64-bit binary number, prefixed with `0b` if the alternate `#` flag is set.
+///
+/// Leading zeros are always included, so the position of each bit is preserved.
+///
+/// # Example
+///
+/// ```
+/// # use macaddr::MacAddr8;
+/// let addr = MacAddr8::new(0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03);
+///
+/// assert_eq!(&format!("{:b}",  addr), "1000000000000000000000000000000000000000000000000000000000000011");
+/// assert_eq!(format!("{:#b}", MacAddr8::nil()).len(), 66);
+/// ```
+impl fmt::Binary for MacAddr8 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        display::write_radix(f, &self.0, 1, "0b")
+    }
+}
+
+/// `MacAddr8` can be formatted as the octal number, prefixed with `0o` if the alternate `#` flag is set.
+///
+/// Leading zeros are always included.
+///
+/// # Example
+///
+/// ```
+/// # use macaddr::MacAddr8;
+/// let addr = MacAddr8::new(0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03);
+///
+/// assert_eq!(&format!("{:o}",  addr), "1000000000000000000003");
+/// assert_eq!(&format!("{:#o}", addr), "0o1000000000000000000003");
+/// ```
+impl fmt::Octal for MacAddr8 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        display::write_radix(f, &self.0, 3, "0o")
+    }
 }
@@ -0,0 +1,171 @@
+//! [Apache Arrow] conversions for the address columns.
+//!
+//! Addresses are stored in the `FixedSizeBinary` arrays of the 6 or 8 bytes width,
+//! and the fields are annotated with the `ARROW:extension:name` metadata,
+//! so the columns are recognized as the MAC addresses after passing through the pipeline.
+//!
+//! [`MacAddr`](../enum.MacAddr.html) is not supported, as its width depends on the variant.
+//!
+//! ## Example
+//!
+//! ```rust
+//! # use macaddr::MacAddr6;
+//! let addrs = [MacAddr6::new(0x01, 0x23, 0x45, 0x67, 0x89, 0xAB), MacAddr6::nil()];
+//! let array = macaddr::arrow::to_array(addrs.iter().copied());
+//!
+//! let decoded = macaddr::arrow::from_array::<MacAddr6>(&array)
+//!     .unwrap()
+//!     .collect::<Vec<_>>();
+//! assert_eq!(decoded, vec![Some(addrs[0]), Some(addrs[1])]);
+//! ```
+//!
+//! [Apache Arrow]: https://docs.rs/arrow
+
+use std::collections::HashMap;
+
+use ::arrow_array::{Array, FixedSizeBinaryArray};
+use ::arrow_schema::{ArrowError, DataType, Field};
+
+use crate::{MacAddr6, MacAddr8};
+
+/// Field metadata key holding the extension type name.
+pub const EXTENSION_NAME_KEY: &str = "ARROW:extension:name";
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// MAC address type storable in the Arrow arrays.
+///
+/// This trait is sealed and implemented for the [`MacAddr6`] and [`MacAddr8`] types only.
+///
+/// [`MacAddr6`]: ../struct.MacAddr6.html
+/// [`MacAddr8`]: ../struct.MacAddr8.html
+pub trait ArrowAddress: private::Sealed + Copy {
+    /// Extension type name of the address columns.
+    const EXTENSION_NAME: &'static str;
+
+    #[doc(hidden)]
+    const LEN: usize;
+
+    #[doc(hidden)]
+    fn to_bytes(&self) -> &[u8];
+
+    #[doc(hidden)]
+    fn from_bytes(bytes: &[u8]) -> Self;
+}
+
+impl private::Sealed for MacAddr6 {}
+
+impl ArrowAddress for MacAddr6 {
+    const EXTENSION_NAME: &'static str = "macaddr.eui48";
+    const LEN: usize = 6;
+
+    fn to_bytes(&self) -> &[u8] {
+        self.as_bytes()
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Self {
+        let mut addr = [0; 6];
+        addr.copy_from_slice(bytes);
+        MacAddr6::from(addr)
+    }
+}
+
+impl private::Sealed for MacAddr8 {}
+
+impl ArrowAddress for MacAddr8 {
+    const EXTENSION_NAME: &'static str = "macaddr.eui64";
+    const LEN: usize = 8;
+
+    fn to_bytes(&self) -> &[u8] {
+        self.as_bytes()
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Self {
+        let mut addr = [0; 8];
+        addr.copy_from_slice(bytes);
+        MacAddr8::from(addr)
+    }
+}
+
+/// Returns the `FixedSizeBinary` data type of the address columns.
+pub fn data_type<T: ArrowAddress>() -> DataType {
+    DataType::FixedSizeBinary(T::LEN as i32)
+}
+
+/// Returns the field of the address column, annotated with the extension type name.
+///
+/// ```rust
+/// # use macaddr::MacAddr6;
+/// let field = macaddr::arrow::field::<MacAddr6>("src", false);
+///
+/// assert_eq!(field.metadata()["ARROW:extension:name"], "macaddr.eui48");
+/// ```
+pub fn field<T: ArrowAddress>(name: impl Into<String>, nullable: bool) -> Field {
+    let metadata = HashMap::from([(EXTENSION_NAME_KEY.to_string(), T::EXTENSION_NAME.to_string())]);
+
+    Field::new(name, data_type::<T>(), nullable).with_metadata(metadata)
+}
+
+/// Creates the array from the addresses.
+pub fn to_array<T, I>(iter: I) -> FixedSizeBinaryArray
+where
+    T: ArrowAddress,
+    I: IntoIterator<Item = T>,
+{
+    to_nullable_array(iter.into_iter().map(Some))
+}
+
+/// Creates the array from the optional addresses, storing `None` values as nulls.
+pub fn to_nullable_array<T, I>(iter: I) -> FixedSizeBinaryArray
+where
+    T: ArrowAddress,
+    I: IntoIterator<Item = Option<T>>,
+{
+    let iter = iter.into_iter();
+    let mut values = Vec::with_capacity(iter.size_hint().0 * T::LEN);
+    let mut validity = Vec::with_capacity(iter.size_hint().0);
+
+    for addr in iter {
+        match addr {
+            Some(addr) => values.extend_from_slice(addr.to_bytes()),
+            // Null slots are still occupying the value bytes.
+            None => values.resize(values.len() + T::LEN, 0),
+        }
+        validity.push(addr.is_some());
+    }
+
+    let nulls = if validity.contains(&false) {
+        Some(validity.into())
+    } else {
+        None
+    };
+
+    FixedSizeBinaryArray::new(T::LEN as i32, values.into(), nulls)
+}
+
+/// Returns an iterator over the addresses stored in the array, yielding `None` for the nulls.
+///
+/// ## Errors
+///
+/// Returns an error if the array width does not match the address size.
+pub fn from_array<T: ArrowAddress>(
+    array: &FixedSizeBinaryArray,
+) -> Result<impl Iterator<Item = Option<T>> + '_, ArrowError> {
+    if array.value_length() != T::LEN as i32 {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "expected FixedSizeBinary({}) array for the MAC addresses, got FixedSizeBinary({})",
+            T::LEN,
+            array.value_length()
+        )));
+    }
+
+    Ok((0..array.len()).map(move |i| {
+        if array.is_null(i) {
+            None
+        } else {
+            Some(T::from_bytes(array.value(i)))
+        }
+    }))
+}
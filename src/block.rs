@@ -0,0 +1,189 @@
+use crate::{MacAddr6, MacPrefix};
+
+/// Parent OUIs of the MA-S (formerly OUI-36 and IAB) assignments.
+const MA_S_PARENTS: [[u8; 3]; 5] = [
+    [0x00, 0x50, 0xC2],
+    [0x00, 0x1B, 0xC5],
+    [0x40, 0xD8, 0x55],
+    [0x70, 0xB3, 0xD5],
+    [0x8C, 0x1F, 0x64],
+];
+
+/// Size of the IEEE Registration Authority assignment the address was allocated from.
+///
+/// It is returned by the [`MacAddr6::assignment_block`] method.
+///
+/// [`MacAddr6::assignment_block`]: struct.MacAddr6.html#method.assignment_block
+#[derive(Debug, Hash, Eq, PartialEq, Ord, PartialOrd, Copy, Clone)]
+pub enum AssignmentBlock {
+    /// MAC Address Block Large, the 24-bit OUI assignment.
+    MaL,
+    /// MAC Address Block Medium, the 28-bit prefix assignment.
+    MaM,
+    /// MAC Address Block Small, the 36-bit prefix assignment, formerly known as OUI-36 and IAB.
+    MaS,
+    /// Company ID, the 24-bit prefix in the locally administered address space,
+    /// which can't be used for the globally unique addresses.
+    Cid,
+}
+
+impl AssignmentBlock {
+    /// Returns the length in bits of the prefix assigned to the organization.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::AssignmentBlock;
+    /// assert_eq!(AssignmentBlock::MaL.prefix_len(), 24);
+    /// assert_eq!(AssignmentBlock::MaS.prefix_len(), 36);
+    /// ```
+    pub const fn prefix_len(&self) -> u8 {
+        match self {
+            AssignmentBlock::MaL | AssignmentBlock::Cid => 24,
+            AssignmentBlock::MaM => 28,
+            AssignmentBlock::MaS => 36,
+        }
+    }
+
+    /// Returns the prefix of this block size containing the address.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::{AssignmentBlock, MacAddr6};
+    /// let addr = MacAddr6::new(0x70, 0xB3, 0xD5, 0x12, 0x34, 0x56);
+    ///
+    /// assert_eq!(AssignmentBlock::MaS.prefix(&addr).to_string(), "70:B3:D5:12:30:00/36");
+    /// ```
+    pub const fn prefix(&self, addr: &MacAddr6) -> MacPrefix {
+        match MacPrefix::new(*addr, self.prefix_len()) {
+            Some(prefix) => prefix,
+            // Prefix lengths are always valid.
+            None => unreachable!(),
+        }
+    }
+}
+
+/// Quadrant of the IEEE 802c Structured Local Address Plan (SLAP) the locally administered address belongs to,
+/// selected by the Z and Y bits of the first address byte.
+///
+/// It is returned by the [`MacAddr6::slap_quadrant`] method.
+///
+/// [`MacAddr6::slap_quadrant`]: struct.MacAddr6.html#method.slap_quadrant
+#[derive(Debug, Hash, Eq, PartialEq, Ord, PartialOrd, Copy, Clone)]
+pub enum SlapQuadrant {
+    /// Administratively Assigned Identifier, as in `x2-xx-xx-xx-xx-xx`, used for the random
+    /// and the manually assigned addresses.
+    Aai,
+    /// Extended Local Identifier, as in `xA-xx-xx-xx-xx-xx`, assigned from a Company ID.
+    Eli,
+    /// Standard Assigned Identifier, as in `xE-xx-xx-xx-xx-xx`, assigned by the protocols specified by the IEEE.
+    Sai,
+    /// Reserved quadrant, as in `x6-xx-xx-xx-xx-xx`, assigned by the local administrators.
+    Unassigned,
+}
+
+impl SlapQuadrant {
+    /// Z and Y bits of the first address byte which select the quadrant.
+    pub(crate) const MASK: u8 = 0x0C;
+
+    /// Returns the Z and Y bits of the first address byte for the quadrant.
+    pub(crate) const fn bits(&self) -> u8 {
+        match self {
+            SlapQuadrant::Aai => 0x00,
+            SlapQuadrant::Unassigned => 0x04,
+            SlapQuadrant::Eli => 0x08,
+            SlapQuadrant::Sai => 0x0C,
+        }
+    }
+
+    pub(crate) const fn from_bits(byte: u8) -> SlapQuadrant {
+        match byte & SlapQuadrant::MASK {
+            0x00 => SlapQuadrant::Aai,
+            0x04 => SlapQuadrant::Unassigned,
+            0x08 => SlapQuadrant::Eli,
+            _ => SlapQuadrant::Sai,
+        }
+    }
+}
+
+/// Classifies the address for the [`MacAddr6::assignment_block`] method.
+///
+/// [`MacAddr6::assignment_block`]: struct.MacAddr6.html#method.assignment_block
+pub(crate) const fn classify(addr: &MacAddr6) -> Option<AssignmentBlock> {
+    let oui = addr.oui_masked();
+
+    if addr.is_local() {
+        return match addr.slap_quadrant() {
+            Some(SlapQuadrant::Eli) => Some(AssignmentBlock::Cid),
+            _ => None,
+        };
+    }
+
+    #[cfg(feature = "oui-db")]
+    match crate::vendor::block_prefix_len(addr) {
+        Some(28) => return Some(AssignmentBlock::MaM),
+        Some(36) => return Some(AssignmentBlock::MaS),
+        _ => {}
+    }
+
+    let mut idx = 0;
+    while idx < MA_S_PARENTS.len() {
+        let parent = MA_S_PARENTS[idx];
+        if oui[0] == parent[0] && oui[1] == parent[1] && oui[2] == parent[2] {
+            return Some(AssignmentBlock::MaS);
+        }
+        idx += 1;
+    }
+
+    Some(AssignmentBlock::MaL)
+}
+
+/// Assignment of the address from the IANA-managed `00:00:5E` unicast and `01:00:5E` multicast blocks,
+/// as listed in the RFC 7042, section 2.
+///
+/// It is returned by the [`MacAddr6::iana_assignment`] method.
+///
+/// [`MacAddr6::iana_assignment`]: struct.MacAddr6.html#method.iana_assignment
+#[derive(Debug, Hash, Eq, PartialEq, Ord, PartialOrd, Copy, Clone)]
+pub enum IanaAssignment {
+    /// Reserved unicast range `00:00:5E:00:00:00` to `00:00:5E:00:00:FF`, requiring the IESG ratification.
+    Reserved,
+    /// VRRP virtual router addresses for IPv4, `00:00:5E:00:01:00` to `00:00:5E:00:01:FF` (RFC 5798).
+    VrrpIpv4,
+    /// VRRP virtual router addresses for IPv6, `00:00:5E:00:02:00` to `00:00:5E:00:02:FF` (RFC 5798).
+    VrrpIpv6,
+    /// Proxy Mobile IPv6 shared link-layer address `00:00:5E:00:52:00` (RFC 6543).
+    ProxyMobileIpv6,
+    /// Unicast `00:00:5E:00:53:00` to `00:00:5E:00:53:FF` and multicast `01:00:5E:90:10:00` to `01:00:5E:90:10:FF`
+    /// ranges for the documentation examples (RFC 7042).
+    Documentation,
+    /// IPv4 multicast group addresses, `01:00:5E:00:00:00` to `01:00:5E:7F:FF:FF` (RFC 1112).
+    Ipv4Multicast,
+    /// MPLS multicast addresses, `01:00:5E:80:00:00` to `01:00:5E:8F:FF:FF` (RFC 5332).
+    MplsMulticast,
+    /// Bidirectional Forwarding Detection on LAG member links address `01:00:5E:90:00:01` (RFC 7130).
+    BfdLag,
+    /// Address of the IANA block which is not assigned for any protocol yet.
+    Unassigned,
+}
+
+/// Classifies the address for the [`MacAddr6::iana_assignment`] method.
+///
+/// [`MacAddr6::iana_assignment`]: struct.MacAddr6.html#method.iana_assignment
+pub(crate) const fn classify_iana(addr: &MacAddr6) -> Option<IanaAssignment> {
+    let assignment = match addr.as_bytes() {
+        [0x00, 0x00, 0x5E, 0x00, 0x00, _] => IanaAssignment::Reserved,
+        [0x00, 0x00, 0x5E, 0x00, 0x01, _] => IanaAssignment::VrrpIpv4,
+        [0x00, 0x00, 0x5E, 0x00, 0x02, _] => IanaAssignment::VrrpIpv6,
+        [0x00, 0x00, 0x5E, 0x00, 0x52, 0x00] => IanaAssignment::ProxyMobileIpv6,
+        [0x00, 0x00, 0x5E, 0x00, 0x53, _] | [0x01, 0x00, 0x5E, 0x90, 0x10, _] => IanaAssignment::Documentation,
+        [0x01, 0x00, 0x5E, d, _, _] if *d < 0x80 => IanaAssignment::Ipv4Multicast,
+        [0x01, 0x00, 0x5E, d, _, _] if *d < 0x90 => IanaAssignment::MplsMulticast,
+        [0x01, 0x00, 0x5E, 0x90, 0x00, 0x01] => IanaAssignment::BfdLag,
+        [0x00 | 0x01, 0x00, 0x5E, _, _, _] => IanaAssignment::Unassigned,
+        _ => return None,
+    };
+
+    Some(assignment)
+}
@@ -0,0 +1,111 @@
+//! Serde helpers mapping [`MacAddr6`] to the [ClickHouse] columns.
+//!
+//! The [clickhouse] crate serializes the `Row` structs with serde, so the address fields
+//! should be annotated with one of these modules to be stored in the compact column types
+//! instead of the strings:
+//!
+//! * [`uint64`] for the `UInt64` columns, holding the address bytes in the big-endian order,
+//!   so `0xACDE48001122` is stored for the `AC-DE-48-00-11-22` address;
+//! * [`fixed_string`] for the `FixedString(6)` columns, holding the raw address bytes.
+//!
+//! Both of them contain an `option` submodule for the `Nullable` columns.
+//!
+//! ## Example
+//!
+//! ```rust
+//! # use macaddr::MacAddr6;
+//! # use serde::{Deserialize, Serialize};
+//! // #[derive(clickhouse::Row)]
+//! #[derive(Serialize, Deserialize)]
+//! struct Flow {
+//!     #[serde(with = "macaddr::clickhouse::uint64")]
+//!     src: MacAddr6,
+//!     #[serde(with = "macaddr::clickhouse::fixed_string")]
+//!     dst: MacAddr6,
+//!     #[serde(with = "macaddr::clickhouse::uint64::option")]
+//!     gateway: Option<MacAddr6>,
+//! }
+//! ```
+//!
+//! [`MacAddr6`]: ../struct.MacAddr6.html
+//! [ClickHouse]: https://clickhouse.com
+//! [clickhouse]: https://docs.rs/clickhouse
+//! [`uint64`]: uint64/index.html
+//! [`fixed_string`]: fixed_string/index.html
+
+macro_rules! option {
+    ($doc:literal) => {
+        #[doc = $doc]
+        pub mod option {
+            use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+            use crate::MacAddr6;
+
+            struct Column(MacAddr6);
+
+            impl Serialize for Column {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    super::serialize(&self.0, serializer)
+                }
+            }
+
+            impl<'de> Deserialize<'de> for Column {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    super::deserialize(deserializer).map(Column)
+                }
+            }
+
+            /// Serializes the optional address, writing `None` as a null.
+            pub fn serialize<S: Serializer>(addr: &Option<MacAddr6>, serializer: S) -> Result<S::Ok, S::Error> {
+                addr.map(Column).serialize(serializer)
+            }
+
+            /// Deserializes the optional address, reading a null as `None`.
+            pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<MacAddr6>, D::Error> {
+                Ok(Option::<Column>::deserialize(deserializer)?.map(|column| column.0))
+            }
+        }
+    };
+}
+
+/// Stores [`MacAddr6`] in the `UInt64` column.
+///
+/// [`MacAddr6`]: ../../struct.MacAddr6.html
+pub mod uint64 {
+    use ::serde::{Deserializer, Serializer};
+
+    use crate::MacAddr6;
+
+    option!("Stores `Option<MacAddr6>` in the `Nullable(UInt64)` column.");
+
+    /// Serializes the address as an unsigned integer.
+    pub fn serialize<S: Serializer>(addr: &MacAddr6, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::serde::u64::serialize(addr, serializer)
+    }
+
+    /// Deserializes the address from an unsigned integer, rejecting values above 48 bits.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<MacAddr6, D::Error> {
+        crate::serde::u64::deserialize(deserializer)
+    }
+}
+
+/// Stores [`MacAddr6`] in the `FixedString(6)` column.
+///
+/// [`MacAddr6`]: ../../struct.MacAddr6.html
+pub mod fixed_string {
+    use ::serde::{Deserializer, Serializer};
+
+    use crate::MacAddr6;
+
+    option!("Stores `Option<MacAddr6>` in the `Nullable(FixedString(6))` column.");
+
+    /// Serializes the address as the fixed-size bytes array.
+    pub fn serialize<S: Serializer>(addr: &MacAddr6, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::serde::array::serialize(addr, serializer)
+    }
+
+    /// Deserializes the address from the fixed-size bytes array.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<MacAddr6, D::Error> {
+        crate::serde::array::deserialize(deserializer)
+    }
+}
@@ -0,0 +1,97 @@
+//! Well-known addresses and address blocks of the IEEE 802 and IETF protocols.
+//!
+//! ## Example
+//!
+//! ```rust
+//! # use macaddr::{consts, MacAddr6};
+//! let dst = MacAddr6::new(0x01, 0x80, 0xC2, 0x00, 0x00, 0x0E);
+//!
+//! assert_eq!(dst, consts::LLDP_NEAREST_BRIDGE);
+//! assert!(consts::BRIDGE_RESERVED.contains(&dst));
+//! assert!(consts::IPV4_MULTICAST.contains(&MacAddr6::new(0x01, 0x00, 0x5E, 0x00, 0x00, 0xFB)));
+//! ```
+
+use crate::{MacAddr6, MacPrefix};
+
+/// Creates the prefix in the `const` context, the lengths below are always valid.
+const fn prefix(addr: MacAddr6, len: u8) -> MacPrefix {
+    match MacPrefix::new(addr, len) {
+        Some(prefix) => prefix,
+        None => panic!("invalid prefix length"),
+    }
+}
+
+/// Broadcast address `FF:FF:FF:FF:FF:FF`, same as [`MacAddr6::broadcast`].
+///
+/// [`MacAddr6::broadcast`]: ../struct.MacAddr6.html#method.broadcast
+pub const BROADCAST: MacAddr6 = MacAddr6::broadcast();
+
+/// Nil address `00:00:00:00:00:00`, same as [`MacAddr6::nil`].
+///
+/// [`MacAddr6::nil`]: ../struct.MacAddr6.html#method.nil
+pub const NIL: MacAddr6 = MacAddr6::nil();
+
+/// IEEE 802.1D Bridge Group address `01:80:C2:00:00:00`, the destination of the Spanning Tree Protocol BPDUs.
+///
+/// It is also the LLDP Nearest Customer Bridge address.
+pub const STP: MacAddr6 = MacAddr6::new(0x01, 0x80, 0xC2, 0x00, 0x00, 0x00);
+
+/// IEEE 802.3 MAC Control address `01:80:C2:00:00:01`, the destination of the PAUSE frames.
+pub const PAUSE: MacAddr6 = MacAddr6::new(0x01, 0x80, 0xC2, 0x00, 0x00, 0x01);
+
+/// IEEE 802.3 Slow Protocols address `01:80:C2:00:00:02`, the destination of the LACP and OAM frames.
+pub const SLOW_PROTOCOLS: MacAddr6 = MacAddr6::new(0x01, 0x80, 0xC2, 0x00, 0x00, 0x02);
+
+/// IEEE 802.1AB LLDP Nearest non-TPMR Bridge address `01:80:C2:00:00:03`,
+/// also used as the IEEE 802.1X PAE group address.
+pub const LLDP_NEAREST_NON_TPMR_BRIDGE: MacAddr6 = MacAddr6::new(0x01, 0x80, 0xC2, 0x00, 0x00, 0x03);
+
+/// IEEE 802.1AB LLDP Nearest Bridge address `01:80:C2:00:00:0E`.
+pub const LLDP_NEAREST_BRIDGE: MacAddr6 = MacAddr6::new(0x01, 0x80, 0xC2, 0x00, 0x00, 0x0E);
+
+/// IEEE 1588 PTP peer delay address `01:80:C2:00:00:0E`, the destination of the peer delay messages
+/// and of all the IEEE 802.1AS messages, same as [`LLDP_NEAREST_BRIDGE`].
+///
+/// [`LLDP_NEAREST_BRIDGE`]: constant.LLDP_NEAREST_BRIDGE.html
+pub const PTP_PEER_DELAY: MacAddr6 = LLDP_NEAREST_BRIDGE;
+
+/// IEEE 1588 PTP primary address `01:1B:19:00:00:00`, the destination of the messages other than the peer delay ones.
+pub const PTP_PRIMARY: MacAddr6 = MacAddr6::new(0x01, 0x1B, 0x19, 0x00, 0x00, 0x00);
+
+/// Cisco Discovery Protocol address `01:00:0C:CC:CC:CC`, also used by VTP, DTP and PAgP.
+pub const CDP: MacAddr6 = MacAddr6::new(0x01, 0x00, 0x0C, 0xCC, 0xCC, 0xCC);
+
+/// IEEE 802.1Q reserved block `01:80:C2:00:00:00/44`, which is never forwarded by the bridges.
+pub const BRIDGE_RESERVED: MacPrefix = prefix(STP, 44);
+
+/// Base of the IPv4 multicast addresses `01:00:5E:00:00:00`, followed by the lower 23 bits of the group.
+pub const IPV4_MULTICAST_BASE: MacAddr6 = MacAddr6::new(0x01, 0x00, 0x5E, 0x00, 0x00, 0x00);
+
+/// Block of the IPv4 multicast addresses `01:00:5E:00:00:00/25`, as defined in RFC 1112.
+pub const IPV4_MULTICAST: MacPrefix = prefix(IPV4_MULTICAST_BASE, 25);
+
+/// Base of the IPv6 multicast addresses `33:33:00:00:00:00`, followed by the lower 32 bits of the group.
+pub const IPV6_MULTICAST_BASE: MacAddr6 = MacAddr6::new(0x33, 0x33, 0x00, 0x00, 0x00, 0x00);
+
+/// Block of the IPv6 multicast addresses `33:33:00:00:00:00/16`, as defined in RFC 2464.
+pub const IPV6_MULTICAST: MacPrefix = prefix(IPV6_MULTICAST_BASE, 16);
+
+/// Block of the VRRP virtual router addresses for IPv4 `00:00:5E:00:01:00/40`, followed by the router ID,
+/// as defined in RFC 5798.
+pub const VRRP_IPV4: MacPrefix = prefix(MacAddr6::new(0x00, 0x00, 0x5E, 0x00, 0x01, 0x00), 40);
+
+/// Block of the VRRP virtual router addresses for IPv6 `00:00:5E:00:02:00/40`, followed by the router ID,
+/// as defined in RFC 5798.
+pub const VRRP_IPV6: MacPrefix = prefix(MacAddr6::new(0x00, 0x00, 0x5E, 0x00, 0x02, 0x00), 40);
+
+/// Block of the HSRP version 1 virtual router addresses `00:00:0C:07:AC:00/40`, followed by the group number,
+/// as described in RFC 2281.
+pub const HSRP_V1: MacPrefix = prefix(MacAddr6::new(0x00, 0x00, 0x0C, 0x07, 0xAC, 0x00), 40);
+
+/// Block of the HSRP version 2 virtual router addresses for IPv4 `00:00:0C:9F:F0:00/36`,
+/// followed by the 12-bit group number.
+pub const HSRP_V2: MacPrefix = prefix(MacAddr6::new(0x00, 0x00, 0x0C, 0x9F, 0xF0, 0x00), 36);
+
+/// Block of the HSRP version 2 virtual router addresses for IPv6 `00:05:73:A0:00:00/36`,
+/// followed by the 12-bit group number.
+pub const HSRP_IPV6: MacPrefix = prefix(MacAddr6::new(0x00, 0x05, 0x73, 0xA0, 0x00, 0x00), 36);
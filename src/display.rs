@@ -0,0 +1,319 @@
+use core::{error::Error, fmt, str};
+
+/// Maximum length of the rendered address: 16 hex digits and 7 separators up to 4 bytes long each.
+const MAX_LEN: usize = 16 + 7 * 4;
+
+const UPPER_DIGITS: &[u8; 16] = b"0123456789ABCDEF";
+const LOWER_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+/// Renders the bytes as the hex digits, putting the `separator` between every `group` bytes,
+/// into the `out` buffer, which should be large enough. Returns the rendered length.
+pub(crate) fn encode(out: &mut [u8], bytes: &[u8], separator: Option<char>, group: usize, upper: bool) -> usize {
+    let digits = if upper { UPPER_DIGITS } else { LOWER_DIGITS };
+    let mut len = 0;
+
+    for (idx, byte) in bytes.iter().enumerate() {
+        if let Some(separator) = separator {
+            if idx > 0 && group > 0 && idx % group == 0 {
+                len += separator.encode_utf8(&mut out[len..]).len();
+            }
+        }
+
+        out[len] = digits[usize::from(byte >> 4)];
+        out[len + 1] = digits[usize::from(byte & 0x0F)];
+        len += 2;
+    }
+
+    len
+}
+
+/// Renders the bytes in the colon-separated uppercase notation into the start of the `buf`.
+pub(crate) fn write_colon<'b>(buf: &'b mut [u8], bytes: &[u8]) -> Result<&'b str, BufferTooSmall> {
+    let buf = buf.get_mut(..bytes.len() * 3 - 1).ok_or(BufferTooSmall)?;
+    encode(buf, bytes, Some(':'), 1, true);
+
+    // Hex digits and colons are always valid UTF-8.
+    Ok(str::from_utf8(buf).unwrap_or_default())
+}
+
+/// Writes the bytes rendered by the [`encode`] function between the `prefix` and the `suffix`
+/// to the [ufmt] formatter.
+///
+/// [ufmt]: https://docs.rs/ufmt
+#[cfg(feature = "ufmt")]
+pub(crate) fn uwrite_encoded<W: ufmt::uWrite + ?Sized>(
+    f: &mut ufmt::Formatter<'_, W>,
+    prefix: &str,
+    bytes: &[u8],
+    separator: char,
+    suffix: &str,
+) -> Result<(), W::Error> {
+    let mut buf = [0; MAX_LEN];
+    let len = encode(&mut buf, bytes, Some(separator), 1, true);
+
+    f.write_str(prefix)?;
+    f.write_str(str::from_utf8(&buf[..len]).unwrap_or_default())?;
+    f.write_str(suffix)
+}
+
+/// Writes the `prefix` and the bytes rendered by the [`encode`] function,
+/// honoring the width, fill and alignment flags of the formatter.
+pub(crate) fn pad_encoded(
+    f: &mut fmt::Formatter,
+    prefix: &str,
+    bytes: &[u8],
+    separator: Option<char>,
+    group: usize,
+    upper: bool,
+) -> fmt::Result {
+    let mut buf = [0; MAX_LEN];
+    buf[..prefix.len()].copy_from_slice(prefix.as_bytes());
+    let len = prefix.len() + encode(&mut buf[prefix.len()..], bytes, separator, group, upper);
+
+    f.pad(str::from_utf8(&buf[..len]).map_err(|_| fmt::Error)?)
+}
+
+/// Writes the bytes as the hex digits, putting the `separator` between every `group` bytes.
+pub(crate) fn write_grouped(
+    f: &mut fmt::Formatter,
+    bytes: &[u8],
+    separator: Option<char>,
+    group: usize,
+    upper: bool,
+) -> fmt::Result {
+    pad_encoded(f, "", bytes, separator, group, upper)
+}
+
+/// Writes the bytes as the bare hex digits, prefixed with `0x` if the alternate `#` flag is set.
+pub(crate) fn write_hex(f: &mut fmt::Formatter, bytes: &[u8], upper: bool) -> fmt::Result {
+    let prefix = if f.alternate() { "0x" } else { "" };
+
+    pad_encoded(f, prefix, bytes, None, 0, upper)
+}
+
+/// Writes the bytes as a single big-endian number in the radix of `2 ^ bits` (binary or octal),
+/// zero-padded to the address width and prefixed with `prefix` if the alternate `#` flag is set.
+pub(crate) fn write_radix(f: &mut fmt::Formatter, bytes: &[u8], bits: usize, prefix: &str) -> fmt::Result {
+    let value = bytes.iter().fold(0u64, |value, byte| value << 8 | u64::from(*byte));
+    let count = (bytes.len() * 8).div_ceil(bits);
+    let mask = (1 << bits) - 1;
+
+    // Up to 64 binary digits with the `0b` prefix.
+    let mut buf = [0; 66];
+    let mut len = 0;
+    if f.alternate() {
+        buf[..prefix.len()].copy_from_slice(prefix.as_bytes());
+        len = prefix.len();
+    }
+    for idx in (0..count).rev() {
+        buf[len] = b'0' + (value >> (idx * bits) & mask) as u8;
+        len += 1;
+    }
+
+    f.pad(str::from_utf8(&buf[..len]).map_err(|_| fmt::Error)?)
+}
+
+macro_rules! display_adaptor {
+    ($(#[$attr:meta])* $name:ident, $method:literal, $separator:expr, $group:expr) => {
+        $(#[$attr])*
+        ///
+        /// Hex digits are uppercase by default or lowercase if the alternate `#` flag is set.
+        ///
+        #[doc = concat!("Returned by the `", $method, "` methods of the address types.")]
+        #[derive(Debug, Copy, Clone)]
+        pub struct $name<'a>(pub(crate) &'a [u8]);
+
+        impl fmt::Display for $name<'_> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write_grouped(f, self.0, $separator, $group, !f.alternate())
+            }
+        }
+    };
+}
+
+display_adaptor!(
+    /// Helper struct to display the address with colons between every byte, as in `AC:DE:48:00:11:22`.
+    DisplayColon,
+    "display_colon",
+    Some(':'),
+    1
+);
+
+display_adaptor!(
+    /// Helper struct to display the address with hyphens between every byte, as in `AC-DE-48-00-11-22`.
+    ///
+    /// This is the IEEE 802 canonical notation.
+    DisplayHyphen,
+    "display_hyphen",
+    Some('-'),
+    1
+);
+
+display_adaptor!(
+    /// Helper struct to display the address with dots between the 16-bit groups, as in `ACDE.4800.1122`.
+    ///
+    /// This notation is used by the Cisco equipment.
+    DisplayDotted,
+    "display_dotted",
+    Some('.'),
+    2
+);
+
+display_adaptor!(
+    /// Helper struct to display the address as the bare hex digits, as in `ACDE48001122`.
+    DisplayBare,
+    "display_bare",
+    None,
+    0
+);
+
+display_adaptor!(
+    /// Helper struct to display the address with dots between every byte, as in `AC.DE.48.00.11.22`.
+    ///
+    /// This notation is used by some HP/Aruba equipment and older Unix tools.
+    /// It is accepted back by the `parse_lenient` methods.
+    DisplayDottedOctets,
+    "display_dotted_octets",
+    Some('.'),
+    1
+);
+
+/// Helper struct to display the `MacAddr8` address as the IPv6 interface identifier,
+/// with colons between the lowercase 16-bit groups, as in `0223:45ff:fe67:89ab`.
+///
+/// Returned by the `MacAddr8::display_interface_id` method.
+#[derive(Debug, Copy, Clone)]
+pub struct DisplayInterfaceId<'a>(pub(crate) &'a [u8]);
+
+impl fmt::Display for DisplayInterfaceId<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_grouped(f, self.0, Some(':'), 2, false)
+    }
+}
+
+/// Helper struct to display the `MacAddr8` address as the Fibre Channel World Wide Name,
+/// with colons between the lowercase bytes, as in `10:00:00:05:1e:7a:7a:00`.
+///
+/// Returned by the `MacAddr8::display_wwn` method.
+#[derive(Debug, Copy, Clone)]
+pub struct DisplayWwn<'a>(pub(crate) &'a [u8]);
+
+impl fmt::Display for DisplayWwn<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_grouped(f, self.0, Some(':'), 1, false)
+    }
+}
+
+/// Helper struct to display the `MacAddr6` address in the SNMP `MacAddress` textual convention,
+/// with colons between the lowercase bytes without the leading zeros, as in `0:1a:2b:3c:4d:5e`.
+///
+/// Returned by the `MacAddr6::display_snmp` method.
+#[derive(Debug, Copy, Clone)]
+pub struct DisplaySnmp<'a>(pub(crate) &'a [u8]);
+
+impl fmt::Display for DisplaySnmp<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut buf = [0; MAX_LEN];
+        let mut len = 0;
+        for (idx, byte) in self.0.iter().enumerate() {
+            if idx > 0 {
+                buf[len] = b':';
+                len += 1;
+            }
+            if *byte > 0x0F {
+                buf[len] = LOWER_DIGITS[usize::from(byte >> 4)];
+                len += 1;
+            }
+            buf[len] = LOWER_DIGITS[usize::from(byte & 0x0F)];
+            len += 1;
+        }
+
+        f.pad(str::from_utf8(&buf[..len]).map_err(|_| fmt::Error)?)
+    }
+}
+
+/// Custom notation of the address, used with the `format` methods of the address types.
+///
+/// ## Example
+///
+/// ```rust
+/// # use macaddr::{MacAddr6, MacFormat};
+/// let addr = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22);
+/// let format = MacFormat {
+///     separator: Some(' '),
+///     uppercase: false,
+///     group_size: 3,
+/// };
+///
+/// assert_eq!(addr.format(&format).to_string(), "acde48 001122");
+/// ```
+#[derive(Debug, Hash, Eq, PartialEq, Copy, Clone)]
+pub struct MacFormat {
+    /// Separator put between the groups of bytes, or `None` for the bare hex digits.
+    pub separator: Option<char>,
+    /// Whether uppercase hex digits are used.
+    pub uppercase: bool,
+    /// Amount of bytes in each group; zero means no grouping at all.
+    pub group_size: usize,
+}
+
+impl MacFormat {
+    /// Colon-separated uppercase notation, as in `AC:DE:48:00:11:22`, used by the `Display` implementations.
+    pub const COLON: MacFormat = MacFormat::new(Some(':'), true, 1);
+
+    /// Hyphen-separated uppercase notation, as in `AC-DE-48-00-11-22`, as defined by IEEE 802.
+    pub const HYPHEN: MacFormat = MacFormat::new(Some('-'), true, 1);
+
+    /// Dot-separated uppercase 16-bit groups, as in `ACDE.4800.1122`, used by the Cisco equipment.
+    pub const DOTTED: MacFormat = MacFormat::new(Some('.'), true, 2);
+
+    /// Bare uppercase hex digits, as in `ACDE48001122`.
+    pub const BARE: MacFormat = MacFormat::new(None, true, 0);
+
+    /// Creates a new notation.
+    pub const fn new(separator: Option<char>, uppercase: bool, group_size: usize) -> MacFormat {
+        MacFormat {
+            separator,
+            uppercase,
+            group_size,
+        }
+    }
+}
+
+impl Default for MacFormat {
+    fn default() -> MacFormat {
+        MacFormat::COLON
+    }
+}
+
+/// Helper struct to display the address in the custom [`MacFormat`] notation.
+///
+/// Returned by the `format` methods of the address types.
+#[derive(Debug, Copy, Clone)]
+pub struct DisplayFormat<'a> {
+    pub(crate) bytes: &'a [u8],
+    pub(crate) format: MacFormat,
+}
+
+impl fmt::Display for DisplayFormat<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let format = &self.format;
+
+        write_grouped(f, self.bytes, format.separator, format.group_size, format.uppercase)
+    }
+}
+
+/// An error which is returned when the buffer is too small to write the address into.
+///
+/// It is returned by the `write_str` methods of the address types.
+/// Buffers of the `MAX_DISPLAY_LEN` bytes are always large enough.
+#[derive(Debug, Hash, Eq, PartialEq, Ord, PartialOrd, Copy, Clone)]
+pub struct BufferTooSmall;
+
+impl fmt::Display for BufferTooSmall {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Buffer is too small to write the MAC address")
+    }
+}
+
+impl Error for BufferTooSmall {}
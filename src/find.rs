@@ -0,0 +1,75 @@
+//! Searching for the MAC addresses in the free-form text.
+
+use core::ops::Range;
+
+use crate::{parser::Parser, ParseError};
+
+const fn is_delimiter(byte: u8) -> bool {
+    matches!(byte, b'-' | b':' | b'.')
+}
+
+/// Checks if the address can't be a part of the preceding word or of a longer address.
+fn is_start_boundary(source: &[u8], start: usize) -> bool {
+    match start.checked_sub(1).map(|idx| source[idx]) {
+        None => true,
+        Some(prev) if is_delimiter(prev) => start < 2 || !source[start - 2].is_ascii_hexdigit(),
+        Some(prev) => !prev.is_ascii_alphanumeric(),
+    }
+}
+
+/// Checks if the address can't be a part of the following word or of a longer address.
+fn is_end_boundary(source: &[u8], end: usize) -> bool {
+    match source.get(end) {
+        None => true,
+        Some(&next) if is_delimiter(next) => !source.get(end + 1).is_some_and(u8::is_ascii_hexdigit),
+        Some(next) => !next.is_ascii_alphanumeric(),
+    }
+}
+
+/// Matches the address with `bare_len` hex digits starting exactly at the `start` byte offset.
+///
+/// Bare hex form is not matched, as it is indistinguishable from the plain numbers in the text.
+pub fn match_at<'a, T>(
+    s: &'a str,
+    start: usize,
+    bare_len: usize,
+    read: fn(&mut Parser<'a>) -> Result<T, ParseError>,
+) -> Option<(Range<usize>, T)> {
+    let source = s.as_bytes();
+    if !source[start].is_ascii_hexdigit() || !is_start_boundary(source, start) {
+        return None;
+    }
+
+    let mut parser = Parser::with_offset(s, start);
+    let addr = read(&mut parser).ok()?;
+    let end = parser.position();
+
+    if end - start > bare_len && is_end_boundary(source, end) {
+        Some((start..end, addr))
+    } else {
+        None
+    }
+}
+
+/// Returns the first address matched by `matcher` at or after the `from` byte offset.
+pub fn find<T, F>(s: &str, from: usize, matcher: F) -> Option<(Range<usize>, T)>
+where
+    F: Fn(&str, usize) -> Option<(Range<usize>, T)>,
+{
+    (from..s.len()).find_map(|start| matcher(s, start))
+}
+
+/// Returns an iterator over the non-overlapping addresses matched by `matcher`.
+pub fn find_all<T, F>(s: &str, matcher: F) -> impl Iterator<Item = (Range<usize>, T)> + '_
+where
+    F: Fn(&str, usize) -> Option<(Range<usize>, T)> + 'static,
+{
+    let mut pos = 0;
+
+    core::iter::from_fn(move || {
+        let (range, addr) = find(s, pos, &matcher)?;
+        pos = range.end;
+
+        Some((range, addr))
+    })
+}
@@ -25,24 +25,240 @@
 //! This crate can be used in a `no_std` builds with
 //! disabled `"std"` feature (enabled by default).
 //!
+//! Parsing, formatting and the [ParseError] type are available without `"std"`
+//! and do not allocate; [ParseError] implements the `core::error::Error` trait.
+//!
+//! Enabled `"alloc"` feature (implied by `"std"`) will add the methods
+//! returning the `String`s, such as `MacAddr6::to_canonical_string`.
+//!
 //! Enabled `"serde"` feature will add support for `no_std`
 //! serde serialization and deserialization.
 //!
+//! Addresses are serialized as the strings in the canonical notation for the human-readable formats
+//! and as the raw bytes for the binary ones; see the [serde] module for the alternatives.
+//!
+//! ## JSON Schema support
+//!
+//! [schemars] `JsonSchema` implementations can be enabled with a `"schemars"` feature
+//! (disabled by default, requires `"std"`). Addresses are described as the strings
+//! with the `mac-address` format and a pattern matching the notations accepted by the parser.
+//!
+//! ## rkyv support
+//!
+//! [rkyv] zero-copy archiving support can be enabled with a `"rkyv"` feature (disabled by default).
+//! Archived addresses are stored as the raw bytes and can be compared with the original types.
+//!
+//! ## Borsh support
+//!
+//! [Borsh] serialization can be enabled with a `"borsh"` feature (disabled by default).
+//! [MacAddr6] and [MacAddr8] are serialized as the fixed 6 and 8 bytes arrays,
+//! and [MacAddr](enum.MacAddr.html) as the enum, with a variant index byte before the address bytes.
+//!
+//! ## speedy support
+//!
+//! [speedy] `Readable` and `Writable` implementations can be enabled with a `"speedy"` feature
+//! (disabled by default, requires `"std"`). Addresses are written as the raw bytes,
+//! [MacAddr](enum.MacAddr.html) is prefixed with a variant index byte.
+//!
+//! ## bincode support
+//!
+//! [bincode] 2 `Encode` and `Decode` implementations can be enabled with a `"bincode"` feature
+//! (disabled by default). Addresses are encoded as the fixed-size arrays without a length prefix,
+//! [MacAddr](enum.MacAddr.html) is prefixed with a variant index byte.
+//!
+//! ## Protobuf support
+//!
+//! Enabled `"prost"` feature (disabled by default, requires `"alloc"`) will add the conversions
+//! between the address types and the [`bytes::Bytes`] used for the protobuf `bytes` fields by [prost],
+//! checking the length of the received value.
+//!
+//! ## zerocopy support
+//!
+//! [zerocopy] `FromBytes`, `IntoBytes`, `KnownLayout`, `Immutable` and `Unaligned` implementations
+//! for [MacAddr6] and [MacAddr8] can be enabled with a `"zerocopy"` feature (disabled by default),
+//! so the packet headers containing them can be reinterpreted from the raw bytes without copying.
+//!
+//! ## bytemuck support
+//!
+//! [bytemuck] `Pod` and `Zeroable` implementations for [MacAddr6] and [MacAddr8] can be enabled
+//! with a `"bytemuck"` feature (disabled by default), so the addresses can be cast from and to the byte slices
+//! and used in the `#[repr(C)]` structs deriving these traits.
+//!
+//! ## Fuzzing support
+//!
+//! [arbitrary] `Arbitrary` implementations can be enabled with an `"arbitrary"` feature (disabled by default),
+//! so the fuzz targets can generate the addresses directly from the fuzzer input.
+//!
+//! [proptest] strategies constrained by the address class are provided in the [proptest](proptest/index.html)
+//! module with a `"proptest"` feature (disabled by default, requires `"std"`), and [quickcheck] `Arbitrary`
+//! implementations with a `"quickcheck"` feature (disabled by default, requires `"std"`).
+//!
+//! ## rand support
+//!
+//! Enabled `"rand"` feature (disabled by default) will add the `random` and `random_local_unicast` methods
+//! generating the addresses with the [rand] random number generators,
+//! as well as the `Distribution` implementations for the `Standard` distribution.
+//!
+//! ## PostgreSQL support
+//!
+//! [postgres-types] `FromSql` and `ToSql` implementations can be enabled with a `"postgres"` feature
+//! (disabled by default, requires `"std"`), mapping [MacAddr6] to the `MACADDR` and [MacAddr8]
+//! to the `MACADDR8` column types.
+//!
+//! [sqlx] `Type`, `Encode` and `Decode` implementations can be enabled with the `"sqlx-postgres"`,
+//! `"sqlx-mysql"` and `"sqlx-sqlite"` features (disabled by default, require `"std"`).
+//! PostgreSQL columns are of the same native types, while MySQL and SQLite ones store the addresses as text.
+//! As sqlx does not know about these types, the compile-time checked queries should use the type overrides,
+//! as in `SELECT addr AS "addr: MacAddr6" FROM interfaces`.
+//!
+//! [Diesel] `FromSql` and `ToSql` implementations for [MacAddr6] and the PostgreSQL `MacAddr` SQL type
+//! can be enabled with a `"diesel"` feature (disabled by default, requires `"std"`).
+//!
+//! [rusqlite] `FromSql` and `ToSql` implementations can be enabled with a `"rusqlite"` feature
+//! (disabled by default, requires `"std"`). Addresses are stored as the raw bytes blobs,
+//! while the text values are accepted on read too.
+//!
+//! ## Apache Arrow support
+//!
+//! Enabled `"arrow"` feature (disabled by default, requires `"std"`) will add the [arrow](arrow/index.html)
+//! module with the conversions between the addresses and the [arrow-rs] `FixedSizeBinaryArray` columns,
+//! along with the extension type names for the column fields.
+//!
+//! ## ClickHouse support
+//!
+//! Enabled `"clickhouse"` feature (disabled by default, implies `"serde"`) will add
+//! the [clickhouse](clickhouse/index.html) module with the serde helpers storing [MacAddr6]
+//! in the `UInt64` and `FixedString(6)` columns of the [ClickHouse] rows.
+//!
+//! ## OUI vendor database
+//!
+//! Enabled `"oui-db"` feature (disabled by default) will add the [vendor](vendor/index.html) module
+//! with the embedded snapshot of the IEEE Registration Authority registry and the `Oui::vendor` method,
+//! so the organization name can be shown for the address, as in `Cisco Systems, Inc`.
+//! The feature is `no_std`-compatible, but adds about 1.2 MB of data to the binary;
+//! the data is shipped in the separate `macaddr-oui` crate, so it is not downloaded without the feature.
+//!
+//! With the `"std"` feature, the module also provides the `VendorDatabase` trait
+//! and the `Registry` type loading the IEEE registry files at runtime.
+//!
+//! ## Hashing
+//!
+//! [MacAddr6] and [MacAddr8] are hashed as the single `u64` integer returned by their `as_hash_key` methods,
+//! so the hash maps keyed by the addresses can use the identity hashers instead of the default SipHash one.
+//!
+//! Enabled `"nohash-hasher"` feature (disabled by default) will implement the [nohash-hasher] `IsEnabled` trait
+//! for them, so they can be used as the `IntMap` and the `IntSet` keys.
+//!
+//! ## defmt support
+//!
+//! [defmt] logging support can be enabled with a `"defmt"` feature (disabled by default).
+//! Addresses are transferred as the raw bytes and rendered by the host in the same colon-separated notation
+//! as with the `Display` implementations, as in `01:23:45:67:89:AB`.
+//!
+//! ## ufmt support
+//!
+//! [ufmt] `uDisplay` and `uDebug` implementations can be enabled with a `"ufmt"` feature
+//! (disabled by default), so the addresses can be written with `uwrite!` without the `core::fmt` machinery.
+//!
 //! [Serde]: https://serde.rs
+//! [schemars]: https://docs.rs/schemars/0.8
+//! [rkyv]: https://rkyv.org
+//! [Borsh]: https://borsh.io
+//! [speedy]: https://docs.rs/speedy
+//! [bincode]: https://docs.rs/bincode/2
+//! [prost]: https://docs.rs/prost
+//! [`bytes::Bytes`]: https://docs.rs/bytes/1/bytes/struct.Bytes.html
+//! [zerocopy]: https://docs.rs/zerocopy
+//! [bytemuck]: https://docs.rs/bytemuck
+//! [arbitrary]: https://docs.rs/arbitrary
+//! [proptest]: https://docs.rs/proptest
+//! [quickcheck]: https://docs.rs/quickcheck
+//! [rand]: https://docs.rs/rand/0.8
+//! [postgres-types]: https://docs.rs/postgres-types
+//! [sqlx]: https://docs.rs/sqlx
+//! [Diesel]: https://diesel.rs
+//! [rusqlite]: https://docs.rs/rusqlite
+//! [arrow-rs]: https://docs.rs/arrow
+//! [ClickHouse]: https://clickhouse.com
+//! [nohash-hasher]: https://docs.rs/nohash-hasher
+//! [defmt]: https://defmt.ferrous-systems.com
+//! [ufmt]: https://docs.rs/ufmt
 //! [MAC address]: https://en.wikipedia.org/wiki/MAC_address
 //! [this Cargo bug]: https://github.com/rust-lang/cargo/issues/3494
 //! [MacAddr6]: struct.MacAddr6.html
 //! [MacAddr8]: struct.MacAddr8.html
+//! [ParseError]: enum.ParseError.html
+//! [serde]: serde/index.html
 #![cfg_attr(not(feature = "std"), no_std)]
-#![doc(html_root_url = "https://docs.rs/macaddr/1.0.0")]
+#![doc(html_root_url = "https://docs.rs/macaddr/2.0.0")]
 #![forbid(unsafe_code)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 mod addr;
 mod addr6;
 mod addr8;
+#[cfg(feature = "arrow")]
+pub mod arrow;
+mod block;
+#[cfg(feature = "clickhouse")]
+pub mod clickhouse;
+pub mod consts;
+mod display;
+mod find;
+mod list;
+mod macros;
+mod mask;
+mod oui;
 mod parser;
+#[cfg(feature = "alloc")]
+mod pool;
+mod prefix;
+mod range;
+#[cfg(feature = "alloc")]
+mod set;
+#[cfg(feature = "alloc")]
+mod trie;
+#[cfg(feature = "proptest")]
+pub mod proptest;
+#[cfg(feature = "schemars")]
+mod schema;
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(any(feature = "sqlx-postgres", feature = "sqlx-mysql", feature = "sqlx-sqlite"))]
+mod sql;
+#[cfg(any(feature = "oui-db", feature = "std"))]
+pub mod vendor;
+pub mod wifi;
+pub mod wol;
 
 pub use self::addr::MacAddr;
+#[cfg(feature = "rkyv")]
+pub use self::addr::{ArchivedMacAddr, MacAddrResolver};
 pub use self::addr6::MacAddr6;
+#[cfg(feature = "rkyv")]
+pub use self::addr6::{ArchivedMacAddr6, MacAddr6Resolver};
 pub use self::addr8::MacAddr8;
-pub use self::parser::ParseError;
+#[cfg(feature = "rkyv")]
+pub use self::addr8::{ArchivedMacAddr8, MacAddr8Resolver};
+pub use self::block::{AssignmentBlock, IanaAssignment, SlapQuadrant};
+pub use self::display::{
+    BufferTooSmall, DisplayBare, DisplayColon, DisplayDotted, DisplayDottedOctets, DisplayFormat, DisplayHyphen,
+    DisplayInterfaceId, DisplaySnmp, DisplayWwn, MacFormat,
+};
+pub use self::list::parse_list;
+pub use self::mask::MacAddrMask;
+pub use self::oui::Oui;
+pub use self::parser::{LetterCase, ParseError, ParseErrorKind, ParseOptions};
+#[cfg(feature = "alloc")]
+pub use self::pool::MacAddrPool;
+pub use self::prefix::{MacPrefix, MacPrefixIter};
+pub use self::range::MacAddr6Range;
+#[cfg(feature = "alloc")]
+pub use self::set::{MacSet, MacSetIter};
+#[cfg(feature = "alloc")]
+pub use self::trie::{MacTrie, MacTrieIter};
+
+#[doc(hidden)]
+pub use self::macros::private as __private;
@@ -0,0 +1,29 @@
+use core::str::FromStr;
+
+use crate::{MacAddr, ParseError};
+
+/// Parses the list of MAC addresses separated by commas, whitespace or newlines.
+///
+/// Each list item is parsed with the [MacAddr] `FromStr` implementation,
+/// so positions in the returned errors are relative to the start of the item.
+/// Empty items, as in the trailing comma, are skipped.
+///
+/// ## Example
+///
+/// ```rust
+/// # use macaddr::{parse_list, MacAddr, MacAddr6, ParseError};
+/// let list = "ac:de:48:00:11:22, ac:de:48:00:11:2Z\nacde.4800.1122.3344,";
+/// let addrs: Vec<Result<MacAddr, ParseError>> = parse_list(list).collect();
+///
+/// assert_eq!(addrs[0], Ok(MacAddr::V6(MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22))));
+/// assert_eq!(addrs[1], Err(ParseError::InvalidCharacter('Z', 16)));
+/// assert!(addrs[2].as_ref().unwrap().is_v8());
+/// assert_eq!(addrs.len(), 3);
+/// ```
+///
+/// [MacAddr]: enum.MacAddr.html
+pub fn parse_list(s: &str) -> impl Iterator<Item = Result<MacAddr, ParseError>> + '_ {
+    s.split(|chr: char| chr == ',' || chr.is_whitespace())
+        .filter(|item| !item.is_empty())
+        .map(MacAddr::from_str)
+}
@@ -0,0 +1,200 @@
+/// Creates a MAC address from the string literal, validating it at compile time.
+///
+/// Literal is accepted in any notation supported by the `FromStr` implementations
+/// and expands either to the [MacAddr6] or to the [MacAddr8] value,
+/// depending on the amount of bytes in it.
+/// Invalid literal fails the compilation.
+///
+/// Wrap the result into the [MacAddr] enum members if the enum is needed.
+///
+/// ## Example
+///
+/// ```rust
+/// # use macaddr::{mac, MacAddr, MacAddr6, MacAddr8};
+/// const ADDR6: MacAddr6 = mac!("AC:DE:48:00:11:22");
+/// const ADDR8: MacAddr8 = mac!("AC-DE-48-00-11-22-33-44");
+/// const ADDR: MacAddr = MacAddr::V6(mac!("acde.4800.1122"));
+///
+/// assert_eq!(ADDR6, MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22));
+/// assert_eq!(ADDR8, MacAddr8::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44));
+/// assert_eq!(ADDR, MacAddr::from(ADDR6));
+/// ```
+///
+/// Invalid literals are not compiled:
+///
+/// ```rust,compile_fail
+/// # use macaddr::mac;
+/// let addr = mac!("AC:DE:48:00:11:2Z");
+/// ```
+///
+/// [MacAddr6]: struct.MacAddr6.html
+/// [MacAddr8]: struct.MacAddr8.html
+/// [MacAddr]: enum.MacAddr.html
+#[macro_export]
+macro_rules! mac {
+    ($s:literal) => {{
+        type Literal = $crate::__private::Literal<{ $crate::__private::literal_len($s) }>;
+
+        const ADDR: <Literal as $crate::__private::LiteralAddr>::Addr = Literal::parse($s);
+
+        ADDR
+    }};
+}
+
+/// Implementation details of the `mac!` macro, not a part of the public API.
+///
+/// Address type is selected by the `Literal` parameter,
+/// because each `Literal<N>` has its own `parse` method.
+pub mod private {
+    use crate::{parser, MacAddr6, MacAddr8};
+
+    pub struct Literal<const N: usize>;
+
+    pub trait LiteralAddr {
+        type Addr;
+    }
+
+    impl LiteralAddr for Literal<6> {
+        type Addr = MacAddr6;
+    }
+
+    impl LiteralAddr for Literal<8> {
+        type Addr = MacAddr8;
+    }
+
+    impl Literal<6> {
+        pub const fn parse(s: &str) -> MacAddr6 {
+            MacAddr6::parse_const(s)
+        }
+    }
+
+    impl Literal<8> {
+        pub const fn parse(s: &str) -> MacAddr8 {
+            MacAddr8::parse_const(s)
+        }
+    }
+
+    /// Returns the amount of bytes in the MAC address literal.
+    pub const fn literal_len(s: &str) -> usize {
+        let source = s.as_bytes();
+        let mut digits = 0;
+        let mut i = 0;
+
+        while i < source.len() {
+            if source[i].is_ascii_hexdigit() {
+                digits += 1;
+            }
+            i += 1;
+        }
+
+        match digits {
+            12 => 6,
+            16 => 8,
+            _ => parser::unwrap_const(Err(parser::ParseError::InvalidLength(source.len()))),
+        }
+    }
+}
+
+/// Implements the comparisons between the address type and the bytes type in both directions,
+/// comparing the address bytes as a slice.
+macro_rules! impl_bytes_cmp {
+    ($(#[$attr:meta])* $name:ident, $bytes:ty $(, $lt:lifetime)?) => {
+        $(#[$attr])*
+        impl<$($lt)?> PartialEq<$bytes> for $name {
+            fn eq(&self, other: &$bytes) -> bool {
+                self.as_bytes()[..] == other[..]
+            }
+        }
+
+        impl<$($lt)?> PartialEq<$name> for $bytes {
+            fn eq(&self, other: &$name) -> bool {
+                self[..] == other.as_bytes()[..]
+            }
+        }
+
+        impl<$($lt)?> PartialOrd<$bytes> for $name {
+            fn partial_cmp(&self, other: &$bytes) -> Option<core::cmp::Ordering> {
+                self.as_bytes()[..].partial_cmp(&other[..])
+            }
+        }
+
+        impl<$($lt)?> PartialOrd<$name> for $bytes {
+            fn partial_cmp(&self, other: &$name) -> Option<core::cmp::Ordering> {
+                self[..].partial_cmp(&other.as_bytes()[..])
+            }
+        }
+    };
+}
+
+pub(crate) use impl_bytes_cmp;
+
+/// Implements the bitwise operators for the address type, with the other address,
+/// the bytes array or the big-endian integer as the right operand.
+macro_rules! impl_bit_ops {
+    ($(#[$attr:meta])* $name:ident, $len:literal) => {
+        $(#[$attr])*
+        impl core::ops::Not for $name {
+            type Output = $name;
+
+            fn not(self) -> $name {
+                let mut bytes = self.into_array();
+                for byte in bytes.iter_mut() {
+                    *byte = !*byte;
+                }
+
+                $name::from(bytes)
+            }
+        }
+
+        $crate::macros::impl_bit_ops!(@op $name, $len, BitAnd, bitand, BitAndAssign, bitand_assign, &, &=);
+        $crate::macros::impl_bit_ops!(@op $name, $len, BitOr, bitor, BitOrAssign, bitor_assign, |, |=);
+        $crate::macros::impl_bit_ops!(@op $name, $len, BitXor, bitxor, BitXorAssign, bitxor_assign, ^, ^=);
+    };
+    (
+        @op $name:ident, $len:literal, $op:ident, $method:ident,
+        $assign:ident, $assign_method:ident, $tok:tt, $assign_tok:tt
+    ) => {
+        impl core::ops::$op<[u8; $len]> for $name {
+            type Output = $name;
+
+            fn $method(self, rhs: [u8; $len]) -> $name {
+                let mut bytes = self.into_array();
+                for (byte, rhs) in bytes.iter_mut().zip(rhs.iter()) {
+                    *byte $assign_tok *rhs;
+                }
+
+                $name::from(bytes)
+            }
+        }
+
+        impl core::ops::$op for $name {
+            type Output = $name;
+
+            fn $method(self, rhs: $name) -> $name {
+                self $tok rhs.into_array()
+            }
+        }
+
+        impl core::ops::$op<u64> for $name {
+            type Output = $name;
+
+            fn $method(self, rhs: u64) -> $name {
+                let mut bytes = [0u8; $len];
+                bytes.copy_from_slice(&rhs.to_be_bytes()[8 - $len..]);
+
+                self $tok bytes
+            }
+        }
+
+        impl<T> core::ops::$assign<T> for $name
+        where
+            $name: core::ops::$op<T, Output = $name>,
+        {
+            fn $assign_method(&mut self, rhs: T) {
+                *self = *self $tok rhs;
+            }
+        }
+    };
+}
+
+pub(crate) use impl_bit_ops;
@@ -0,0 +1,249 @@
+use core::{convert::TryFrom, fmt, str::FromStr};
+
+use crate::{parser::Parser, MacAddr6, MacPrefix, ParseError};
+
+/// Wildcard match of the `MacAddr6` addresses, the pattern bits of which are compared only
+/// where the mask bits are set, written in the OpenFlow notation, as in `01:00:00:00:00:00/01:00:00:00:00:00`.
+///
+/// Unlike [MacPrefix], the mask bits are not required to be contiguous.
+///
+/// ## Example
+///
+/// ```rust
+/// # use macaddr::{MacAddr6, MacAddrMask};
+/// let multicast: MacAddrMask = "01:00:00:00:00:00/01:00:00:00:00:00".parse().unwrap();
+///
+/// assert!(multicast.matches(&MacAddr6::broadcast()));
+/// assert!(!multicast.matches(&MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22)));
+/// ```
+///
+/// [MacPrefix]: struct.MacPrefix.html
+#[derive(Debug, Hash, Eq, PartialEq, Ord, PartialOrd, Copy, Clone)]
+pub struct MacAddrMask {
+    pattern: MacAddr6,
+    mask: MacAddr6,
+}
+
+impl MacAddrMask {
+    /// Creates a new `MacAddrMask`, clearing the pattern bits which are not selected by the mask.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::{MacAddr6, MacAddrMask};
+    /// let mask = MacAddrMask::new(MacAddr6::broadcast(), MacAddr6::new(0x01, 0x00, 0x00, 0x00, 0x00, 0x00));
+    ///
+    /// assert_eq!(mask.pattern(), MacAddr6::new(0x01, 0x00, 0x00, 0x00, 0x00, 0x00));
+    /// ```
+    pub const fn new(pattern: MacAddr6, mask: MacAddr6) -> MacAddrMask {
+        let [a, b, c, d, e, f] = pattern.into_array();
+        let [g, h, i, j, k, l] = mask.into_array();
+
+        MacAddrMask {
+            pattern: MacAddr6::new(a & g, b & h, c & i, d & j, e & k, f & l),
+            mask,
+        }
+    }
+
+    /// Creates a new `MacAddrMask` matching only the address itself.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::{MacAddr6, MacAddrMask};
+    /// let mask = MacAddrMask::exact(MacAddr6::broadcast());
+    ///
+    /// assert!(mask.matches(&MacAddr6::broadcast()));
+    /// assert!(!mask.matches(&MacAddr6::nil()));
+    /// ```
+    pub const fn exact(addr: MacAddr6) -> MacAddrMask {
+        MacAddrMask::new(addr, MacAddr6::broadcast())
+    }
+
+    /// Creates a new `MacAddrMask` matching any address.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::{MacAddr6, MacAddrMask};
+    /// assert!(MacAddrMask::any().matches(&MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22)));
+    /// ```
+    pub const fn any() -> MacAddrMask {
+        MacAddrMask::new(MacAddr6::nil(), MacAddr6::nil())
+    }
+
+    /// Returns the pattern with the bits not selected by the mask cleared.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::{MacAddr6, MacAddrMask};
+    /// let mask: MacAddrMask = "AC:DE:48:00:11:22/FF:FF:FF:00:00:00".parse().unwrap();
+    ///
+    /// assert_eq!(mask.pattern(), MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x00, 0x00));
+    /// ```
+    pub const fn pattern(&self) -> MacAddr6 {
+        self.pattern
+    }
+
+    /// Returns the mask selecting the compared bits.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::{MacAddr6, MacAddrMask};
+    /// let mask: MacAddrMask = "AC:DE:48:00:11:22/FF:FF:FF:00:00:00".parse().unwrap();
+    ///
+    /// assert_eq!(mask.mask(), MacAddr6::new(0xFF, 0xFF, 0xFF, 0x00, 0x00, 0x00));
+    /// ```
+    pub const fn mask(&self) -> MacAddr6 {
+        self.mask
+    }
+
+    /// Returns `true` if the address bits selected by the mask are equal to the pattern bits,
+    /// same as the [`MacAddr6::matches`] method.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::{MacAddr6, MacAddrMask};
+    /// let mask: MacAddrMask = "00:00:00:00:00:00/03:00:00:00:00:00".parse().unwrap();
+    ///
+    /// assert!(mask.matches(&MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22)));
+    /// assert!(!mask.matches(&MacAddr6::new(0xAE, 0xDE, 0x48, 0x00, 0x11, 0x22)));
+    /// ```
+    ///
+    /// [`MacAddr6::matches`]: struct.MacAddr6.html#method.matches
+    pub const fn matches(&self, addr: &MacAddr6) -> bool {
+        addr.matches(self.pattern, self.mask)
+    }
+
+    /// Returns the `MacPrefix` matching the same addresses, or `None` if the mask bits are not contiguous.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::{MacAddrMask, MacPrefix};
+    /// let mask: MacAddrMask = "AC:DE:48:00:00:00/FF:FF:FF:00:00:00".parse().unwrap();
+    ///
+    /// assert_eq!(mask.to_prefix(), "AC:DE:48:00:00:00/24".parse::<MacPrefix>().ok());
+    /// assert_eq!("01:00:00:00:00:00/01:00:00:00:00:00".parse::<MacAddrMask>().unwrap().to_prefix(), None);
+    /// ```
+    pub const fn to_prefix(&self) -> Option<MacPrefix> {
+        // Contiguous masks are the leading ones of the 48 bits shifted to the top of the integer.
+        let mask = self.mask.to_u64() << 16;
+        if mask.leading_ones() + mask.trailing_zeros() < 64 {
+            return None;
+        }
+
+        MacPrefix::new(self.pattern, mask.leading_ones() as u8)
+    }
+}
+
+/// `MacAddrMask` matching the same addresses as the prefix.
+///
+/// # Example
+///
+/// ```
+/// # use macaddr::{MacAddr6, MacAddrMask, MacPrefix};
+/// let prefix: MacPrefix = "AC:DE:48:00:00:00/28".parse().unwrap();
+///
+/// assert_eq!(MacAddrMask::from(prefix).mask(), MacAddr6::new(0xFF, 0xFF, 0xFF, 0xF0, 0x00, 0x00));
+/// ```
+impl From<MacPrefix> for MacAddrMask {
+    fn from(prefix: MacPrefix) -> Self {
+        MacAddrMask::new(prefix.first(), MacAddr6::broadcast() ^ prefix.first() ^ prefix.last())
+    }
+}
+
+impl From<MacAddr6> for MacAddrMask {
+    fn from(addr: MacAddr6) -> Self {
+        MacAddrMask::exact(addr)
+    }
+}
+
+/// `MacAddrMask` is parsed from the pattern and the mask addresses in any notation accepted
+/// by the [MacAddr6] parser, separated with a slash.
+///
+/// Pattern without the mask is matched exactly, as in the OpenFlow flows.
+///
+/// # Example
+///
+/// ```
+/// # use macaddr::{MacAddr6, MacAddrMask, ParseError};
+/// let addr = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22);
+/// let mask = MacAddrMask::new(addr, MacAddr6::new(0xFF, 0xFF, 0xFF, 0x00, 0x00, 0x00));
+///
+/// assert_eq!("AC:DE:48:00:00:00/FF:FF:FF:00:00:00".parse(), Ok(mask));
+/// assert_eq!("acde.4800.1122/ffff.ff00.0000".parse(), Ok(mask));
+/// assert_eq!("AC:DE:48:00:11:22".parse(), Ok(MacAddrMask::exact(addr)));
+/// assert!("AC:DE:48:00:00:00/FF:FF:FF".parse::<MacAddrMask>().is_err());
+/// assert_eq!(
+///     "12:34:56:78:9A:BC/ff:ff:ff:00:00:0g".parse::<MacAddrMask>(),
+///     Err(ParseError::InvalidCharacter('g', 34))
+/// );
+/// assert_eq!(
+///     "AC:DE:48:00:00:00/FF:FF:FF:00:00:00/x".parse::<MacAddrMask>(),
+///     Err(ParseError::InvalidCharacter('/', 35))
+/// );
+/// ```
+///
+/// [MacAddr6]: struct.MacAddr6.html
+impl FromStr for MacAddrMask {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once('/') {
+            Some((pattern, mask)) => {
+                let pattern = MacAddr6::from_str(pattern)?;
+                let start = s.len() - mask.len();
+                // Mask is parsed in place, so its errors are reported at the positions in the whole string.
+                match mask.find('/') {
+                    Some(extra) => match Parser::with_offset(&s[..start + extra], start).read_v6_addr() {
+                        Err(ParseError::InvalidLength(_)) | Ok(_) => {
+                            Err(ParseError::InvalidCharacter('/', start + extra))
+                        }
+                        Err(err) => Err(err),
+                    },
+                    None => {
+                        let mask = Parser::with_offset(s, start).read_v6_addr()?;
+                        Ok(MacAddrMask::new(pattern, mask))
+                    }
+                }
+            }
+            None => MacAddr6::from_str(s).map(MacAddrMask::exact),
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a str> for MacAddrMask {
+    type Error = ParseError;
+
+    fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+        MacAddrMask::from_str(s)
+    }
+}
+
+/// `MacAddrMask` is displayed as the pattern and the mask separated with a slash,
+/// as in `01:00:00:00:00:00/01:00:00:00:00:00`.
+///
+/// Formatting flags are applied to both addresses as for the [MacAddr6] `Display` implementation.
+///
+/// # Example
+///
+/// ```
+/// # use macaddr::MacAddrMask;
+/// let mask: MacAddrMask = "ac-de-48-00-11-22/ff-ff-ff-00-00-00".parse().unwrap();
+///
+/// assert_eq!(mask.to_string(), "AC:DE:48:00:00:00/FF:FF:FF:00:00:00");
+/// assert_eq!(format!("{:-#}", mask), "ac-de-48-00-00-00/ff-ff-ff-00-00-00");
+/// ```
+///
+/// [MacAddr6]: struct.MacAddr6.html
+impl fmt::Display for MacAddrMask {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.pattern, f)?;
+        f.write_str("/")?;
+        fmt::Display::fmt(&self.mask, f)
+    }
+}
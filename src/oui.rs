@@ -0,0 +1,192 @@
+use core::{convert::TryFrom, fmt, str::FromStr};
+
+use crate::{display, parser, MacAddr6, MacAddr8, ParseError, ParseOptions};
+
+/// Organizationally Unique Identifier (OUI), the 24-bit vendor prefix of the MAC addresses
+/// assigned by the IEEE Registration Authority.
+///
+/// ## Example
+///
+/// ```rust
+/// # use macaddr::{MacAddr6, Oui};
+/// let oui: Oui = "AC-DE-48".parse().unwrap();
+/// let addr = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22);
+///
+/// assert_eq!(Oui::from(addr), oui);
+/// assert!(oui.contains(&addr));
+/// assert_eq!(oui.to_string(), "AC-DE-48");
+/// ```
+#[derive(Default, Hash, Eq, PartialEq, Ord, PartialOrd, Copy, Clone)]
+pub struct Oui([u8; 3]);
+
+impl Oui {
+    /// Creates a new `Oui` from the three bytes.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::Oui;
+    /// let oui = Oui::new(0xAC, 0xDE, 0x48);
+    /// ```
+    pub const fn new(a: u8, b: u8, c: u8) -> Oui {
+        Oui([a, b, c])
+    }
+
+    /// Parses the OUI from the hyphen- or colon-separated hex notation or from the bare hex digits,
+    /// as in `AC-DE-48`, `ac:de:48` or `ACDE48`.
+    ///
+    /// Same as the `FromStr` implementation.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::{Oui, ParseError};
+    /// assert_eq!(Oui::parse("ac:de:48"), Ok(Oui::new(0xAC, 0xDE, 0x48)));
+    /// assert_eq!(Oui::parse("AC-DE-48-00"), Err(ParseError::InvalidLength(11)));
+    /// ```
+    pub fn parse(s: &str) -> Result<Oui, ParseError> {
+        // Cisco-style dotted notation is only defined for the whole addresses.
+        parser::Parser::with_options(s, ParseOptions::new().dot(false)).read_oui()
+    }
+
+    /// Returns `true` if the address is assigned from this OUI,
+    /// i.e. its first three bytes are equal to the OUI.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::{MacAddr6, MacAddr8, Oui};
+    /// let oui = Oui::new(0xAC, 0xDE, 0x48);
+    ///
+    /// assert!(oui.contains(&MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22)));
+    /// assert!(oui.contains(&MacAddr8::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44)));
+    /// assert!(!oui.contains(&MacAddr6::nil()));
+    /// ```
+    pub fn contains<A: AsRef<[u8]>>(&self, addr: &A) -> bool {
+        addr.as_ref().starts_with(&self.0)
+    }
+
+    /// Returns a reference to the raw bytes array of the `Oui`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::Oui;
+    /// let oui = Oui::new(0xAC, 0xDE, 0x48);
+    ///
+    /// assert_eq!(oui.as_bytes(), &[0xAC, 0xDE, 0x48]);
+    /// ```
+    pub const fn as_bytes(&self) -> &[u8; 3] {
+        &self.0
+    }
+
+    /// Consumes `Oui` and returns raw bytes array.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::Oui;
+    /// let oui = Oui::new(0xAC, 0xDE, 0x48);
+    ///
+    /// assert_eq!(oui.into_array(), [0xAC, 0xDE, 0x48]);
+    /// ```
+    pub const fn into_array(self) -> [u8; 3] {
+        self.0
+    }
+
+    /// Returns the name of the organization the OUI is assigned to in the embedded IEEE registry snapshot.
+    ///
+    /// Same as the [`vendor::lookup`] function.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::Oui;
+    /// assert_eq!(Oui::new(0x00, 0x00, 0x0C).vendor(), Some("Cisco Systems, Inc"));
+    /// assert_eq!(Oui::new(0xFF, 0xFF, 0xFF).vendor(), None);
+    /// ```
+    ///
+    /// [`vendor::lookup`]: vendor/fn.lookup.html
+    #[cfg(feature = "oui-db")]
+    pub fn vendor(&self) -> Option<&'static str> {
+        crate::vendor::lookup(self)
+    }
+}
+
+impl FromStr for Oui {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Oui::parse(s)
+    }
+}
+
+impl<'a> TryFrom<&'a str> for Oui {
+    type Error = ParseError;
+
+    fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+        Oui::parse(s)
+    }
+}
+
+impl From<[u8; 3]> for Oui {
+    fn from(bytes: [u8; 3]) -> Self {
+        Oui(bytes)
+    }
+}
+
+impl From<Oui> for [u8; 3] {
+    fn from(oui: Oui) -> Self {
+        oui.0
+    }
+}
+
+impl From<MacAddr6> for Oui {
+    fn from(addr: MacAddr6) -> Self {
+        Oui(addr.oui())
+    }
+}
+
+impl From<MacAddr8> for Oui {
+    fn from(addr: MacAddr8) -> Self {
+        Oui(addr.oui())
+    }
+}
+
+impl AsRef<[u8]> for Oui {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+/// `Oui` is debug-formatted in the IEEE registry notation, as in `Oui(AC-DE-48)`.
+impl fmt::Debug for Oui {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Oui({})", self)
+    }
+}
+
+/// `Oui` is displayed in the IEEE registry notation with hyphens, as in `AC-DE-48`,
+/// or with colons if the `+` flag is set.
+///
+/// Hex digits are uppercase by default or lowercase if the alternate `#` flag is set.
+/// Width, fill and alignment flags are respected.
+///
+/// # Example
+///
+/// ```
+/// # use macaddr::Oui;
+/// let oui = Oui::new(0xAC, 0xDE, 0x48);
+///
+/// assert_eq!(&format!("{}", oui), "AC-DE-48");
+/// assert_eq!(&format!("{:+}", oui), "AC:DE:48");
+/// assert_eq!(&format!("{:#}", oui), "ac-de-48");
+/// assert_eq!(&format!("{:>10}", oui), "  AC-DE-48");
+/// ```
+impl fmt::Display for Oui {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let separator = if f.sign_plus() { ':' } else { '-' };
+
+        display::write_grouped(f, &self.0, Some(separator), 1, !f.alternate())
+    }
+}
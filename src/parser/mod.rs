@@ -1,27 +1,28 @@
-#[cfg(feature = "std")]
-use std::{error::Error, fmt};
+use core::{error::Error, fmt};
 
-#[cfg(not(feature = "std"))]
-use core::fmt;
+use crate::{MacAddr, MacAddr6, MacAddr8, Oui};
 
-use crate::{MacAddr, MacAddr6, MacAddr8};
+mod options;
+
+pub use self::options::{LetterCase, ParseOptions};
 
 /// An error which can be returned when parsing MAC address.
 ///
-/// This error is used as the error type for the `FromStr` implementation
+/// This error is used as the error type for the `FromStr` and `TryFrom` implementations
 /// for [MacAddr6] and [MacAddr8].
 ///
 /// [MacAddr6]: ./struct.MacAddr6.html
 /// [MacAddr8]: ./struct.MacAddr8.html
 #[derive(Debug, Hash, Eq, PartialEq, Ord, PartialOrd, Copy, Clone)]
+#[non_exhaustive]
 pub enum ParseError {
-    /// Provided string can't be parsed into the given type,
+    /// Provided string or byte slice can't be parsed into the given type,
     /// because it is either too short or too long.
     ///
     /// For example, any trailing symbols will result in the error,
     /// as in `"12-34-56-78-9A-BC\n"`.
     ///
-    /// This enum member will contain the provided string length when returned.
+    /// This enum member will contain the provided string or slice length when returned.
     InvalidLength(usize),
 
     /// Invalid character occurred in the provided string.
@@ -30,6 +31,85 @@ pub enum ParseError {
     ///
     /// This enum member will contain the wrong char and it's position when returned.
     InvalidCharacter(char, usize),
+
+    /// Delimiter occurred in the unexpected position of the provided string
+    /// or it is different from the first delimiter used,
+    /// as in `"12-34:56-78-9A-BC"`.
+    ///
+    /// This enum member will contain the wrong delimiter and it's position when returned.
+    InvalidDelimiter(char, usize),
+
+    /// Prefix length of the [MacPrefix] is either missing or out of range,
+    /// as in `"AC:DE:48:00:00:00"` or `"AC:DE:48:00:00:00/49"`.
+    ///
+    /// This enum member will contain the position right after the slash when returned,
+    /// or the provided string length if there is no slash.
+    ///
+    /// [MacPrefix]: ./struct.MacPrefix.html
+    InvalidPrefixLength(usize),
+}
+
+impl ParseError {
+    /// Returns the kind of this error.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::{MacAddr6, ParseErrorKind};
+    /// let err = "12-34:56-78-9A-BC".parse::<MacAddr6>().unwrap_err();
+    ///
+    /// assert_eq!(err.kind(), ParseErrorKind::InvalidDelimiter);
+    /// ```
+    pub fn kind(&self) -> ParseErrorKind {
+        match self {
+            ParseError::InvalidLength(..) => ParseErrorKind::InvalidLength,
+            ParseError::InvalidCharacter(..) => ParseErrorKind::InvalidCharacter,
+            ParseError::InvalidDelimiter(..) => ParseErrorKind::InvalidDelimiter,
+            ParseError::InvalidPrefixLength(..) => ParseErrorKind::InvalidPrefixLength,
+        }
+    }
+
+    /// Returns the byte offset in the provided string where the error occurred.
+    ///
+    /// For the [InvalidLength] errors it is the length of the provided string.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// let err = "12-34:56-78-9A-BC".parse::<MacAddr6>().unwrap_err();
+    ///
+    /// assert_eq!(err.position(), 5);
+    /// ```
+    ///
+    /// [InvalidLength]: #variant.InvalidLength
+    pub fn position(&self) -> usize {
+        match self {
+            ParseError::InvalidLength(pos)
+            | ParseError::InvalidCharacter(_, pos)
+            | ParseError::InvalidDelimiter(_, pos)
+            | ParseError::InvalidPrefixLength(pos) => *pos,
+        }
+    }
+}
+
+/// A list specifying the kinds of [ParseError].
+///
+/// It is returned by the [ParseError::kind] method.
+///
+/// [ParseError]: ./enum.ParseError.html
+/// [ParseError::kind]: ./enum.ParseError.html#method.kind
+#[derive(Debug, Hash, Eq, PartialEq, Ord, PartialOrd, Copy, Clone)]
+#[non_exhaustive]
+pub enum ParseErrorKind {
+    /// Provided string is either too short or too long.
+    InvalidLength,
+    /// Invalid character occurred in the provided string.
+    InvalidCharacter,
+    /// Unexpected or inconsistent delimiter occurred in the provided string.
+    InvalidDelimiter,
+    /// Prefix length is either missing or out of range.
+    InvalidPrefixLength,
 }
 
 impl fmt::Display for ParseError {
@@ -39,48 +119,289 @@ impl fmt::Display for ParseError {
             ParseError::InvalidCharacter(chr, pos) => {
                 f.write_fmt(format_args!("Unexpected character '{}' at position {}", chr, pos,))
             }
+            ParseError::InvalidDelimiter(chr, pos) => {
+                f.write_fmt(format_args!("Unexpected delimiter '{}' at position {}", chr, pos,))
+            }
+            ParseError::InvalidPrefixLength(pos) => {
+                f.write_fmt(format_args!("Invalid prefix length at position {}", pos))
+            }
         }
     }
 }
 
-#[cfg(feature = "std")]
 impl Error for ParseError {}
 
-#[derive(Debug, Eq, PartialEq)]
+const fn invalid_char(chr: char, pos: usize) -> ParseError {
+    match chr {
+        '-' | ':' | '.' => ParseError::InvalidDelimiter(chr, pos),
+        _ => ParseError::InvalidCharacter(chr, pos),
+    }
+}
+
+/// Unwraps the parsing result, panicking with a message describing the error kind.
+///
+/// Panic messages can't be formatted in the `const fn`s,
+/// so the error position is not included.
+pub const fn unwrap_const<T: Copy>(result: Result<T, ParseError>) -> T {
+    match result {
+        Ok(value) => value,
+        Err(ParseError::InvalidLength(..)) => panic!("invalid MAC address length"),
+        Err(ParseError::InvalidCharacter(..)) => panic!("invalid character in MAC address"),
+        Err(ParseError::InvalidDelimiter(..)) => panic!("invalid delimiter in MAC address"),
+        Err(ParseError::InvalidPrefixLength(..)) => panic!("invalid MAC address prefix length"),
+    }
+}
+
+// The `?` operator can't be used in the `const fn`s yet.
+macro_rules! tri {
+    ($e:expr) => {
+        match $e {
+            Ok(value) => value,
+            Err(err) => return Err(err),
+        }
+    };
+}
+
+/// Marks the bytes which are not a hex digit in the `HEX_DIGITS` table.
+const NOT_DIGIT: u8 = 0x80;
+/// Marks the lower case letters in the `HEX_DIGITS` table.
+const LOWER_CASE: u8 = 0x40;
+/// Marks the upper case letters in the `HEX_DIGITS` table.
+const UPPER_CASE: u8 = 0x20;
+
+/// Hex digit value for each byte in the lower nibble,
+/// along with the marker bits in the upper one.
+static HEX_DIGITS: [u8; 256] = {
+    let mut table = [NOT_DIGIT; 256];
+    let mut i = 0;
+
+    while i < 10 {
+        table[b'0' as usize + i] = i as u8;
+        i += 1;
+    }
+
+    i = 0;
+    while i < 6 {
+        table[b'a' as usize + i] = (10 + i as u8) | LOWER_CASE;
+        table[b'A' as usize + i] = (10 + i as u8) | UPPER_CASE;
+        i += 1;
+    }
+
+    table
+};
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+#[repr(u8)]
 enum Delimiter {
-    Hyphen,
-    Colon,
-    Dot,
+    Hyphen = b'-',
+    Colon = b':',
+    Dot = b'.',
+}
+
+/// ARP hardware type of the Ethernet, used in the DHCP client identifiers.
+const HARDWARE_TYPE_ETHERNET: u8 = 0x01;
+
+/// Unescapes the string written by the ISC DHCP server into the `dhcpd.leases` file, as in `"\001\254\336H"`,
+/// into the buffer, returning the written bytes.
+///
+/// Surrounding quotes are optional; unprintable bytes are written as the three octal digits after the backslash
+/// and the rest of the escaped characters are taken literally, as in `\"` or `\\`.
+pub fn unescape_isc_string<'b>(s: &str, buf: &'b mut [u8]) -> Result<&'b [u8], ParseError> {
+    let source = s.as_bytes();
+    let (start, end) = match source {
+        [b'"', .., b'"'] => (1, source.len() - 1),
+        [b'"', ..] => return Err(ParseError::InvalidLength(s.len())),
+        _ => (0, source.len()),
+    };
+
+    let mut pos = start;
+    let mut len = 0;
+    while pos < end {
+        let byte = match source[pos] {
+            b'\\' if pos + 1 == end => return Err(ParseError::InvalidLength(s.len())),
+            b'\\' if source[pos + 1].is_ascii_digit() => {
+                let digits = source.get(pos + 1..pos + 4).filter(|_| pos + 4 <= end);
+                let digits = digits.ok_or(ParseError::InvalidLength(s.len()))?;
+
+                let mut value = 0u16;
+                for (idx, digit) in digits.iter().enumerate() {
+                    if !(b'0'..=b'7').contains(digit) {
+                        return Err(ParseError::InvalidCharacter(*digit as char, pos + 1 + idx));
+                    }
+                    value = value * 8 + u16::from(digit - b'0');
+                }
+                if value > 0xFF {
+                    return Err(ParseError::InvalidCharacter(digits[0] as char, pos + 1));
+                }
+
+                pos += 3;
+                value as u8
+            }
+            b'\\' if !source[pos + 1].is_ascii() => {
+                let chr = s[pos + 1..].chars().next().unwrap_or(char::REPLACEMENT_CHARACTER);
+                return Err(ParseError::InvalidCharacter(chr, pos + 1));
+            }
+            b'\\' => {
+                pos += 1;
+                source[pos]
+            }
+            b'"' => return Err(ParseError::InvalidCharacter('"', pos)),
+            byte if !byte.is_ascii() => {
+                let chr = s[pos..].chars().next().unwrap_or(char::REPLACEMENT_CHARACTER);
+                return Err(ParseError::InvalidCharacter(chr, pos));
+            }
+            byte => byte,
+        };
+
+        *buf.get_mut(len).ok_or(ParseError::InvalidLength(s.len()))? = byte;
+        len += 1;
+        pos += 1;
+    }
+
+    Ok(&buf[..len])
+}
+
+/// Parses the `MacAddr6` address in the notations of the SNMP tools: the `MacAddress` textual convention
+/// with its `1x:` display hint, as in `0:1a:2b:3c:4d:5e`, where the leading zeros of the bytes are omitted,
+/// or the raw `OCTET STRING` value with the space-separated bytes, as in `00 1A 2B 3C 4D 5E`,
+/// optionally followed by a single space as printed by the Net-SNMP tools.
+pub fn parse_snmp(s: &str) -> Result<MacAddr6, ParseError> {
+    let source = s.as_bytes();
+    let end = match source {
+        [.., b' '] => source.len() - 1,
+        _ => source.len(),
+    };
+
+    let mut bytes = [0u8; 6];
+    let (mut idx, mut digits, mut delimiter) = (0, 0, None);
+    for (pos, chr) in source[..end].iter().enumerate() {
+        match (*chr as char).to_digit(16) {
+            Some(_) if digits == 2 => return Err(ParseError::InvalidCharacter(*chr as char, pos)),
+            Some(value) => {
+                bytes[idx] = bytes[idx] << 4 | value as u8;
+                digits += 1;
+            }
+            None if *chr == b':' || *chr == b' ' => {
+                // Raw octets are always written with both digits.
+                let short = *chr == b' ' && digits < 2;
+                if digits == 0 || short || delimiter.is_some_and(|delimiter| delimiter != *chr) {
+                    return Err(ParseError::InvalidDelimiter(*chr as char, pos));
+                }
+                if idx == bytes.len() - 1 {
+                    return Err(ParseError::InvalidLength(s.len()));
+                }
+
+                delimiter = Some(*chr);
+                idx += 1;
+                digits = 0;
+            }
+            None => {
+                let chr = s[pos..].chars().next().unwrap_or(char::REPLACEMENT_CHARACTER);
+                return Err(invalid_char(chr, pos));
+            }
+        }
+    }
+
+    let short = delimiter == Some(b' ') && digits < 2;
+    if idx != bytes.len() - 1 || digits == 0 || short {
+        return Err(ParseError::InvalidLength(s.len()));
+    }
+
+    Ok(MacAddr6::from(bytes))
 }
 
 // Heavily based on the Rust' `std/net/parser.rs` sources.
+//
+// All the methods are `const fn`, so the very same parser
+// is used by the `FromStr` implementations and the compile-time parsing.
 #[derive(Debug)]
 pub struct Parser<'a> {
     source: &'a [u8],
     pos: usize,
     delimiter: Option<Delimiter>,
+    options: ParseOptions,
+    /// Marker bits of the `HEX_DIGITS` table entries which are not accepted.
+    rejected: u8,
+    lenient: bool,
 }
 
 impl<'a> Parser<'a> {
-    pub fn new(s: &'a str) -> Parser<'a> {
+    pub const fn new(s: &'a str) -> Parser<'a> {
+        Parser::with_options(s, ParseOptions::new())
+    }
+
+    /// Creates a parser accepting only the notations enabled in `options`.
+    pub const fn with_options(s: &'a str, options: ParseOptions) -> Parser<'a> {
+        Parser::from_ascii(s.as_bytes(), options)
+    }
+
+    /// Creates a parser for the ASCII text, which is not required to be a valid UTF-8.
+    ///
+    /// Non-ASCII bytes are reported as the invalid Latin-1 characters.
+    pub const fn from_ascii(source: &'a [u8], options: ParseOptions) -> Parser<'a> {
+        let rejected = match options.case {
+            LetterCase::Any => NOT_DIGIT,
+            LetterCase::Upper => NOT_DIGIT | LOWER_CASE,
+            LetterCase::Lower => NOT_DIGIT | UPPER_CASE,
+        };
+
         Parser {
-            source: s.as_bytes(),
+            source,
             pos: 0,
             delimiter: None,
+            options,
+            rejected,
+            lenient: false,
+        }
+    }
+
+    /// Creates a parser starting at the `start` byte offset of the string.
+    pub const fn with_offset(s: &'a str, start: usize) -> Parser<'a> {
+        Parser {
+            source: s.as_bytes(),
+            pos: start,
+            delimiter: None,
+            options: ParseOptions::new(),
+            rejected: NOT_DIGIT,
+            lenient: false,
         }
     }
 
-    fn is_eof(&self) -> bool {
+    /// Returns the current byte offset in the source.
+    pub const fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// Creates a parser which ignores the surrounding whitespace
+    /// and the trailing `%` interface annotation,
+    /// accepts any delimiter (or none) between each byte
+    /// and single-digit bytes followed by a delimiter.
+    pub fn lenient(s: &'a str) -> Parser<'a> {
+        let end = s.find('%').unwrap_or(s.len());
+        let end = s[..end].trim_end().len();
+        let start = end - s[..end].trim_start().len();
+
+        Parser {
+            source: &s.as_bytes()[..end],
+            pos: start,
+            delimiter: None,
+            options: ParseOptions::new(),
+            rejected: NOT_DIGIT,
+            lenient: true,
+        }
+    }
+
+    const fn is_eof(&self) -> bool {
         self.pos == self.source.len()
     }
 
-    fn move_next(&mut self) {
+    const fn move_next(&mut self) {
         if !self.is_eof() {
             self.pos += 1;
         }
     }
 
-    fn peek_char(&mut self) -> Option<char> {
+    const fn peek_char(&self) -> Option<char> {
         if self.is_eof() {
             None
         } else {
@@ -88,112 +409,310 @@ impl<'a> Parser<'a> {
         }
     }
 
-    fn read_char(&mut self) -> Result<char, ParseError> {
+    const fn read_digit(&mut self) -> Result<u8, ParseError> {
         if self.is_eof() {
-            Err(ParseError::InvalidLength(self.pos))
-        } else {
-            let r = self.source[self.pos] as char;
-            self.pos += 1;
-            Ok(r)
+            return Err(ParseError::InvalidLength(self.pos));
         }
+
+        let byte = self.source[self.pos];
+        let digit = HEX_DIGITS[byte as usize];
+        if digit & self.rejected != 0 {
+            return Err(invalid_char(byte as char, self.pos));
+        }
+
+        self.pos += 1;
+        Ok(digit & 0x0F)
     }
 
-    fn read_digit(&mut self) -> Result<u8, ParseError> {
-        let chr = self.read_char()?;
+    const fn read_byte(&mut self) -> Result<u8, ParseError> {
+        // Fast path for the two valid digits, errors are reported by the `read_digit` calls below.
+        if self.pos + 1 < self.source.len() {
+            let high = HEX_DIGITS[self.source[self.pos] as usize];
+            let low = HEX_DIGITS[self.source[self.pos + 1] as usize];
 
-        match chr as u8 {
-            byte @ b'0'..=b'9' => Ok(byte - b'0'),
-            byte @ b'a'..=b'f' => Ok(byte - b'a' + 10),
-            byte @ b'A'..=b'F' => Ok(byte - b'A' + 10),
-            _ => Err(ParseError::InvalidCharacter(chr, self.pos)),
+            if (high | low) & self.rejected == 0 {
+                self.pos += 2;
+                return Ok((high & 0x0F) << 4 | (low & 0x0F));
+            }
+        }
+
+        let high = tri!(self.read_digit());
+
+        // Lenient parser accepts the groups without zero padding, as in `0:a:95:9d:68:16`,
+        // but neither in the bare hex form nor in the dotted 16-bit groups,
+        // where the odd digit means the address is truncated.
+        let next = if self.is_eof() { self.delimiter } else { self.probe_delimiter() };
+        if self.lenient && matches!(next, Some(Delimiter::Hyphen) | Some(Delimiter::Colon)) {
+            return Ok(high);
+        }
+        // Delimiters are not misplaced for the lenient parser, it is the low digit which is missing.
+        if self.lenient && !self.is_eof() && next.is_some() {
+            return Err(ParseError::InvalidCharacter(self.source[self.pos] as char, self.pos));
         }
+
+        let low = tri!(self.read_digit());
+
+        Ok(high * 16 + low)
     }
 
-    fn probe_delimiter(&mut self) -> Result<Option<()>, ParseError> {
+    const fn probe_delimiter(&self) -> Option<Delimiter> {
+        if self.is_eof() {
+            return None;
+        }
+
+        match self.source[self.pos] {
+            b'-' => Some(Delimiter::Hyphen),
+            b':' => Some(Delimiter::Colon),
+            b'.' => Some(Delimiter::Dot),
+            _ => None,
+        }
+    }
+
+    /// Returns the error for the unexpected character at the current position.
+    const fn unexpected(&self) -> ParseError {
         match self.peek_char() {
-            Some('-') if self.delimiter.is_none() => {
-                self.delimiter = Some(Delimiter::Hyphen);
-                Ok(Some(()))
+            Some(chr) => invalid_char(chr, self.pos),
+            None => ParseError::InvalidLength(self.pos),
+        }
+    }
+
+    const fn allows(&self, delimiter: Delimiter) -> bool {
+        match delimiter {
+            Delimiter::Hyphen => self.options.hyphen,
+            Delimiter::Colon => self.options.colon,
+            Delimiter::Dot => self.options.dot,
+        }
+    }
+
+    /// Reads the delimiter expected before the `idx`-th byte, if there is one.
+    ///
+    /// Hyphens and colons separate each byte, while dots separate
+    /// the 16-bit groups, as in the Cisco `0123.4567.89AB` notation.
+    /// The delimiter kind is detected from the first one seen;
+    /// if there is none after the first group, the bare hex form is expected.
+    const fn read_delimiter(&mut self, idx: usize) -> Result<(), ParseError> {
+        if self.lenient {
+            // Only the last delimiter seen is remembered, as they could be mixed.
+            if let Some(delimiter) = self.probe_delimiter() {
+                self.delimiter = Some(delimiter);
+                self.move_next();
             }
-            Some('-') if self.delimiter != Some(Delimiter::Hyphen) => Err(ParseError::InvalidCharacter('-', self.pos)),
-            Some('-') => Ok(Some(())),
 
-            Some(':') if self.delimiter.is_none() => {
-                self.delimiter = Some(Delimiter::Colon);
-                Ok(Some(()))
+            return Ok(());
+        }
+
+        let delimiter = match self.delimiter {
+            Some(delimiter) => delimiter,
+            None => {
+                match self.probe_delimiter() {
+                    Some(Delimiter::Dot) if idx != 2 => {}
+                    Some(Delimiter::Hyphen) | Some(Delimiter::Colon) if idx != 1 => {}
+                    Some(delimiter) if !self.allows(delimiter) => return Err(self.unexpected()),
+                    Some(delimiter) => {
+                        self.delimiter = Some(delimiter);
+                        self.move_next();
+                    }
+                    None if idx == 2 && !self.options.bare => return Err(self.unexpected()),
+                    None => {}
+                }
+
+                return Ok(());
             }
-            Some(':') if self.delimiter != Some(Delimiter::Colon) => Err(ParseError::InvalidCharacter(':', self.pos)),
-            Some(':') => Ok(Some(())),
+        };
 
-            Some('.') if self.delimiter.is_none() => {
-                self.delimiter = Some(Delimiter::Dot);
-                Ok(Some(()))
+        let expected = match delimiter {
+            Delimiter::Dot => idx & 1 == 0,
+            _ => true,
+        };
+
+        if expected {
+            if self.is_eof() || self.source[self.pos] != delimiter as u8 {
+                return Err(self.unexpected());
             }
-            Some('.') if self.delimiter != Some(Delimiter::Dot) => Err(ParseError::InvalidCharacter('.', self.pos)),
-            Some('.') => Ok(Some(())),
-            _ => Ok(None),
+            self.pos += 1;
         }
+
+        Ok(())
     }
 
-    pub fn read_v6_addr(&mut self) -> Result<MacAddr6, ParseError> {
-        let mut bytes = [0; 6];
-        let mut i = 0;
+    /// Reads the bytes from the `from` up to the `to` index.
+    const fn read_bytes(&mut self, bytes: &mut [u8], from: usize, to: usize) -> Result<(), ParseError> {
+        let mut idx = from;
 
-        while i < 6 {
-            if self.probe_delimiter()?.is_some() {
-                self.move_next();
+        while idx < to {
+            if idx > 0 {
+                tri!(self.read_delimiter(idx));
             }
 
-            let mut digit = self.read_digit()? * 16;
-            digit += self.read_digit()?;
+            bytes[idx] = tri!(self.read_byte());
+            idx += 1;
+        }
 
-            bytes[i] = digit;
+        Ok(())
+    }
 
-            i += 1;
+    /// Reads the whole rest of the source as `count` bytes, if it is written in one of the strict notations.
+    ///
+    /// Notation is guessed from the source length and all the delimiters and digits
+    /// are validated at once, without branching on each one.
+    /// Returns `false` without consuming anything otherwise,
+    /// so the slow path could report the exact error.
+    const fn read_exact(&mut self, bytes: &mut [u8], count: usize) -> bool {
+        let source = self.source;
+        let len = source.len() - self.pos;
+
+        let (group, delimiter, allowed) = if len == 3 * count - 1 {
+            let delimiter = source[self.pos + 2];
+            let allowed = match delimiter {
+                b'-' => self.options.hyphen,
+                b':' => self.options.colon,
+                _ => false,
+            };
+            (1, delimiter, allowed)
+        } else if len == 5 * count / 2 - 1 {
+            (2, b'.', self.options.dot)
+        } else if len == 2 * count {
+            (count, 0, self.options.bare)
+        } else {
+            return false;
+        };
+
+        if !allowed || self.lenient {
+            return false;
+        }
+
+        let mut offset = self.pos;
+        let mut rejected = 0;
+        let mut mismatched = 0;
+        let mut idx = 0;
+
+        while idx < count {
+            if idx > 0 && idx % group == 0 {
+                mismatched |= source[offset] ^ delimiter;
+                offset += 1;
+            }
+
+            let high = HEX_DIGITS[source[offset] as usize];
+            let low = HEX_DIGITS[source[offset + 1] as usize];
+            rejected |= (high | low) & self.rejected;
+            bytes[idx] = (high & 0x0F) << 4 | (low & 0x0F);
+
+            offset += 2;
+            idx += 1;
+        }
+
+        if rejected | mismatched == 0 {
+            self.pos = offset;
+            true
+        } else {
+            false
         }
+    }
 
+    const fn read_eof(&self) -> Result<(), ParseError> {
         if self.is_eof() {
-            Ok(MacAddr6::from(bytes))
+            Ok(())
         } else {
             Err(ParseError::InvalidLength(self.source.len()))
         }
     }
 
-    pub fn read_v8_addr(&mut self) -> Result<MacAddr8, ParseError> {
+    /// Reads the `MacAddr6` address, leaving the rest of the source unread.
+    pub const fn read_v6_prefix(&mut self) -> Result<MacAddr6, ParseError> {
+        let mut bytes = [0; 6];
+        tri!(self.read_bytes(&mut bytes, 0, 6));
+
+        Ok(MacAddr6::new(bytes[0], bytes[1], bytes[2], bytes[3], bytes[4], bytes[5]))
+    }
+
+    /// Reads the `MacAddr8` address, leaving the rest of the source unread.
+    pub const fn read_v8_prefix(&mut self) -> Result<MacAddr8, ParseError> {
         let mut bytes = [0; 8];
-        let mut i = 0;
+        tri!(self.read_bytes(&mut bytes, 0, 8));
 
-        while i < 8 {
-            if self.probe_delimiter()?.is_some() {
-                self.move_next();
-            }
+        Ok(MacAddr8::new(
+            bytes[0], bytes[1], bytes[2], bytes[3], bytes[4], bytes[5], bytes[6], bytes[7],
+        ))
+    }
 
-            let mut digit = self.read_digit()? * 16;
-            digit += self.read_digit()?;
+    /// Reads the whole rest of the source as the three bytes of the `Oui`.
+    pub const fn read_oui(&mut self) -> Result<Oui, ParseError> {
+        let mut bytes = [0; 3];
+        tri!(self.read_bytes(&mut bytes, 0, 3));
+        tri!(self.read_eof());
 
-            bytes[i] = digit;
+        Ok(Oui::new(bytes[0], bytes[1], bytes[2]))
+    }
 
-            i += 1;
+    pub const fn read_v6_addr(&mut self) -> Result<MacAddr6, ParseError> {
+        let mut bytes = [0; 6];
+        if !self.read_exact(&mut bytes, 6) {
+            tri!(self.read_bytes(&mut bytes, 0, 6));
+            tri!(self.read_eof());
         }
 
-        if self.is_eof() {
-            Ok(MacAddr8::from(bytes))
-        } else {
-            Err(ParseError::InvalidLength(self.source.len()))
+        Ok(MacAddr6::new(bytes[0], bytes[1], bytes[2], bytes[3], bytes[4], bytes[5]))
+    }
+
+    pub const fn read_v8_addr(&mut self) -> Result<MacAddr8, ParseError> {
+        let mut bytes = [0; 8];
+        if !self.read_exact(&mut bytes, 8) {
+            tri!(self.read_bytes(&mut bytes, 0, 8));
+            tri!(self.read_eof());
+        }
+
+        Ok(MacAddr8::new(
+            bytes[0], bytes[1], bytes[2], bytes[3], bytes[4], bytes[5], bytes[6], bytes[7],
+        ))
+    }
+
+    /// Reads the DHCP client identifier (RFC 2132, section 9.14), made of the hardware type octet
+    /// followed by the `MacAddr6` address, as in `01:ac:de:48:00:11:22`.
+    ///
+    /// Only the Ethernet hardware type (`01`) is accepted.
+    pub const fn read_v6_client_id(&mut self) -> Result<MacAddr6, ParseError> {
+        let start = self.pos;
+        let mut bytes = [0; 7];
+        bytes[0] = tri!(self.read_byte());
+        if bytes[0] != HARDWARE_TYPE_ETHERNET {
+            // Pointing to the first digit which differs from the `01` octet.
+            let pos = if self.source[start] == b'0' { start + 1 } else { start };
+            return Err(invalid_char(self.source[pos] as char, pos));
         }
+
+        tri!(self.read_bytes(&mut bytes, 1, 7));
+        tri!(self.read_eof());
+
+        Ok(MacAddr6::new(bytes[1], bytes[2], bytes[3], bytes[4], bytes[5], bytes[6]))
     }
 
-    pub fn read_addr(&mut self) -> Result<MacAddr, ParseError> {
-        match self.read_v6_addr() {
-            Ok(addr) => return Ok(addr.into()),
-            Err(err @ ParseError::InvalidCharacter(..)) => return Err(err),
-            Err(ParseError::InvalidLength(..)) => {}
+    pub const fn read_addr(&mut self) -> Result<MacAddr, ParseError> {
+        let mut bytes = [0; 8];
+        if self.read_exact(&mut bytes, 6) {
+            return Ok(MacAddr::V6(MacAddr6::new(
+                bytes[0], bytes[1], bytes[2], bytes[3], bytes[4], bytes[5],
+            )));
+        }
+        if self.read_exact(&mut bytes, 8) {
+            return Ok(MacAddr::V8(MacAddr8::new(
+                bytes[0], bytes[1], bytes[2], bytes[3], bytes[4], bytes[5], bytes[6], bytes[7],
+            )));
+        }
+
+        tri!(self.read_bytes(&mut bytes, 0, 6));
+        if self.is_eof() {
+            return Ok(MacAddr::V6(MacAddr6::new(
+                bytes[0], bytes[1], bytes[2], bytes[3], bytes[4], bytes[5],
+            )));
         }
 
-        // Rolling back to the start.
-        self.pos = 0;
+        // Source is not over yet, so continuing with the *EUI-64* address in a single pass.
+        tri!(self.read_bytes(&mut bytes, 6, 8));
+        tri!(self.read_eof());
 
-        self.read_v8_addr().map(Into::into)
+        Ok(MacAddr::V8(MacAddr8::new(
+            bytes[0], bytes[1], bytes[2], bytes[3], bytes[4], bytes[5], bytes[6], bytes[7],
+        )))
     }
 }
 
@@ -0,0 +1,86 @@
+/// Letter case allowed in the hex digits of the parsed MAC address.
+#[derive(Debug, Hash, Eq, PartialEq, Ord, PartialOrd, Copy, Clone)]
+pub enum LetterCase {
+    /// Both lower and upper case letters are allowed, as in `ab:CD:eF:12:34:56`.
+    Any,
+    /// Only upper case letters are allowed, as in `AB:CD:EF:12:34:56`.
+    Upper,
+    /// Only lower case letters are allowed, as in `ab:cd:ef:12:34:56`.
+    Lower,
+}
+
+/// Options restricting the notations accepted by the `parse_with` methods.
+///
+/// By default all the notations supported by the `FromStr` implementations are accepted.
+///
+/// ## Example
+///
+/// ```rust
+/// # use macaddr::{LetterCase, MacAddr6, ParseOptions};
+/// let canonical = ParseOptions::new()
+///     .colon(false)
+///     .dot(false)
+///     .bare(false)
+///     .case(LetterCase::Upper);
+///
+/// assert!(MacAddr6::parse_with("AC-DE-48-00-11-22", &canonical).is_ok());
+/// assert!(MacAddr6::parse_with("ac-de-48-00-11-22", &canonical).is_err());
+/// assert!(MacAddr6::parse_with("AC:DE:48:00:11:22", &canonical).is_err());
+/// ```
+#[derive(Debug, Hash, Eq, PartialEq, Copy, Clone)]
+pub struct ParseOptions {
+    pub(crate) hyphen: bool,
+    pub(crate) colon: bool,
+    pub(crate) dot: bool,
+    pub(crate) bare: bool,
+    pub(crate) case: LetterCase,
+}
+
+impl ParseOptions {
+    /// Creates new options accepting all the supported notations.
+    pub const fn new() -> ParseOptions {
+        ParseOptions {
+            hyphen: true,
+            colon: true,
+            dot: true,
+            bare: true,
+            case: LetterCase::Any,
+        }
+    }
+
+    /// Sets whether the hyphen-separated notation, as in `AC-DE-48-00-11-22`, is accepted.
+    pub const fn hyphen(mut self, allow: bool) -> ParseOptions {
+        self.hyphen = allow;
+        self
+    }
+
+    /// Sets whether the colon-separated notation, as in `AC:DE:48:00:11:22`, is accepted.
+    pub const fn colon(mut self, allow: bool) -> ParseOptions {
+        self.colon = allow;
+        self
+    }
+
+    /// Sets whether the Cisco-style dotted notation, as in `ACDE.4800.1122`, is accepted.
+    pub const fn dot(mut self, allow: bool) -> ParseOptions {
+        self.dot = allow;
+        self
+    }
+
+    /// Sets whether the bare hex notation without delimiters, as in `ACDE48001122`, is accepted.
+    pub const fn bare(mut self, allow: bool) -> ParseOptions {
+        self.bare = allow;
+        self
+    }
+
+    /// Sets the letter case allowed in the hex digits.
+    pub const fn case(mut self, case: LetterCase) -> ParseOptions {
+        self.case = case;
+        self
+    }
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        ParseOptions::new()
+    }
+}
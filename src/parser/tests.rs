@@ -1,12 +1,11 @@
-#[cfg(feature = "std")]
-use std::str::FromStr;
-
-#[cfg(not(feature = "std"))]
-use core::str::FromStr;
+use core::{convert::TryFrom, str::FromStr};
 
 use assert_matches::assert_matches;
 
-use crate::{MacAddr, MacAddr6, MacAddr8};
+use crate::{
+    parse_list, LetterCase, MacAddr, MacAddr6, MacAddr8, MacAddrMask, MacPrefix, Oui, ParseError, ParseErrorKind,
+    ParseOptions,
+};
 
 #[test]
 fn test_parse_v6_upper_case_canonical_format() {
@@ -58,6 +57,16 @@ fn test_parse_v6_cisco_format() {
     assert_eq!(&[0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC], addr.as_bytes());
 }
 
+#[test]
+fn test_parse_v6_bare_format() {
+    let addr = MacAddr6::from_str("123456789abc");
+
+    assert!(addr.is_ok());
+    let addr = addr.unwrap();
+
+    assert_eq!(&[0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC], addr.as_bytes());
+}
+
 #[test]
 fn test_parse_v8_canonical_format() {
     let addr = MacAddr8::from_str("12-34-56-78-9A-BC-DE-F0");
@@ -78,6 +87,26 @@ fn test_parse_v8_colon_format() {
     assert_eq!(&[0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC, 0xDE, 0xF0], addr.as_bytes());
 }
 
+#[test]
+fn test_parse_v8_cisco_format() {
+    let addr = MacAddr8::from_str("1234.5678.9abc.def0");
+
+    assert!(addr.is_ok());
+    let addr = addr.unwrap();
+
+    assert_eq!(&[0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC, 0xDE, 0xF0], addr.as_bytes());
+}
+
+#[test]
+fn test_parse_v8_bare_format() {
+    let addr = MacAddr8::from_str("123456789ABCDEF0");
+
+    assert!(addr.is_ok());
+    let addr = addr.unwrap();
+
+    assert_eq!(&[0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC, 0xDE, 0xF0], addr.as_bytes());
+}
+
 #[test]
 fn test_parse_canonical_format() {
     let addr = MacAddr::from_str("12-34-56-78-9A-BC-DE-F0");
@@ -98,6 +127,40 @@ fn test_parse_colon_format() {
     assert_eq!(&[0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC, 0xDE, 0xF0], addr.as_bytes());
 }
 
+#[test]
+fn test_parse_cisco_format() {
+    let addr = MacAddr::from_str("1234.5678.9ABC");
+
+    assert!(addr.is_ok());
+    let addr = addr.unwrap();
+    assert_matches!(addr, MacAddr::V6(..));
+    assert_eq!(&[0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC], addr.as_bytes());
+
+    let addr = MacAddr::from_str("1234.5678.9ABC.DEF0");
+
+    assert!(addr.is_ok());
+    let addr = addr.unwrap();
+    assert_matches!(addr, MacAddr::V8(..));
+    assert_eq!(&[0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC, 0xDE, 0xF0], addr.as_bytes());
+}
+
+#[test]
+fn test_parse_bare_format() {
+    let addr = MacAddr::from_str("123456789ABC");
+
+    assert!(addr.is_ok());
+    let addr = addr.unwrap();
+    assert_matches!(addr, MacAddr::V6(..));
+    assert_eq!(&[0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC], addr.as_bytes());
+
+    let addr = MacAddr::from_str("123456789ABCDEF0");
+
+    assert!(addr.is_ok());
+    let addr = addr.unwrap();
+    assert_matches!(addr, MacAddr::V8(..));
+    assert_eq!(&[0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC, 0xDE, 0xF0], addr.as_bytes());
+}
+
 #[test]
 fn test_parse_v6_empty() {
     let addr = MacAddr6::from_str("");
@@ -165,12 +228,862 @@ fn test_parse_v8_invalid_char() {
 fn test_parse_v6_different_delimiters() {
     let addr = MacAddr6::from_str("ab-cd:ef-12-34-56");
 
-    assert!(addr.is_err());
+    assert_eq!(addr, Err(ParseError::InvalidDelimiter(':', 5)));
 }
 
 #[test]
 fn test_parse_v8_different_delimiters() {
     let addr = MacAddr8::from_str("ab-cd-ef-12-34-56-78:9A");
 
-    assert!(addr.is_err());
+    assert_eq!(addr, Err(ParseError::InvalidDelimiter(':', 20)));
+}
+
+#[test]
+fn test_parse_invalid_length() {
+    assert_eq!(MacAddr::from_str("12:34:56:78:9A"), Err(ParseError::InvalidLength(14)));
+    assert_eq!(MacAddr::from_str("12:34:56:78:9A:BC:DE"), Err(ParseError::InvalidLength(20)));
+    assert_eq!(
+        MacAddr::from_str("12:34:56:78:9A:BC:DE:F0:12"),
+        Err(ParseError::InvalidLength(26))
+    );
+}
+
+#[test]
+fn test_parse_invalid_char() {
+    assert_eq!(
+        MacAddr::from_str("12:34:56:78:9A:BC:DE:FZ"),
+        Err(ParseError::InvalidCharacter('Z', 22))
+    );
+}
+
+#[test]
+fn test_parse_different_delimiters() {
+    let addr = MacAddr::from_str("ab-cd-ef-12-34-56-78:9A");
+
+    assert_eq!(addr, Err(ParseError::InvalidDelimiter(':', 20)));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_parse_v6_display_roundtrip() {
+    let addr = MacAddr6::new(0xAB, 0x0D, 0xEF, 0x12, 0x34, 0x56);
+
+    assert_eq!(MacAddr6::from_str(&format!("{}", addr)), Ok(addr));
+    assert_eq!(MacAddr6::from_str(&format!("{:-}", addr)), Ok(addr));
+    assert_eq!(MacAddr6::from_str(&format!("{:#}", addr)), Ok(addr));
+    assert_eq!(MacAddr6::from_str(&format!("{:-#}", addr)), Ok(addr));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_parse_v8_display_roundtrip() {
+    let addr = MacAddr8::new(0xAB, 0x0D, 0xEF, 0x12, 0x34, 0x56, 0x78, 0x9A);
+
+    assert_eq!(MacAddr8::from_str(&format!("{}", addr)), Ok(addr));
+    assert_eq!(MacAddr8::from_str(&format!("{:-}", addr)), Ok(addr));
+    assert_eq!(MacAddr8::from_str(&format!("{:#}", addr)), Ok(addr));
+    assert_eq!(MacAddr8::from_str(&format!("{:-#}", addr)), Ok(addr));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_parse_hex_display_roundtrip() {
+    let addr = MacAddr::from([0xAB, 0x0D, 0xEF, 0x12, 0x34, 0x56, 0x78, 0x9A]);
+
+    assert_eq!(MacAddr::from_str(&format!("{:x}", addr)), Ok(addr));
+    assert_eq!(MacAddr::from_str(&format!("{:X}", addr)), Ok(addr));
+    assert_eq!(
+        MacAddr::from_str(&format!("{:#x}", addr)),
+        Err(ParseError::InvalidCharacter('x', 1))
+    );
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_parse_display_adaptors_roundtrip() {
+    let addr6 = MacAddr6::new(0xAB, 0x0D, 0xEF, 0x12, 0x34, 0x56);
+    let addr8 = MacAddr8::new(0xAB, 0x0D, 0xEF, 0x12, 0x34, 0x56, 0x78, 0x9A);
+
+    for s in [
+        addr6.display_colon().to_string(),
+        addr6.display_hyphen().to_string(),
+        addr6.display_dotted().to_string(),
+        format!("{:#}", addr6.display_bare()),
+    ] {
+        assert_eq!(MacAddr6::from_str(&s), Ok(addr6));
+    }
+    for s in [
+        addr8.display_colon().to_string(),
+        format!("{:#}", addr8.display_hyphen()),
+        addr8.display_dotted().to_string(),
+        addr8.display_bare().to_string(),
+    ] {
+        assert_eq!(MacAddr8::from_str(&s), Ok(addr8));
+    }
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_debug_format() {
+    let addr = MacAddr::from([0xAB, 0x0D, 0xEF, 0x12, 0x34, 0x56]);
+
+    assert_eq!(format!("{:?}", addr), "V6(MacAddr6(AB-0D-EF-12-34-56))");
+    assert_eq!(format!("{:#?}", addr), "V6(\n    MacAddr6(\n        AB-0D-EF-12-34-56,\n    ),\n)");
+    assert_eq!(format!("{:>20?}", addr), "V6(MacAddr6(AB-0D-EF-12-34-56))");
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_display_padding() {
+    let addr = MacAddr::from([0xAB, 0x0D, 0xEF, 0x12, 0x34, 0x56]);
+
+    assert_eq!(format!("[{:<20}]", addr), "[AB:0D:EF:12:34:56   ]");
+    assert_eq!(format!("[{:^#21}]", addr), "[  ab:0d:ef:12:34:56  ]");
+    assert_eq!(format!("[{:>16x}]", addr), "[    ab0def123456]");
+    assert_eq!(format!("[{:>#16X}]", addr), "[  0xAB0DEF123456]");
+    assert_eq!(format!("[{:-<16}]", addr.display_dotted()), "[AB0D.EF12.3456--]");
+    assert_eq!(format!("[{:8}]", addr), "[AB:0D:EF:12:34:56]");
+
+    let addr = MacAddr6::new(0x00, 0x1A, 0x2B, 0x3C, 0x04, 0x5E);
+    assert_eq!(format!("[{:>18}]", addr.display_snmp()), "[   0:1a:2b:3c:4:5e]");
+    assert_eq!(format!("[{:*<18}]", addr.display_snmp()), "[0:1a:2b:3c:4:5e***]");
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_display_format() {
+    use crate::MacFormat;
+
+    let addr = MacAddr8::new(0xAB, 0x0D, 0xEF, 0x12, 0x34, 0x56, 0x78, 0x9A);
+
+    assert_eq!(addr.format(&MacFormat::default()).to_string(), addr.to_string());
+    assert_eq!(addr.format(&MacFormat::HYPHEN).to_string(), format!("{:-}", addr));
+    assert_eq!(addr.format(&MacFormat::DOTTED).to_string(), addr.display_dotted().to_string());
+    assert_eq!(addr.format(&MacFormat::BARE).to_string(), format!("{:X}", addr));
+
+    let format = MacFormat::new(Some('→'), false, 3);
+    assert_eq!(format!("{:>21}", addr.format(&format)), "   ab0def→123456→789a");
+    let format = MacFormat::new(Some(':'), false, 0);
+    assert_eq!(addr.format(&format).to_string(), "ab0def123456789a");
+    let format = MacFormat::new(Some('→'), true, 1);
+    assert_eq!(addr.format(&format).to_string(), "AB→0D→EF→12→34→56→78→9A");
+
+    for format in [MacFormat::COLON, MacFormat::HYPHEN, MacFormat::DOTTED, MacFormat::BARE] {
+        assert_eq!(MacAddr8::from_str(&addr.format(&format).to_string()), Ok(addr));
+    }
+}
+
+/// Fixed-size `fmt::Write` buffer, as used in the `no_std` builds instead of the `String`.
+struct Buffer {
+    bytes: [u8; 32],
+    len: usize,
+}
+
+impl Buffer {
+    fn new() -> Buffer {
+        Buffer { bytes: [0; 32], len: 0 }
+    }
+
+    fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.bytes[..self.len]).unwrap()
+    }
+}
+
+impl core::fmt::Write for Buffer {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        let end = self.len + s.len();
+        if end > self.bytes.len() {
+            return Err(core::fmt::Error);
+        }
+
+        self.bytes[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "ufmt")]
+impl ufmt::uWrite for Buffer {
+    type Error = core::fmt::Error;
+
+    fn write_str(&mut self, s: &str) -> Result<(), Self::Error> {
+        core::fmt::Write::write_str(self, s)
+    }
+}
+
+#[test]
+#[cfg(feature = "ufmt")]
+fn test_ufmt_roundtrip() {
+    let addr = MacAddr::from([0xAB, 0x0D, 0xEF, 0x12, 0x34, 0x56, 0x78, 0x9A]);
+
+    let mut buf = Buffer::new();
+    ufmt::uwrite!(buf, "{}", addr).unwrap();
+    assert_eq!(MacAddr::from_str(buf.as_str()), Ok(addr));
+
+    let mut buf = Buffer::new();
+    ufmt::uwrite!(buf, "{:?}", MacAddr6::new(0xAB, 0x0D, 0xEF, 0x12, 0x34, 0x56)).unwrap();
+    assert_eq!(buf.as_str(), "MacAddr6(AB-0D-EF-12-34-56)");
+}
+
+#[test]
+fn test_parse_write_str_roundtrip() {
+    let addr6 = MacAddr6::new(0xAB, 0x0D, 0xEF, 0x12, 0x34, 0x56);
+    let addr8 = MacAddr8::new(0xAB, 0x0D, 0xEF, 0x12, 0x34, 0x56, 0x78, 0x9A);
+
+    let mut buf = [0; MacAddr6::MAX_DISPLAY_LEN];
+    assert_eq!(MacAddr6::from_str(addr6.write_str(&mut buf).unwrap()), Ok(addr6));
+    let mut buf = [0; MacAddr::MAX_DISPLAY_LEN];
+    assert_eq!(MacAddr::from_str(MacAddr::V8(addr8).write_str(&mut buf).unwrap()), Ok(MacAddr::V8(addr8)));
+
+    let mut buf = [0; MacAddr8::MAX_DISPLAY_LEN + 1];
+    assert_eq!(addr8.write_str(&mut buf).map(str::len), Ok(MacAddr8::MAX_DISPLAY_LEN));
+    assert!(addr8.write_str(&mut buf[..MacAddr8::MAX_DISPLAY_LEN - 1]).is_err());
+}
+
+#[test]
+fn test_parse_display_roundtrip_without_alloc() {
+    use core::fmt::Write;
+
+    let addr6 = MacAddr6::new(0xAB, 0x0D, 0xEF, 0x12, 0x34, 0x56);
+    let addr8 = MacAddr8::new(0xAB, 0x0D, 0xEF, 0x12, 0x34, 0x56, 0x78, 0x9A);
+
+    let mut buf = Buffer::new();
+    write!(buf, "{}", addr6).unwrap();
+    assert_eq!(MacAddr6::from_str(buf.as_str()), Ok(addr6));
+
+    let mut buf = Buffer::new();
+    write!(buf, "{:-}", MacAddr::from(addr8)).unwrap();
+    assert_eq!(MacAddr::from_str(buf.as_str()), Ok(MacAddr::V8(addr8)));
+}
+
+#[test]
+fn test_parse_v6_misplaced_dots() {
+    assert_eq!(
+        MacAddr6::from_str("12.34.56.78.9A.BC"),
+        Err(ParseError::InvalidDelimiter('.', 2))
+    );
+    assert_eq!(
+        MacAddr6::from_str("1234.56789A.BC"),
+        Err(ParseError::InvalidCharacter('9', 9))
+    );
+    assert_eq!(
+        MacAddr6::from_str("123456.789ABC"),
+        Err(ParseError::InvalidDelimiter('.', 6))
+    );
+}
+
+#[test]
+fn test_parse_v8_misplaced_dots() {
+    assert_eq!(
+        MacAddr8::from_str("1234.5678.9ABCDE.F0"),
+        Err(ParseError::InvalidCharacter('D', 14))
+    );
+}
+
+#[test]
+fn test_parse_v6_leading_delimiter() {
+    assert_eq!(
+        MacAddr6::from_str(":12:34:56:78:9A:BC"),
+        Err(ParseError::InvalidDelimiter(':', 0))
+    );
+}
+
+#[test]
+fn test_parse_v8_leading_delimiter() {
+    assert_eq!(
+        MacAddr8::from_str("-12-34-56-78-9A-BC-DE-F0"),
+        Err(ParseError::InvalidDelimiter('-', 0))
+    );
+}
+
+#[test]
+fn test_parse_v8_trailing_delimiter() {
+    assert_eq!(
+        MacAddr8::from_str("1234.5678.9ABC.DEF0."),
+        Err(ParseError::InvalidLength(20))
+    );
+}
+
+#[test]
+fn test_parse_v6_missing_delimiter() {
+    assert_eq!(
+        MacAddr6::from_str("12:34:5678:9A:BC"),
+        Err(ParseError::InvalidCharacter('7', 8))
+    );
+}
+
+#[test]
+fn test_parse_v6_bare_with_delimiter() {
+    assert_eq!(
+        MacAddr6::from_str("123456-789ABC"),
+        Err(ParseError::InvalidDelimiter('-', 6))
+    );
+    assert_eq!(MacAddr6::from_str("123456789AB"), Err(ParseError::InvalidLength(11)));
+}
+
+#[test]
+fn test_parse_v6_lenient_mixed_delimiters() {
+    let addr = MacAddr6::parse_lenient("12:34-56:78-9A.BC");
+
+    assert_eq!(addr, Ok(MacAddr6::new(0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC)));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_parse_v6_lenient_dotted_octets() {
+    let addr = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22);
+
+    assert_eq!(MacAddr6::parse_lenient("ac.de.48.00.11.22"), Ok(addr));
+    assert_eq!(MacAddr6::parse_lenient(&addr.display_dotted_octets().to_string()), Ok(addr));
+    assert_eq!(
+        MacAddr6::parse_lenient("ac.de.48.00.11.22.33"),
+        Err(ParseError::InvalidLength(20))
+    );
+    assert_eq!(MacAddr6::from_str("ac.de.48.00.11.22"), Err(ParseError::InvalidDelimiter('.', 2)));
+}
+
+#[test]
+fn test_parse_v6_lenient_whitespace() {
+    let addr = MacAddr6::parse_lenient(" \t12-34-56-78-9A-BC\r\n");
+
+    assert_eq!(addr, Ok(MacAddr6::new(0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC)));
+}
+
+#[test]
+fn test_parse_v6_lenient_interface_annotation() {
+    let addr = MacAddr6::parse_lenient("   01-23-45-67-89-AB %12\r\n");
+
+    assert_eq!(addr, Ok(MacAddr6::new(0x01, 0x23, 0x45, 0x67, 0x89, 0xAB)));
+}
+
+#[test]
+fn test_parse_v6_strict_interface_annotation() {
+    let addr = MacAddr6::from_str("01-23-45-67-89-AB%12");
+
+    assert_eq!(addr, Err(ParseError::InvalidLength(20)));
+}
+
+#[test]
+fn test_parse_v6_lenient_single_digits() {
+    assert_eq!(
+        MacAddr6::parse_lenient("0:a:95:9d:68:16"),
+        Ok(MacAddr6::new(0x00, 0x0A, 0x95, 0x9D, 0x68, 0x16))
+    );
+    assert_eq!(
+        MacAddr6::parse_lenient("0:1:2:3:4:5"),
+        Ok(MacAddr6::new(0x00, 0x01, 0x02, 0x03, 0x04, 0x05))
+    );
+    assert_eq!(
+        MacAddr8::parse_lenient("0:1:2:3:4:5:6:7"),
+        Ok(MacAddr8::new(0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07))
+    );
+    assert_matches!(MacAddr::parse_lenient("0:1:2:3:4:5:6:7"), Ok(MacAddr::V8(..)));
+}
+
+#[test]
+fn test_parse_lenient_truncated_bare() {
+    assert_eq!(MacAddr6::parse_lenient("0123456789A"), Err(ParseError::InvalidLength(11)));
+    assert_eq!(MacAddr6::parse_lenient("0123456789A%eth0"), Err(ParseError::InvalidLength(11)));
+    assert_eq!(MacAddr8::parse_lenient("0123456789ABCDE"), Err(ParseError::InvalidLength(15)));
+    assert_eq!(MacAddr::parse_lenient("0123456789A"), Err(ParseError::InvalidLength(11)));
+    assert_eq!(MacAddr::parse_lenient("0123456789ABCDE"), Err(ParseError::InvalidLength(15)));
+    assert_eq!(
+        MacAddr6::parse_lenient("0123456789AB"),
+        Ok(MacAddr6::new(0x01, 0x23, 0x45, 0x67, 0x89, 0xAB))
+    );
+}
+
+#[test]
+fn test_parse_lenient_truncated_dotted() {
+    assert_eq!(MacAddr6::parse_lenient("ACDE.480.1122"), Err(ParseError::InvalidCharacter('.', 8)));
+    assert_eq!(MacAddr6::parse_lenient("ACDE.4800.112"), Err(ParseError::InvalidLength(13)));
+    assert_eq!(MacAddr8::parse_lenient("ACD.E48.001.122"), Err(ParseError::InvalidCharacter('.', 3)));
+    assert_eq!(MacAddr::parse_lenient("ACDE.4800.112"), Err(ParseError::InvalidLength(13)));
+    assert_eq!(MacAddr6::parse_lenient("ac.de.4.0.11.22"), Err(ParseError::InvalidCharacter('.', 7)));
+    assert_eq!(
+        MacAddr6::parse_lenient("ACDE.4800.1122"),
+        Ok(MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22))
+    );
+}
+
+#[test]
+fn test_parse_v6_strict_single_digits() {
+    assert_eq!(
+        MacAddr6::from_str("0:a:95:9d:68:16"),
+        Err(ParseError::InvalidDelimiter(':', 1))
+    );
+}
+
+#[test]
+fn test_parse_v6_lenient_invalid_char() {
+    let addr = MacAddr6::parse_lenient("  12-34-56 78-9A-BC");
+
+    assert_eq!(addr, Err(ParseError::InvalidCharacter(' ', 10)));
+}
+
+#[test]
+fn test_parse_v6_strict_whitespace() {
+    let addr = MacAddr6::from_str(" 12-34-56-78-9A-BC");
+
+    assert_eq!(addr, Err(ParseError::InvalidCharacter(' ', 0)));
+}
+
+#[test]
+fn test_parse_v8_lenient_mixed_delimiters() {
+    let addr = MacAddr8::parse_lenient(" 12:34-56:78-9A.BC-DE:F0 ");
+
+    assert_eq!(addr, Ok(MacAddr8::new(0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC, 0xDE, 0xF0)));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_parse_v8_lenient_dotted_octets() {
+    let addr = MacAddr8::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44);
+
+    assert_eq!(MacAddr8::parse_lenient("ac.de.48.00.11.22.33.44"), Ok(addr));
+    assert_eq!(MacAddr8::parse_lenient(&addr.display_dotted_octets().to_string()), Ok(addr));
+    assert_matches!(MacAddr::parse_lenient("ac.de.48.00.11.22.33.44"), Ok(MacAddr::V8(..)));
+}
+
+#[test]
+fn test_parse_v8_lenient_invalid_char() {
+    let addr = MacAddr8::parse_lenient(" 12-34-56-78-9A-BC-DE_F0");
+
+    assert_eq!(addr, Err(ParseError::InvalidCharacter('_', 21)));
+}
+
+#[test]
+fn test_parse_v8_strict_whitespace() {
+    let addr = MacAddr8::from_str("12-34-56-78-9A-BC-DE-F0 ");
+
+    assert_eq!(addr, Err(ParseError::InvalidLength(24)));
+}
+
+#[test]
+fn test_parse_lenient() {
+    let addr = MacAddr::parse_lenient(" 12:34-56:78-9A.BC-DE:F0 ");
+
+    assert_matches!(addr, Ok(MacAddr::V8(..)));
+
+    let addr = MacAddr::parse_lenient(" 12:34-56:78-9A.BC ");
+
+    assert_matches!(addr, Ok(MacAddr::V6(..)));
+}
+
+#[test]
+fn test_parse_error_kind_and_position() {
+    let err = MacAddr6::from_str("ab-Qd-ef-12-34-56").unwrap_err();
+    assert_eq!(err.kind(), ParseErrorKind::InvalidCharacter);
+    assert_eq!(err.position(), 3);
+
+    let err = MacAddr6::from_str("ab-cd-ef-12-34-56:").unwrap_err();
+    assert_eq!(err.kind(), ParseErrorKind::InvalidLength);
+    assert_eq!(err.position(), 18);
+
+    let err = MacAddr8::from_str("1234.5678:9ABC.DEF0").unwrap_err();
+    assert_eq!(err.kind(), ParseErrorKind::InvalidDelimiter);
+    assert_eq!(err.position(), 9);
+}
+
+#[test]
+fn test_parse_const() {
+    const ADDR6: MacAddr6 = MacAddr6::parse_const("12:34:56:78:9A:BC");
+    const ADDR8: MacAddr8 = MacAddr8::parse_const("1234.5678.9abc.def0");
+
+    assert_eq!(ADDR6, MacAddr6::new(0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC));
+    assert_eq!(ADDR8, MacAddr8::new(0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC, 0xDE, 0xF0));
+}
+
+#[test]
+fn test_try_parse_const() {
+    const ADDR6: Result<MacAddr6, ParseError> = MacAddr6::try_parse_const("12:34:56:78:9A:BC");
+    const ADDR8: Result<MacAddr8, ParseError> = MacAddr8::try_parse_const("1234.5678.9abc.deZ0");
+
+    assert_eq!(ADDR6, Ok(MacAddr6::new(0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC)));
+    assert_eq!(ADDR8, Err(ParseError::InvalidCharacter('Z', 17)));
+}
+
+#[test]
+#[should_panic(expected = "invalid character in MAC address")]
+fn test_parse_const_invalid_char() {
+    MacAddr6::parse_const("12:34:56:78:9A:BZ");
+}
+
+#[test]
+#[should_panic(expected = "invalid MAC address length")]
+fn test_parse_const_invalid_length() {
+    MacAddr8::parse_const("12:34:56:78:9A:BC");
+}
+
+#[test]
+fn test_try_from_str() {
+    assert_eq!(
+        MacAddr6::try_from("12:34:56:78:9A:BC"),
+        Ok(MacAddr6::new(0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC))
+    );
+    assert_eq!(
+        MacAddr8::try_from("12:34:56:78:9A:BC:DE:F0"),
+        Ok(MacAddr8::new(0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC, 0xDE, 0xF0))
+    );
+    assert_matches!(MacAddr::try_from("12:34:56:78:9A:BC:DE:F0"), Ok(MacAddr::V8(..)));
+    assert_eq!(MacAddr6::try_from("12:34:56"), Err(ParseError::InvalidLength(8)));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_try_from_string() {
+    assert_eq!(
+        MacAddr6::try_from(String::from("12:34:56:78:9A:BC")),
+        Ok(MacAddr6::new(0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC))
+    );
+    assert_matches!(MacAddr::try_from(String::from("1234.5678.9ABC")), Ok(MacAddr::V6(..)));
+}
+
+#[test]
+fn test_try_from_slice() {
+    let bytes = [0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC, 0xDE, 0xF0, 0x00];
+
+    assert_eq!(
+        MacAddr6::try_from(&bytes[..6]),
+        Ok(MacAddr6::new(0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC))
+    );
+    assert_eq!(
+        MacAddr8::try_from(&bytes[..8]),
+        Ok(MacAddr8::new(0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC, 0xDE, 0xF0))
+    );
+    assert_matches!(MacAddr::try_from(&bytes[..6]), Ok(MacAddr::V6(..)));
+    assert_matches!(MacAddr::try_from(&bytes[..8]), Ok(MacAddr::V8(..)));
+
+    assert_eq!(MacAddr6::try_from(&bytes[..]), Err(ParseError::InvalidLength(9)));
+    assert_eq!(MacAddr8::try_from(&bytes[..6]), Err(ParseError::InvalidLength(6)));
+    assert_eq!(MacAddr::try_from(&bytes[..7]), Err(ParseError::InvalidLength(7)));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_find_in_str() {
+    let text = "ac:de:48:00:11:22,[AC-DE-48-00-11-23] acde.4800.1124.";
+    let found: Vec<_> = MacAddr6::find_all_in_str(text).collect();
+
+    assert_eq!(
+        found,
+        vec![
+            (0..17, MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22)),
+            (19..36, MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x23)),
+            (38..52, MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x24)),
+        ]
+    );
+}
+
+#[test]
+fn test_find_in_str_boundaries() {
+    assert_eq!(MacAddr6::find_in_str("acde48001122"), None);
+    assert_eq!(MacAddr6::find_in_str("xac:de:48:00:11:22"), None);
+    assert_eq!(MacAddr6::find_in_str("ac:de:48:00:11:22x"), None);
+    assert_eq!(MacAddr6::find_in_str("ac:de:48:00:11:22:33:44"), None);
+    assert_eq!(MacAddr6::find_in_str("00:ac:de:48:00:11:22"), None);
+    assert_eq!(MacAddr8::find_in_str("ac:de:48:00:11:22"), None);
+    assert_eq!(MacAddr6::find_in_str("ac:de:48:00:11:2"), None);
+    assert_eq!(
+        MacAddr6::find_in_str("ac:de:48:00:11:223 ac:de:48:00:11:22"),
+        Some((19..36, MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22)))
+    );
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_find_in_str_mixed() {
+    let text = "ac:de:48:00:11:22:33:44 ac:de:48:00:11:22";
+    let found: Vec<_> = MacAddr::find_all_in_str(text).map(|(_, addr)| addr).collect();
+
+    assert_eq!(
+        found,
+        vec![
+            MacAddr::V8(MacAddr8::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44)),
+            MacAddr::V6(MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22)),
+        ]
+    );
+}
+
+#[test]
+fn test_parse_with_delimiters() {
+    let options = ParseOptions::new().hyphen(false).colon(false);
+
+    assert_eq!(
+        MacAddr6::parse_with("1234.5678.9ABC", &options),
+        Ok(MacAddr6::new(0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC))
+    );
+    assert_eq!(
+        MacAddr6::parse_with("12-34-56-78-9A-BC", &options),
+        Err(ParseError::InvalidDelimiter('-', 2))
+    );
+    assert_eq!(
+        MacAddr8::parse_with("12:34:56:78:9A:BC:DE:F0", &options),
+        Err(ParseError::InvalidDelimiter(':', 2))
+    );
+}
+
+#[test]
+fn test_parse_with_bare() {
+    let options = ParseOptions::new().bare(false);
+
+    assert_eq!(
+        MacAddr6::parse_with("123456789ABC", &options),
+        Err(ParseError::InvalidCharacter('5', 4))
+    );
+    assert_eq!(
+        MacAddr6::parse_with("1234", &options),
+        Err(ParseError::InvalidLength(4))
+    );
+    assert_eq!(
+        MacAddr::parse_with("123456789ABCDEF0", &options),
+        Err(ParseError::InvalidCharacter('5', 4))
+    );
+}
+
+#[test]
+fn test_parse_with_case() {
+    let upper = ParseOptions::new().case(LetterCase::Upper);
+    let lower = ParseOptions::new().case(LetterCase::Lower);
+
+    assert!(MacAddr6::parse_with("AB-CD-EF-12-34-56", &upper).is_ok());
+    assert_eq!(
+        MacAddr6::parse_with("AB-CD-eF-12-34-56", &upper),
+        Err(ParseError::InvalidCharacter('e', 6))
+    );
+    assert!(MacAddr6::parse_with("ab-cd-ef-12-34-56", &lower).is_ok());
+    assert_eq!(
+        MacAddr6::parse_with("ab-cd-ef-12-34-5F", &lower),
+        Err(ParseError::InvalidCharacter('F', 16))
+    );
+}
+
+#[test]
+fn test_parse_ascii() {
+    assert_eq!(
+        MacAddr6::parse_ascii(b"12:34:56:78:9a:bc"),
+        Ok(MacAddr6::new(0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC))
+    );
+    assert_eq!(
+        MacAddr8::parse_ascii(b"1234.5678.9abc.def0"),
+        Ok(MacAddr8::new(0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC, 0xDE, 0xF0))
+    );
+    assert_matches!(MacAddr::parse_ascii(b"123456789abc"), Ok(MacAddr::V6(..)));
+}
+
+#[test]
+fn test_parse_ascii_invalid_byte() {
+    assert_eq!(
+        MacAddr6::parse_ascii(b"12:34:56:78:9a:\xFF\xFE"),
+        Err(ParseError::InvalidCharacter('\u{FF}', 15))
+    );
+}
+
+#[test]
+fn test_parse_list() {
+    let list = " 12:34:56:78:9A:BC,12-34-56-78-9A-BC-DE-F0\r\n1234.5678.9ABC\t\n,,";
+    let mut addrs = parse_list(list);
+
+    assert_matches!(addrs.next(), Some(Ok(MacAddr::V6(..))));
+    assert_matches!(addrs.next(), Some(Ok(MacAddr::V8(..))));
+    assert_matches!(addrs.next(), Some(Ok(MacAddr::V6(..))));
+    assert_matches!(addrs.next(), None);
+}
+
+#[test]
+fn test_parse_list_invalid_item() {
+    let mut addrs = parse_list("12:34:56:78:9A:BC 12:34:56");
+
+    assert_matches!(addrs.next(), Some(Ok(MacAddr::V6(..))));
+    assert_eq!(addrs.next(), Some(Err(ParseError::InvalidLength(8))));
+    assert_eq!(addrs.next(), None);
+}
+
+#[test]
+fn test_parse_list_empty() {
+    assert_eq!(parse_list(" ,\n, ").next(), None);
+}
+
+#[test]
+fn test_parse_v6_bit_reversed() {
+    assert_eq!(
+        MacAddr6::parse_bit_reversed("80-00-00-00-00-01"),
+        Ok(MacAddr6::new(0x01, 0x00, 0x00, 0x00, 0x00, 0x80))
+    );
+    assert_eq!(
+        MacAddr6::parse_bit_reversed("80-00-00-00-00"),
+        Err(ParseError::InvalidLength(14))
+    );
+}
+
+#[test]
+fn test_parse_fast_path_fallback() {
+    assert_eq!(
+        MacAddr6::from_str("1234.5678-9ABC"),
+        Err(ParseError::InvalidDelimiter('-', 9))
+    );
+    assert_eq!(
+        MacAddr6::from_str("12.34.56.78.9A.B"),
+        Err(ParseError::InvalidDelimiter('.', 2))
+    );
+    assert_eq!(
+        MacAddr8::from_str("12-34-56-78-9A-BC-DE-F\u{0}"),
+        Err(ParseError::InvalidCharacter('\u{0}', 22))
+    );
+    assert_eq!(
+        MacAddr::from_str("123456789ABCDEFG"),
+        Err(ParseError::InvalidCharacter('G', 15))
+    );
+    assert_eq!(
+        MacAddr6::parse_lenient("12:34:56:78:9a:bc"),
+        MacAddr6::from_str("12-34-56-78-9A-BC")
+    );
+}
+
+#[test]
+fn test_parse_error_is_core_error() {
+    fn assert_error<E: core::error::Error>(_: &E) {}
+
+    let err = MacAddr6::from_str("").unwrap_err();
+    assert_error(&err);
+}
+
+#[test]
+fn test_parse_v6_dhcp_client_id() {
+    let addr = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22);
+
+    assert_eq!(MacAddr6::parse_dhcp_client_id("01:ac:de:48:00:11:22"), Ok(addr));
+    assert_eq!(MacAddr6::parse_dhcp_client_id("01-AC-DE-48-00-11-22"), Ok(addr));
+    assert_eq!(MacAddr6::parse_dhcp_client_id("01acde48001122"), Ok(addr));
+
+    assert_eq!(
+        MacAddr6::parse_dhcp_client_id("06:ac:de:48:00:11:22"),
+        Err(ParseError::InvalidCharacter('6', 1))
+    );
+    assert_eq!(
+        MacAddr6::parse_dhcp_client_id("11:ac:de:48:00:11:22"),
+        Err(ParseError::InvalidCharacter('1', 0))
+    );
+    assert_eq!(
+        MacAddr6::parse_dhcp_client_id("01:ac:de:48:00:11"),
+        Err(ParseError::InvalidLength(17))
+    );
+    assert_eq!(
+        MacAddr6::parse_dhcp_client_id("01:ac:de:48:00:11:22:33"),
+        Err(ParseError::InvalidLength(23))
+    );
+    assert_eq!(
+        MacAddr6::parse_dhcp_client_id("01:ac-de:48:00:11:22"),
+        Err(ParseError::InvalidDelimiter('-', 5))
+    );
+}
+
+#[test]
+fn test_parse_oui() {
+    let oui = Oui::new(0xAC, 0xDE, 0x48);
+
+    assert_eq!(Oui::from_str("AC-DE-48"), Ok(oui));
+    assert_eq!(Oui::from_str("ac:de:48"), Ok(oui));
+    assert_eq!(Oui::from_str("acde48"), Ok(oui));
+    assert_eq!(Oui::from_str("AC-DE:48"), Err(ParseError::InvalidDelimiter(':', 5)));
+    assert_eq!(Oui::from_str("ACDE.48"), Err(ParseError::InvalidDelimiter('.', 4)));
+    assert_eq!(Oui::from_str("AC-DE"), Err(ParseError::InvalidLength(5)));
+    assert_eq!(Oui::from_str("AC-DE-48-00"), Err(ParseError::InvalidLength(11)));
+    assert_eq!(Oui::from_str("AC-DE-4G"), Err(ParseError::InvalidCharacter('G', 7)));
+}
+
+#[test]
+fn test_parse_v6_isc_client_id() {
+    let addr = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22);
+
+    assert_eq!(MacAddr6::parse_isc_client_id(r#""\001\254\336H\000\021\"""#), Ok(addr));
+    assert_eq!(MacAddr6::parse_isc_client_id(r#"\001\254\336H\000\021\""#), Ok(addr));
+    assert_eq!(MacAddr6::parse_isc_client_id(r#""\001\254\336\110\000\021\042""#), Ok(addr));
+
+    let rfc4361 = r#""\377\000\000\000\001\000\003\000\001\254\336H\000\021\"""#;
+    assert_eq!(MacAddr6::parse_isc_client_id(rfc4361), Ok(addr));
+
+    assert_eq!(
+        MacAddr6::parse_isc_client_id(r#""\001\254\336H\000\021""#),
+        Err(ParseError::InvalidLength(23))
+    );
+    assert_eq!(
+        MacAddr6::parse_isc_client_id(r#""\001\254\338H\000\021\"""#),
+        Err(ParseError::InvalidCharacter('8', 12))
+    );
+    assert_eq!(
+        MacAddr6::parse_isc_client_id(r#""\001\254\436H\000\021\"""#),
+        Err(ParseError::InvalidCharacter('4', 10))
+    );
+    assert_eq!(
+        MacAddr6::parse_isc_client_id(r#""\001\254\336H"\000\021\"""#),
+        Err(ParseError::InvalidCharacter('"', 14))
+    );
+    assert_eq!(MacAddr6::parse_isc_client_id("\"\\é\""), Err(ParseError::InvalidCharacter('é', 2)));
+    assert_eq!(
+        MacAddr6::parse_isc_client_id(r#""\001\254\é\000\021\"""#),
+        Err(ParseError::InvalidCharacter('é', 10))
+    );
+    assert_eq!(MacAddr6::parse_isc_client_id(r#""\001\25"#), Err(ParseError::InvalidLength(8)));
+    assert_eq!(MacAddr6::parse_isc_client_id("\"\\001é\""), Err(ParseError::InvalidCharacter('é', 5)));
+}
+
+#[test]
+fn test_parse_v6_snmp() {
+    let addr = MacAddr6::new(0x00, 0x1A, 0x2B, 0x3C, 0x04, 0x5E);
+
+    assert_eq!(MacAddr6::parse_snmp("0:1a:2b:3c:4:5e"), Ok(addr));
+    assert_eq!(MacAddr6::parse_snmp("00:1A:2B:3C:04:5E"), Ok(addr));
+    assert_eq!(MacAddr6::parse_snmp("00 1A 2B 3C 04 5E"), Ok(addr));
+    assert_eq!(MacAddr6::parse_snmp("00 1A 2B 3C 04 5E "), Ok(addr));
+
+    assert_eq!(MacAddr6::parse_snmp("0:1a:2b:3c:4:5"), Ok(MacAddr6::new(0x00, 0x1A, 0x2B, 0x3C, 0x04, 0x05)));
+    assert_eq!(MacAddr6::parse_snmp("0:1a:2b:3c:4"), Err(ParseError::InvalidLength(12)));
+    assert_eq!(MacAddr6::parse_snmp("0:1a:2b:3c:4:5e:"), Err(ParseError::InvalidLength(16)));
+    assert_eq!(MacAddr6::parse_snmp("0:1a:2b:3c:4:5e:6f"), Err(ParseError::InvalidLength(18)));
+    assert_eq!(MacAddr6::parse_snmp(""), Err(ParseError::InvalidLength(0)));
+    assert_eq!(MacAddr6::parse_snmp("0:1a2:2b:3c:4:5e"), Err(ParseError::InvalidCharacter('2', 4)));
+    assert_eq!(MacAddr6::parse_snmp("0::2b:3c:4:5e"), Err(ParseError::InvalidDelimiter(':', 2)));
+    assert_eq!(MacAddr6::parse_snmp("00 1A:2B 3C 04 5E"), Err(ParseError::InvalidDelimiter(':', 5)));
+    assert_eq!(MacAddr6::parse_snmp("0 1A 2B 3C 04 5E"), Err(ParseError::InvalidDelimiter(' ', 1)));
+    assert_eq!(MacAddr6::parse_snmp("00 1A 2B 3C 04 5"), Err(ParseError::InvalidLength(16)));
+    assert_eq!(MacAddr6::parse_snmp("0-1a-2b-3c-4-5e"), Err(ParseError::InvalidDelimiter('-', 1)));
+    assert_eq!(MacAddr6::parse_snmp("0:1g:2b:3c:4:5e"), Err(ParseError::InvalidCharacter('g', 3)));
+}
+
+#[test]
+fn test_parse_mask_and_prefix_positions() {
+    assert_eq!(
+        MacAddrMask::from_str("12:34:56:78:9A:BC/ff:ff:ff:00:00:0g"),
+        Err(ParseError::InvalidCharacter('g', 34))
+    );
+    assert_eq!(
+        MacAddrMask::from_str("12:34:56:78:9A:BC/ff-ff:ff:00:00:00"),
+        Err(ParseError::InvalidDelimiter(':', 23))
+    );
+    assert_eq!(MacAddrMask::from_str("12:34:56:78:9A:BC/ff:ff"), Err(ParseError::InvalidLength(23)));
+    assert_eq!(
+        MacAddrMask::from_str("12:34:56:78:9A:BG/ff:ff:ff:00:00:0g"),
+        Err(ParseError::InvalidCharacter('G', 16))
+    );
+    assert_eq!(
+        MacAddrMask::from_str("AC:DE:48:00:00:00/FF:FF:FF:00:00:00/x"),
+        Err(ParseError::InvalidCharacter('/', 35))
+    );
+    assert_eq!(
+        MacAddrMask::from_str("AC:DE:48:00:00:00/FF:FF/x"),
+        Err(ParseError::InvalidCharacter('/', 23))
+    );
+    assert_eq!(
+        MacAddrMask::from_str("AC:DE:48:00:00:00/FF:FG/x"),
+        Err(ParseError::InvalidCharacter('G', 22))
+    );
+
+    assert_eq!(MacPrefix::from_str("AC:DE:48:00:00:00"), Err(ParseError::InvalidPrefixLength(17)));
+    assert_eq!(MacPrefix::from_str("AC:DE:48:00:00:00/"), Err(ParseError::InvalidPrefixLength(18)));
+    assert_eq!(MacPrefix::from_str("AC:DE:48:00:00:00/49"), Err(ParseError::InvalidPrefixLength(18)));
+    assert_eq!(
+        MacPrefix::from_str("AC:DE:48:00:00:00/49").map_err(|err| err.kind()),
+        Err(ParseErrorKind::InvalidPrefixLength)
+    );
 }
@@ -0,0 +1,211 @@
+use alloc::{collections::BTreeSet, vec::Vec};
+
+use crate::{MacAddr6, MacPrefix};
+
+/// Allocator of the unique `MacAddr6` addresses from one or more [MacPrefix] blocks.
+///
+/// Addresses are handed out sequentially from the blocks in the order they were added,
+/// and the released ones are reused first, the lowest address first.
+///
+/// Pool state can be persisted and restored with serde if the `"serde"` feature is enabled.
+///
+/// ## Example
+///
+/// ```rust
+/// # use macaddr::{MacAddr6, MacAddrPool};
+/// let mut pool = MacAddrPool::new("02:00:5E:10:00:00/46".parse().unwrap());
+///
+/// let first = pool.allocate().unwrap();
+/// assert_eq!(first, MacAddr6::new(0x02, 0x00, 0x5E, 0x10, 0x00, 0x00));
+/// assert_eq!(pool.allocate(), Some(MacAddr6::new(0x02, 0x00, 0x5E, 0x10, 0x00, 0x01)));
+///
+/// assert!(pool.release(&first));
+/// assert_eq!(pool.allocate(), Some(first));
+/// ```
+///
+/// [MacPrefix]: struct.MacPrefix.html
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct MacAddrPool {
+    blocks: Vec<MacPrefix>,
+    /// Lowest address which was never handed out by the sequential allocation, or `None` if the blocks are exhausted.
+    next: Option<MacAddr6>,
+    allocated: BTreeSet<MacAddr6>,
+    released: BTreeSet<MacAddr6>,
+}
+
+impl MacAddrPool {
+    /// Creates a new `MacAddrPool` allocating from the block.
+    pub fn new(block: MacPrefix) -> MacAddrPool {
+        MacAddrPool {
+            blocks: alloc::vec![block],
+            next: Some(block.first()),
+            allocated: BTreeSet::new(),
+            released: BTreeSet::new(),
+        }
+    }
+
+    /// Adds the block to allocate from after the previously added ones are exhausted.
+    ///
+    /// Returns `false` and keeps the pool intact if the block overlaps any of the previously added ones.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::{MacAddr6, MacAddrPool};
+    /// let mut pool = MacAddrPool::new("02:00:5E:10:00:00/48".parse().unwrap());
+    ///
+    /// assert!(pool.add_block("02:00:5E:20:00:00/48".parse().unwrap()));
+    /// assert!(!pool.add_block("02:00:5E:00:00:00/24".parse().unwrap()));
+    ///
+    /// assert_eq!(pool.allocate(), Some(MacAddr6::new(0x02, 0x00, 0x5E, 0x10, 0x00, 0x00)));
+    /// assert_eq!(pool.allocate(), Some(MacAddr6::new(0x02, 0x00, 0x5E, 0x20, 0x00, 0x00)));
+    /// assert_eq!(pool.allocate(), None);
+    /// ```
+    pub fn add_block(&mut self, block: MacPrefix) -> bool {
+        if self.blocks.iter().any(|other| other.contains(&block.first()) || block.contains(&other.first())) {
+            return false;
+        }
+
+        self.blocks.push(block);
+        if self.next.is_none() {
+            self.next = Some(block.first());
+        }
+
+        true
+    }
+
+    /// Returns the blocks the addresses are allocated from, in the allocation order.
+    pub fn blocks(&self) -> &[MacPrefix] {
+        &self.blocks
+    }
+
+    /// Returns `true` if the address belongs to one of the pool blocks.
+    pub fn contains(&self, addr: &MacAddr6) -> bool {
+        self.blocks.iter().any(|block| block.contains(addr))
+    }
+
+    /// Returns `true` if the address is currently allocated from the pool.
+    pub fn is_allocated(&self, addr: &MacAddr6) -> bool {
+        self.allocated.contains(addr)
+    }
+
+    /// Returns the number of the currently allocated addresses.
+    pub fn len(&self) -> usize {
+        self.allocated.len()
+    }
+
+    /// Returns `true` if no addresses are currently allocated.
+    pub fn is_empty(&self) -> bool {
+        self.allocated.is_empty()
+    }
+
+    /// Returns an iterator over the currently allocated addresses, in the ascending order.
+    pub fn allocated(&self) -> impl Iterator<Item = MacAddr6> + '_ {
+        self.allocated.iter().copied()
+    }
+
+    /// Allocates the unique address, preferring the released ones,
+    /// or returns `None` if all the addresses of the pool blocks are allocated.
+    pub fn allocate(&mut self) -> Option<MacAddr6> {
+        if let Some(addr) = self.released.pop_first() {
+            self.allocated.insert(addr);
+            return Some(addr);
+        }
+
+        while let Some(addr) = self.next {
+            self.next = self.successor(&addr);
+            // Skip the addresses reserved ahead of the sequential allocation.
+            if self.allocated.insert(addr) {
+                return Some(addr);
+            }
+        }
+
+        None
+    }
+
+    /// Marks the address as allocated, as for the addresses restored from the existing configuration.
+    ///
+    /// Returns `false` if the address is outside of the pool blocks or is already allocated.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::{MacAddr6, MacAddrPool};
+    /// let mut pool = MacAddrPool::new("02:00:5E:10:00:00/47".parse().unwrap());
+    ///
+    /// assert!(pool.reserve(MacAddr6::new(0x02, 0x00, 0x5E, 0x10, 0x00, 0x00)));
+    /// assert!(!pool.reserve(MacAddr6::broadcast()));
+    /// assert_eq!(pool.allocate(), Some(MacAddr6::new(0x02, 0x00, 0x5E, 0x10, 0x00, 0x01)));
+    /// ```
+    pub fn reserve(&mut self, addr: MacAddr6) -> bool {
+        if !self.contains(&addr) || !self.allocated.insert(addr) {
+            return false;
+        }
+
+        self.released.remove(&addr);
+        true
+    }
+
+    /// Returns the allocated address to the pool for the reuse.
+    ///
+    /// Returns `false` if the address was not allocated from the pool.
+    pub fn release(&mut self, addr: &MacAddr6) -> bool {
+        if !self.allocated.remove(addr) {
+            return false;
+        }
+
+        self.released.insert(*addr);
+        true
+    }
+
+    /// Restores the pool from the persisted state, returning `None` if it is inconsistent.
+    #[cfg(feature = "serde")]
+    pub(crate) fn from_state(
+        blocks: Vec<MacPrefix>,
+        next: Option<MacAddr6>,
+        allocated: BTreeSet<MacAddr6>,
+        released: BTreeSet<MacAddr6>,
+    ) -> Option<MacAddrPool> {
+        let mut pool = MacAddrPool {
+            blocks: Vec::with_capacity(blocks.len()),
+            next: None,
+            allocated: BTreeSet::new(),
+            released: BTreeSet::new(),
+        };
+
+        for block in blocks {
+            if !pool.add_block(block) {
+                return None;
+            }
+        }
+
+        let consistent = next.is_none_or(|addr| pool.contains(&addr))
+            && allocated.iter().chain(released.iter()).all(|addr| pool.contains(addr))
+            && allocated.is_disjoint(&released);
+        if !consistent {
+            return None;
+        }
+
+        pool.next = next;
+        pool.allocated = allocated;
+        pool.released = released;
+        Some(pool)
+    }
+
+    /// Returns the persisted state of the pool, as accepted by the `from_state` method.
+    #[cfg(feature = "serde")]
+    pub(crate) fn state(&self) -> (&[MacPrefix], Option<MacAddr6>, &BTreeSet<MacAddr6>, &BTreeSet<MacAddr6>) {
+        (&self.blocks, self.next, &self.allocated, &self.released)
+    }
+
+    /// Returns the address following the given one in the blocks order.
+    fn successor(&self, addr: &MacAddr6) -> Option<MacAddr6> {
+        let idx = self.blocks.iter().position(|block| block.contains(addr))?;
+
+        if *addr < self.blocks[idx].last() {
+            addr.next()
+        } else {
+            self.blocks.get(idx + 1).map(MacPrefix::first)
+        }
+    }
+}
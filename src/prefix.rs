@@ -0,0 +1,257 @@
+use core::{convert::TryFrom, fmt, iter::FusedIterator, ops::RangeInclusive, str::FromStr};
+
+use crate::{MacAddr6, ParseError};
+
+/// Maximum length of the `MacAddr6` prefix in bits.
+const MAX_LEN: u8 = 48;
+
+/// All the bits of the `MacAddr6` address as an integer.
+const ALL_BITS: u64 = 0xFFFF_FFFF_FFFF;
+
+/// A block of the `MacAddr6` addresses sharing the first `len` bits,
+/// written in the CIDR-like notation, as in `AC:DE:48:00:00:00/24`.
+///
+/// ## Example
+///
+/// ```rust
+/// # use macaddr::{MacAddr6, MacPrefix};
+/// let prefix: MacPrefix = "AC:DE:48:00:00:00/24".parse().unwrap();
+///
+/// assert!(prefix.contains(&MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22)));
+/// assert!(!prefix.contains(&MacAddr6::new(0xAC, 0xDE, 0x49, 0x00, 0x11, 0x22)));
+/// assert_eq!(prefix.last(), MacAddr6::new(0xAC, 0xDE, 0x48, 0xFF, 0xFF, 0xFF));
+/// ```
+#[derive(Debug, Hash, Eq, PartialEq, Ord, PartialOrd, Copy, Clone)]
+pub struct MacPrefix {
+    addr: MacAddr6,
+    len: u8,
+}
+
+impl MacPrefix {
+    /// Creates a new `MacPrefix` of the first `len` bits of the address, clearing the rest of them.
+    ///
+    /// Returns `None` if `len` is greater than 48.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::{MacAddr6, MacPrefix};
+    /// let prefix = MacPrefix::new(MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22), 28).unwrap();
+    ///
+    /// assert_eq!(prefix.first(), MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x00, 0x00));
+    /// assert_eq!(MacPrefix::new(MacAddr6::nil(), 49), None);
+    /// ```
+    pub const fn new(addr: MacAddr6, len: u8) -> Option<MacPrefix> {
+        if len > MAX_LEN {
+            return None;
+        }
+
+        match MacAddr6::from_u64(addr.to_u64() & mask(len)) {
+            Some(addr) => Some(MacPrefix {
+                addr,
+                len,
+            }),
+            None => None,
+        }
+    }
+
+    /// Returns the prefix length in bits.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacPrefix;
+    /// let prefix: MacPrefix = "AC:DE:48:00:00:00/24".parse().unwrap();
+    ///
+    /// assert_eq!(prefix.prefix_len(), 24);
+    /// ```
+    pub const fn prefix_len(&self) -> u8 {
+        self.len
+    }
+
+    /// Returns `true` if the address starts with the prefix bits.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::{MacAddr6, MacPrefix};
+    /// let prefix: MacPrefix = "AC:DE:48:00:00:00/24".parse().unwrap();
+    ///
+    /// assert!(prefix.contains(&MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22)));
+    /// assert!(!prefix.contains(&MacAddr6::broadcast()));
+    /// ```
+    pub const fn contains(&self, addr: &MacAddr6) -> bool {
+        addr.to_u64() & mask(self.len) == self.addr.to_u64()
+    }
+
+    /// Returns the first address of the block, with all the bits after the prefix cleared.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::{MacAddr6, MacPrefix};
+    /// let prefix: MacPrefix = "AC:DE:48:00:00:00/24".parse().unwrap();
+    ///
+    /// assert_eq!(prefix.first(), MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x00, 0x00));
+    /// ```
+    pub const fn first(&self) -> MacAddr6 {
+        self.addr
+    }
+
+    /// Returns the last address of the block, with all the bits after the prefix set.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::{MacAddr6, MacPrefix};
+    /// let prefix: MacPrefix = "AC:DE:48:00:00:00/24".parse().unwrap();
+    ///
+    /// assert_eq!(prefix.last(), MacAddr6::new(0xAC, 0xDE, 0x48, 0xFF, 0xFF, 0xFF));
+    /// ```
+    pub const fn last(&self) -> MacAddr6 {
+        match MacAddr6::from_u64(self.addr.to_u64() | ALL_BITS >> self.len) {
+            Some(addr) => addr,
+            // Address bits could not overflow 48 bits after masking.
+            None => self.addr,
+        }
+    }
+
+    /// Returns an iterator over all the addresses of the block, in the ascending order.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::{MacAddr6, MacPrefix};
+    /// let prefix: MacPrefix = "AC:DE:48:00:00:00/46".parse().unwrap();
+    ///
+    /// assert_eq!(prefix.iter().count(), 4);
+    /// assert_eq!(prefix.iter().last(), Some(MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x00, 0x03)));
+    /// ```
+    pub fn iter(&self) -> MacPrefixIter {
+        MacPrefixIter(self.first().to_u64()..=self.last().to_u64())
+    }
+}
+
+/// Returns the mask of the first `len` bits of the 48-bit address.
+const fn mask(len: u8) -> u64 {
+    ALL_BITS ^ ALL_BITS >> len
+}
+
+/// `MacPrefix` is parsed from the address in any notation accepted by the [MacAddr6] parser,
+/// followed by the slash and the decimal prefix length from 0 to 48.
+///
+/// Bits after the prefix are cleared, so `AC:DE:48:00:11:22/24` is the same as `AC:DE:48:00:00:00/24`.
+/// Missing or out of range prefix length is reported as the [`ParseError::InvalidPrefixLength`] error.
+///
+/// # Example
+///
+/// ```
+/// # use macaddr::{MacAddr6, MacPrefix, ParseError};
+/// let prefix = MacPrefix::new(MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x00, 0x00), 24);
+///
+/// assert_eq!("AC:DE:48:00:00:00/24".parse().ok(), prefix);
+/// assert_eq!("acde.4800.1122/24".parse().ok(), prefix);
+/// assert_eq!("AC:DE:48:00:00:00/49".parse::<MacPrefix>(), Err(ParseError::InvalidPrefixLength(18)));
+/// assert_eq!("AC:DE:48:00:00:00".parse::<MacPrefix>(), Err(ParseError::InvalidPrefixLength(17)));
+/// assert_eq!("AC:DE:48:00:00:00/2x".parse::<MacPrefix>(), Err(ParseError::InvalidCharacter('x', 19)));
+/// ```
+///
+/// [MacAddr6]: struct.MacAddr6.html
+/// [`ParseError::InvalidPrefixLength`]: enum.ParseError.html#variant.InvalidPrefixLength
+impl FromStr for MacPrefix {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let slash = s.find('/').ok_or(ParseError::InvalidPrefixLength(s.len()))?;
+        let addr = MacAddr6::from_str(&s[..slash])?;
+
+        let digits = &s[slash + 1..];
+        if let Some((pos, chr)) = digits.char_indices().find(|(_, chr)| !chr.is_ascii_digit()) {
+            return Err(ParseError::InvalidCharacter(chr, slash + 1 + pos));
+        }
+
+        digits
+            .parse()
+            .ok()
+            .and_then(|len| MacPrefix::new(addr, len))
+            .ok_or(ParseError::InvalidPrefixLength(slash + 1))
+    }
+}
+
+impl<'a> TryFrom<&'a str> for MacPrefix {
+    type Error = ParseError;
+
+    fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+        MacPrefix::from_str(s)
+    }
+}
+
+/// `MacPrefix` is displayed as the first address of the block followed by the prefix length,
+/// as in `AC:DE:48:00:00:00/24`.
+///
+/// Formatting flags are applied to the address as for the [MacAddr6] `Display` implementation.
+///
+/// # Example
+///
+/// ```
+/// # use macaddr::MacPrefix;
+/// let prefix: MacPrefix = "ac-de-48-00-11-22/24".parse().unwrap();
+///
+/// assert_eq!(prefix.to_string(), "AC:DE:48:00:00:00/24");
+/// assert_eq!(format!("{:-#}", prefix), "ac-de-48-00-00-00/24");
+/// ```
+///
+/// [MacAddr6]: struct.MacAddr6.html
+impl fmt::Display for MacPrefix {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.addr, f)?;
+        write!(f, "/{}", self.len)
+    }
+}
+
+impl IntoIterator for MacPrefix {
+    type Item = MacAddr6;
+    type IntoIter = MacPrefixIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl IntoIterator for &MacPrefix {
+    type Item = MacAddr6;
+    type IntoIter = MacPrefixIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the addresses of the [MacPrefix] block.
+///
+/// It is returned by the [MacPrefix::iter] method.
+///
+/// [MacPrefix]: struct.MacPrefix.html
+/// [MacPrefix::iter]: struct.MacPrefix.html#method.iter
+#[derive(Debug, Clone)]
+pub struct MacPrefixIter(RangeInclusive<u64>);
+
+impl Iterator for MacPrefixIter {
+    type Item = MacAddr6;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().and_then(MacAddr6::from_u64)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for MacPrefixIter {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().and_then(MacAddr6::from_u64)
+    }
+}
+
+impl FusedIterator for MacPrefixIter {}
@@ -0,0 +1,68 @@
+//! [proptest] strategies generating the MAC addresses.
+//!
+//! Generated addresses are shrunk towards the zero bytes,
+//! keeping the bits of the requested address class in place.
+//!
+//! ## Example
+//!
+//! ```rust
+//! # use proptest::prelude::*;
+//! proptest!(|(addr in macaddr::proptest::unicast_mac6())| {
+//!     prop_assert!(addr.is_unicast());
+//! });
+//! ```
+//!
+//! [proptest]: https://docs.rs/proptest
+
+use ::proptest::{arbitrary::any, strategy::Strategy};
+
+use crate::{MacAddr, MacAddr6, MacAddr8};
+
+/// Individual/group bit of the first address byte, set for the multicast addresses.
+const MULTICAST_BIT: u8 = 1;
+
+/// Universal/local bit of the first address byte, set for the locally administered addresses.
+const LOCAL_BIT: u8 = 1 << 1;
+
+fn mac6_with(set: u8, clear: u8) -> impl Strategy<Value = MacAddr6> {
+    any::<[u8; 6]>().prop_map(move |mut bytes| {
+        bytes[0] = bytes[0] & !clear | set;
+        MacAddr6::from(bytes)
+    })
+}
+
+/// Generates any `MacAddr6` address.
+pub fn any_mac6() -> impl Strategy<Value = MacAddr6> {
+    mac6_with(0, 0)
+}
+
+/// Generates any `MacAddr8` address.
+pub fn any_mac8() -> impl Strategy<Value = MacAddr8> {
+    any::<[u8; 8]>().prop_map(MacAddr8::from)
+}
+
+/// Generates any `MacAddr` address of both variants.
+pub fn any_mac() -> impl Strategy<Value = MacAddr> {
+    ::proptest::prop_oneof![any_mac6().prop_map(MacAddr::V6), any_mac8().prop_map(MacAddr::V8)]
+}
+
+/// Generates the unicast `MacAddr6` addresses.
+pub fn unicast_mac6() -> impl Strategy<Value = MacAddr6> {
+    mac6_with(0, MULTICAST_BIT)
+}
+
+/// Generates the multicast `MacAddr6` addresses, including the broadcast one.
+pub fn multicast_mac6() -> impl Strategy<Value = MacAddr6> {
+    mac6_with(MULTICAST_BIT, 0)
+}
+
+/// Generates the locally administered `MacAddr6` addresses, both unicast and multicast.
+pub fn locally_administered() -> impl Strategy<Value = MacAddr6> {
+    mac6_with(LOCAL_BIT, 0)
+}
+
+/// Generates the universally administered unicast `MacAddr6` addresses,
+/// as the ones assigned to the network interfaces by their manufacturers.
+pub fn universal_unicast_mac6() -> impl Strategy<Value = MacAddr6> {
+    mac6_with(0, MULTICAST_BIT | LOCAL_BIT)
+}
@@ -0,0 +1,87 @@
+use core::{iter::FusedIterator, ops::RangeInclusive};
+
+use crate::{MacAddr6, MacPrefix};
+
+/// An iterator over the consecutive `MacAddr6` addresses from the start to the end address inclusively.
+///
+/// It is returned by the [`MacAddr6::range_inclusive`] method.
+///
+/// ## Example
+///
+/// ```rust
+/// # use macaddr::MacAddr6;
+/// let start = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x00, 0xFE);
+/// let end = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x01, 0x01);
+///
+/// let addrs = MacAddr6::range_inclusive(start, end).collect::<Vec<_>>();
+/// assert_eq!(addrs.len(), 4);
+/// assert_eq!(addrs[2], MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x01, 0x00));
+/// ```
+///
+/// [`MacAddr6::range_inclusive`]: struct.MacAddr6.html#method.range_inclusive
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct MacAddr6Range(RangeInclusive<u64>);
+
+impl MacAddr6Range {
+    pub(crate) fn new(start: MacAddr6, end: MacAddr6) -> MacAddr6Range {
+        MacAddr6Range(start.to_u64()..=end.to_u64())
+    }
+
+    /// Returns `true` if the address is in the remaining part of the range.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// let range = MacAddr6::range_inclusive(MacAddr6::nil(), MacAddr6::new(0x00, 0x00, 0x00, 0x00, 0x00, 0xFF));
+    ///
+    /// assert!(range.contains(&MacAddr6::new(0x00, 0x00, 0x00, 0x00, 0x00, 0x10)));
+    /// assert!(!range.contains(&MacAddr6::broadcast()));
+    /// ```
+    pub fn contains(&self, addr: &MacAddr6) -> bool {
+        self.0.contains(&addr.to_u64())
+    }
+
+    /// Returns `true` if there are no addresses left in the range.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::MacAddr6;
+    /// assert!(MacAddr6::range_inclusive(MacAddr6::broadcast(), MacAddr6::nil()).is_empty());
+    /// assert!(!MacAddr6::range_inclusive(MacAddr6::nil(), MacAddr6::nil()).is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl From<MacPrefix> for MacAddr6Range {
+    fn from(prefix: MacPrefix) -> Self {
+        MacAddr6Range::new(prefix.first(), prefix.last())
+    }
+}
+
+impl Iterator for MacAddr6Range {
+    type Item = MacAddr6;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().and_then(MacAddr6::from_u64)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.0.nth(n).and_then(MacAddr6::from_u64)
+    }
+}
+
+impl DoubleEndedIterator for MacAddr6Range {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().and_then(MacAddr6::from_u64)
+    }
+}
+
+impl FusedIterator for MacAddr6Range {}
@@ -0,0 +1,87 @@
+//! [schemars] `JsonSchema` implementations, describing the addresses as the strings
+//! in any notation accepted by the `FromStr` implementations.
+//!
+//! [schemars]: https://docs.rs/schemars
+
+use schemars::{
+    gen::SchemaGenerator,
+    schema::{InstanceType, Metadata, Schema, SchemaObject, StringValidation},
+    JsonSchema,
+};
+
+use crate::{MacAddr, MacAddr6, MacAddr8};
+
+/// Pattern of the `MacAddr6` address in the hyphen, colon, Cisco or bare hex notation.
+const V6_PATTERN: &str = concat!(
+    "^(?:[0-9A-Fa-f]{2}(?:-[0-9A-Fa-f]{2}){5}",
+    "|[0-9A-Fa-f]{2}(?::[0-9A-Fa-f]{2}){5}",
+    "|[0-9A-Fa-f]{4}(?:\\.[0-9A-Fa-f]{4}){2}",
+    "|[0-9A-Fa-f]{12})$",
+);
+
+/// Pattern of the `MacAddr8` address in the hyphen, colon, Cisco or bare hex notation.
+const V8_PATTERN: &str = concat!(
+    "^(?:[0-9A-Fa-f]{2}(?:-[0-9A-Fa-f]{2}){7}",
+    "|[0-9A-Fa-f]{2}(?::[0-9A-Fa-f]{2}){7}",
+    "|[0-9A-Fa-f]{4}(?:\\.[0-9A-Fa-f]{4}){3}",
+    "|[0-9A-Fa-f]{16})$",
+);
+
+/// Pattern of either `MacAddr6` or `MacAddr8` address.
+const PATTERN: &str = concat!(
+    "^(?:[0-9A-Fa-f]{2}(?:-[0-9A-Fa-f]{2}){5}(?:(?:-[0-9A-Fa-f]{2}){2})?",
+    "|[0-9A-Fa-f]{2}(?::[0-9A-Fa-f]{2}){5}(?:(?::[0-9A-Fa-f]{2}){2})?",
+    "|[0-9A-Fa-f]{4}(?:\\.[0-9A-Fa-f]{4}){2}(?:\\.[0-9A-Fa-f]{4})?",
+    "|[0-9A-Fa-f]{12}(?:[0-9A-Fa-f]{4})?)$",
+);
+
+fn string_schema(description: &str, pattern: &str, example: &str) -> Schema {
+    SchemaObject {
+        metadata: Some(Box::new(Metadata {
+            description: Some(description.into()),
+            examples: vec![example.into()],
+            ..Default::default()
+        })),
+        instance_type: Some(InstanceType::String.into()),
+        format: Some("mac-address".into()),
+        string: Some(Box::new(StringValidation {
+            pattern: Some(pattern.into()),
+            ..Default::default()
+        })),
+        ..Default::default()
+    }
+    .into()
+}
+
+impl JsonSchema for MacAddr6 {
+    fn schema_name() -> String {
+        "MacAddr6".into()
+    }
+
+    fn json_schema(_gen: &mut SchemaGenerator) -> Schema {
+        string_schema("MAC address in EUI-48 format", V6_PATTERN, "AC-DE-48-00-11-22")
+    }
+}
+
+impl JsonSchema for MacAddr8 {
+    fn schema_name() -> String {
+        "MacAddr8".into()
+    }
+
+    fn json_schema(_gen: &mut SchemaGenerator) -> Schema {
+        string_schema("MAC address in EUI-64 format", V8_PATTERN, "AC-DE-48-00-11-22-33-44")
+    }
+}
+
+impl JsonSchema for MacAddr {
+    fn schema_name() -> String {
+        "MacAddr".into()
+    }
+
+    fn json_schema(_gen: &mut SchemaGenerator) -> Schema {
+        string_schema("MAC address, either in EUI-48 or EUI-64 format", PATTERN, "AC-DE-48-00-11-22")
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,66 @@
+use core::str::FromStr;
+
+use regex::Regex;
+use schemars::{
+    gen::SchemaGenerator,
+    schema::{InstanceType, Schema, SingleOrVec},
+    JsonSchema,
+};
+
+use super::{PATTERN, V6_PATTERN, V8_PATTERN};
+use crate::{MacAddr, MacAddr6, MacAddr8};
+
+const INPUTS: &[&str] = &[
+    "AC-DE-48-00-11-22",
+    "ac:de:48:00:11:22",
+    "acde.4800.1122",
+    "ACDE48001122",
+    "AC-DE-48-00-11-22-33-44",
+    "ac:de:48:00:11:22:33:44",
+    "acde.4800.1122.3344",
+    "ACDE480011223344",
+    "AC-DE:48-00-11-22",
+    "AC-DE-48-00-11",
+    "AC-DE-48-00-11-22-33",
+    "ac.de.48.00.11.22",
+    "acde.4800.1122.33",
+    "ACDE4800112",
+    "ACDE48001122334",
+    "ACDE480011223344FF",
+    "AC-DE-48-00-11-2Z",
+    "-AC-DE-48-00-11-22",
+    "",
+];
+
+#[test]
+fn test_schema_patterns_match_parser() {
+    let v6 = Regex::new(V6_PATTERN).unwrap();
+    let v8 = Regex::new(V8_PATTERN).unwrap();
+    let any = Regex::new(PATTERN).unwrap();
+
+    for input in INPUTS {
+        assert_eq!(v6.is_match(input), MacAddr6::from_str(input).is_ok(), "{}", input);
+        assert_eq!(v8.is_match(input), MacAddr8::from_str(input).is_ok(), "{}", input);
+        assert_eq!(any.is_match(input), MacAddr::from_str(input).is_ok(), "{}", input);
+    }
+}
+
+#[test]
+fn test_schema_string() {
+    let mut gen = SchemaGenerator::default();
+
+    for schema in [
+        MacAddr6::json_schema(&mut gen),
+        MacAddr8::json_schema(&mut gen),
+        MacAddr::json_schema(&mut gen),
+    ] {
+        let schema = match schema {
+            Schema::Object(schema) => schema,
+            Schema::Bool(..) => panic!("object schema expected"),
+        };
+
+        assert_eq!(schema.instance_type, Some(SingleOrVec::Single(Box::new(InstanceType::String))));
+        assert_eq!(schema.format.as_deref(), Some("mac-address"));
+        assert!(schema.string.and_then(|string| string.pattern).is_some());
+    }
+}
@@ -0,0 +1,472 @@
+//! Serde support for the MAC address types.
+//!
+//! By default, addresses are serialized as the strings in the canonical notation,
+//! as in `"AC-DE-48-00-11-22"`, for the human-readable formats, such as JSON,
+//! and as the raw bytes for the binary formats.
+//!
+//! Strings in any notation supported by the `FromStr` implementations,
+//! such as `"ac:de:48:00:11:22"` or `"acde.4800.1122"`, are accepted on deserialization,
+//! as well as the raw bytes or a sequence of bytes.
+//!
+//! [`MacAddr`] enum is serialized in the same way, without the variant tag,
+//! and the variant is detected from the address length on deserialization.
+//!
+//! Note that the raw bytes are prefixed with their length by some binary formats, such as [postcard] or bincode;
+//! the [`array`] helper module can be used to get exactly 6 or 8 bytes instead.
+//!
+//! Submodules of this module can be used with the `#[serde(with = "...")]` field attribute
+//! to select the representation explicitly, regardless of the format used.
+//!
+//! ## Example
+//!
+//! ```rust
+//! # use macaddr::{MacAddr, MacAddr6};
+//! # use serde::{Deserialize, Serialize};
+//! #[derive(Serialize, Deserialize)]
+//! struct Interface {
+//!     addr: MacAddr,
+//!     #[serde(with = "macaddr::serde::u64")]
+//!     gateway: MacAddr6,
+//! }
+//! ```
+//!
+//! [`MacAddr`]: ../enum.MacAddr.html
+//! [postcard]: https://docs.rs/postcard
+//! [`array`]: array/index.html
+
+use core::{convert::TryFrom, fmt, marker::PhantomData, str};
+
+use ::serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{display, MacAddr, MacAddr6, MacAddr8, MacPrefix, ParseError};
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// MAC address type supported by the helper modules of the [`macaddr::serde`] module.
+///
+/// This trait is sealed and implemented for the [`MacAddr`], [`MacAddr6`] and [`MacAddr8`] types.
+///
+/// [`macaddr::serde`]: index.html
+/// [`MacAddr`]: ../enum.MacAddr.html
+/// [`MacAddr6`]: ../struct.MacAddr6.html
+/// [`MacAddr8`]: ../struct.MacAddr8.html
+pub trait Address: private::Sealed + Sized {
+    #[doc(hidden)]
+    const EXPECTING: &'static str;
+
+    #[doc(hidden)]
+    fn to_bytes(&self) -> &[u8];
+
+    #[doc(hidden)]
+    fn from_bytes(bytes: &[u8]) -> Option<Self>;
+
+    #[doc(hidden)]
+    fn parse(s: &str) -> Result<Self, ParseError>;
+
+    #[doc(hidden)]
+    fn parse_ascii(s: &[u8]) -> Result<Self, ParseError>;
+}
+
+/// MAC address type supported by the [`macaddr::serde::u64`] helper module.
+///
+/// This trait is sealed and implemented for the [`MacAddr6`] and [`MacAddr8`] types only,
+/// as the [`MacAddr`] variant can't be detected from an integer.
+///
+/// [`macaddr::serde::u64`]: u64/index.html
+/// [`MacAddr`]: ../enum.MacAddr.html
+/// [`MacAddr6`]: ../struct.MacAddr6.html
+/// [`MacAddr8`]: ../struct.MacAddr8.html
+pub trait IntegerAddress: Address {
+    #[doc(hidden)]
+    fn to_u64(&self) -> u64;
+
+    #[doc(hidden)]
+    fn from_u64(value: u64) -> Option<Self>;
+}
+
+/// MAC address type of a fixed size, supported by the [`macaddr::serde::array`] helper module.
+///
+/// This trait is sealed and implemented for the [`MacAddr6`] and [`MacAddr8`] types only,
+/// as the [`MacAddr`] size depends on its variant.
+///
+/// [`macaddr::serde::array`]: array/index.html
+/// [`MacAddr`]: ../enum.MacAddr.html
+/// [`MacAddr6`]: ../struct.MacAddr6.html
+/// [`MacAddr8`]: ../struct.MacAddr8.html
+pub trait ArrayAddress: Address {
+    #[doc(hidden)]
+    const LEN: usize;
+}
+
+impl private::Sealed for MacAddr6 {}
+
+impl Address for MacAddr6 {
+    const EXPECTING: &'static str = "EUI-48 MAC address";
+
+    fn to_bytes(&self) -> &[u8] {
+        self.as_bytes()
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        <[u8; 6]>::try_from(bytes).ok().map(MacAddr6::from)
+    }
+
+    fn parse(s: &str) -> Result<Self, ParseError> {
+        s.parse()
+    }
+
+    fn parse_ascii(s: &[u8]) -> Result<Self, ParseError> {
+        Self::parse_ascii(s)
+    }
+}
+
+impl ArrayAddress for MacAddr6 {
+    const LEN: usize = 6;
+}
+
+impl IntegerAddress for MacAddr6 {
+    fn to_u64(&self) -> u64 {
+        MacAddr6::to_u64(self)
+    }
+
+    fn from_u64(value: u64) -> Option<Self> {
+        MacAddr6::from_u64(value)
+    }
+}
+
+impl private::Sealed for MacAddr8 {}
+
+impl Address for MacAddr8 {
+    const EXPECTING: &'static str = "EUI-64 MAC address";
+
+    fn to_bytes(&self) -> &[u8] {
+        self.as_bytes()
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        <[u8; 8]>::try_from(bytes).ok().map(MacAddr8::from)
+    }
+
+    fn parse(s: &str) -> Result<Self, ParseError> {
+        s.parse()
+    }
+
+    fn parse_ascii(s: &[u8]) -> Result<Self, ParseError> {
+        Self::parse_ascii(s)
+    }
+}
+
+impl ArrayAddress for MacAddr8 {
+    const LEN: usize = 8;
+}
+
+impl IntegerAddress for MacAddr8 {
+    fn to_u64(&self) -> u64 {
+        MacAddr8::to_u64(self)
+    }
+
+    fn from_u64(value: u64) -> Option<Self> {
+        Some(MacAddr8::from_u64(value))
+    }
+}
+
+impl private::Sealed for MacAddr {}
+
+impl Address for MacAddr {
+    const EXPECTING: &'static str = "EUI-48 or EUI-64 MAC address";
+
+    fn to_bytes(&self) -> &[u8] {
+        self.as_bytes()
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        MacAddr::try_from(bytes).ok()
+    }
+
+    fn parse(s: &str) -> Result<Self, ParseError> {
+        s.parse()
+    }
+
+    fn parse_ascii(s: &[u8]) -> Result<Self, ParseError> {
+        Self::parse_ascii(s)
+    }
+}
+
+fn serialize_str<T: Address, S: Serializer>(addr: &T, serializer: S) -> Result<S::Ok, S::Error> {
+    let mut buf = [0; MacAddr8::MAX_DISPLAY_LEN];
+    let len = display::encode(&mut buf, addr.to_bytes(), Some('-'), 1, true);
+
+    // Hex digits and hyphens are always valid UTF-8.
+    serializer.serialize_str(str::from_utf8(&buf[..len]).unwrap_or_default())
+}
+
+/// Visitor accepting the address as a string, as the raw bytes or as a sequence of bytes.
+///
+/// Strings in any notation supported by the `FromStr` implementations are accepted,
+/// as well as the same ASCII text passed as bytes, which no raw address is long enough to be confused with.
+struct AddressVisitor<T>(PhantomData<T>);
+
+impl<'de, T: Address> de::Visitor<'de> for AddressVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(T::EXPECTING)
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<T, E> {
+        T::parse(v).map_err(E::custom)
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<T, E> {
+        match T::from_bytes(v) {
+            Some(addr) => Ok(addr),
+            // Bare hex notation of the `MacAddr6` is the shortest textual one.
+            None if v.len() >= 12 => T::parse_ascii(v).map_err(E::custom),
+            None => Err(E::invalid_length(v.len(), &self)),
+        }
+    }
+
+    fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<T, A::Error> {
+        let mut bytes = [0; 8];
+        let mut len = 0;
+
+        while let Some(byte) = seq.next_element()? {
+            if len == bytes.len() {
+                return Err(de::Error::invalid_length(len + 1, &self));
+            }
+            bytes[len] = byte;
+            len += 1;
+        }
+
+        T::from_bytes(&bytes[..len]).ok_or_else(|| de::Error::invalid_length(len, &self))
+    }
+}
+
+/// Visitor accepting the address as an unsigned integer.
+struct U64Visitor<T>(PhantomData<T>);
+
+impl<'de, T: IntegerAddress> de::Visitor<'de> for U64Visitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} as an unsigned integer", T::EXPECTING)
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<T, E> {
+        T::from_u64(v).ok_or_else(|| E::invalid_value(de::Unexpected::Unsigned(v), &self))
+    }
+}
+
+macro_rules! impl_serde {
+    ($name:ident) => {
+        impl Serialize for $name {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                if serializer.is_human_readable() {
+                    string::serialize(self, serializer)
+                } else {
+                    bytes::serialize(self, serializer)
+                }
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                if deserializer.is_human_readable() {
+                    // Byte arrays written by the derived implementations of the previous versions are accepted too.
+                    deserializer.deserialize_any(AddressVisitor(PhantomData))
+                } else {
+                    bytes::deserialize(deserializer)
+                }
+            }
+        }
+    };
+}
+
+impl_serde!(MacAddr6);
+impl_serde!(MacAddr8);
+impl_serde!(MacAddr);
+
+/// `MacPrefix` is serialized as a string in the CIDR-like notation, as in `"AC:DE:48:00:00:00/24"`,
+/// for both the human-readable and the binary formats.
+///
+/// # Example
+///
+/// ```
+/// # use macaddr::MacPrefix;
+/// # use serde_test::{assert_tokens, Token};
+/// let prefix: MacPrefix = "AC:DE:48:00:00:00/24".parse().unwrap();
+///
+/// assert_tokens(&prefix, &[Token::Str("AC:DE:48:00:00:00/24")]);
+/// ```
+impl Serialize for MacPrefix {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for MacPrefix {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(PrefixVisitor)
+    }
+}
+
+/// Visitor accepting the prefix as a string in any notation supported by the `FromStr` implementation.
+struct PrefixVisitor;
+
+impl<'de> de::Visitor<'de> for PrefixVisitor {
+    type Value = MacPrefix;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("MAC address prefix")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<MacPrefix, E> {
+        v.parse().map_err(E::custom)
+    }
+}
+
+/// Serializes the address as a string in the canonical notation, as in `"AC-DE-48-00-11-22"`.
+///
+/// Deserialization accepts any notation supported by the `FromStr` implementations.
+///
+/// ## Example
+///
+/// ```rust
+/// # use macaddr::MacAddr8;
+/// # use serde::{Deserialize, Serialize};
+/// #[derive(Serialize, Deserialize)]
+/// struct Port {
+///     #[serde(with = "macaddr::serde::string")]
+///     guid: MacAddr8,
+/// }
+/// ```
+pub mod string {
+    use core::marker::PhantomData;
+
+    use ::serde::{Deserializer, Serializer};
+
+    use super::{Address, AddressVisitor};
+
+    /// Serializes the address as a string.
+    pub fn serialize<T: Address, S: Serializer>(addr: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        super::serialize_str(addr, serializer)
+    }
+
+    /// Deserializes the address from a string.
+    pub fn deserialize<'de, T: Address, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        deserializer.deserialize_str(AddressVisitor(PhantomData))
+    }
+}
+
+/// Serializes the address as the raw bytes.
+///
+/// Deserialization accepts a sequence of bytes too.
+///
+/// ## Example
+///
+/// ```rust
+/// # use macaddr::MacAddr6;
+/// # use serde::{Deserialize, Serialize};
+/// #[derive(Serialize, Deserialize)]
+/// struct Frame {
+///     #[serde(with = "macaddr::serde::bytes")]
+///     source: MacAddr6,
+/// }
+/// ```
+pub mod bytes {
+    use core::marker::PhantomData;
+
+    use ::serde::{Deserializer, Serializer};
+
+    use super::{Address, AddressVisitor};
+
+    /// Serializes the address as the raw bytes.
+    pub fn serialize<T: Address, S: Serializer>(addr: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(addr.to_bytes())
+    }
+
+    /// Deserializes the address from the raw bytes.
+    pub fn deserialize<'de, T: Address, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        deserializer.deserialize_bytes(AddressVisitor(PhantomData))
+    }
+}
+
+/// Serializes the address as a fixed-size tuple of bytes.
+///
+/// Unlike the raw bytes, tuples are not prefixed with their length by the binary formats,
+/// so the address always takes exactly 6 or 8 bytes, as in the [postcard] format.
+///
+/// ## Example
+///
+/// ```rust
+/// # use macaddr::MacAddr6;
+/// # use serde::{Deserialize, Serialize};
+/// #[derive(Serialize, Deserialize)]
+/// struct Telemetry {
+///     #[serde(with = "macaddr::serde::array")]
+///     source: MacAddr6,
+///     rssi: i8,
+/// }
+/// ```
+///
+/// [postcard]: https://docs.rs/postcard
+pub mod array {
+    use core::marker::PhantomData;
+
+    use ::serde::{ser::SerializeTuple, Deserializer, Serializer};
+
+    use super::{AddressVisitor, ArrayAddress};
+
+    /// Serializes the address as a tuple of bytes.
+    pub fn serialize<T: ArrayAddress, S: Serializer>(addr: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut tuple = serializer.serialize_tuple(T::LEN)?;
+        for byte in addr.to_bytes() {
+            tuple.serialize_element(byte)?;
+        }
+
+        tuple.end()
+    }
+
+    /// Deserializes the address from a tuple of bytes.
+    pub fn deserialize<'de, T: ArrayAddress, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        deserializer.deserialize_tuple(T::LEN, AddressVisitor(PhantomData))
+    }
+}
+
+/// Serializes the address as an unsigned integer, made of its bytes in the big-endian order.
+///
+/// ## Example
+///
+/// ```rust
+/// # use macaddr::MacAddr6;
+/// # use serde::{Deserialize, Serialize};
+/// #[derive(Serialize, Deserialize)]
+/// struct Row {
+///     #[serde(with = "macaddr::serde::u64")]
+///     addr: MacAddr6,
+/// }
+/// ```
+pub mod u64 {
+    use core::marker::PhantomData;
+
+    use ::serde::{Deserializer, Serializer};
+
+    use super::{IntegerAddress, U64Visitor};
+
+    /// Serializes the address as an unsigned integer.
+    pub fn serialize<T: IntegerAddress, S: Serializer>(addr: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(addr.to_u64())
+    }
+
+    /// Deserializes the address from an unsigned integer.
+    pub fn deserialize<'de, T: IntegerAddress, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        deserializer.deserialize_u64(U64Visitor(PhantomData))
+    }
+}
+
+#[cfg(feature = "alloc")]
+mod pool;
+#[cfg(test)]
+mod tests;
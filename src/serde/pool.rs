@@ -0,0 +1,206 @@
+//! Serde support for the [`MacAddrPool`] state.
+//!
+//! [`MacAddrPool`]: ../../struct.MacAddrPool.html
+
+use alloc::{collections::BTreeSet, vec::Vec};
+use core::{fmt, marker::PhantomData};
+
+use ::serde::{
+    de,
+    ser::{self, SerializeStruct},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::{MacAddr6, MacAddrPool, MacPrefix};
+
+const FIELDS: &[&str] = &["blocks", "next", "allocated", "released"];
+
+/// `MacAddrPool` is serialized as a struct with the `blocks`, `next`, `allocated` and `released` fields,
+/// holding the sequences of the prefixes and the addresses.
+///
+/// Inconsistent state, such as the overlapping blocks or the addresses outside of them,
+/// is rejected on deserialization.
+///
+/// # Example
+///
+/// ```
+/// # use macaddr::MacAddrPool;
+/// # use serde_test::{assert_tokens, Configure, Token};
+/// let mut pool = MacAddrPool::new("02:00:5E:10:00:00/47".parse().unwrap());
+/// pool.allocate();
+///
+/// assert_tokens(&pool.readable(), &[
+///     Token::Struct { name: "MacAddrPool", len: 4 },
+///     Token::Str("blocks"),
+///     Token::Seq { len: Some(1) },
+///     Token::Str("02:00:5E:10:00:00/47"),
+///     Token::SeqEnd,
+///     Token::Str("next"),
+///     Token::Some,
+///     Token::Str("02-00-5E-10-00-01"),
+///     Token::Str("allocated"),
+///     Token::Seq { len: Some(1) },
+///     Token::Str("02-00-5E-10-00-00"),
+///     Token::SeqEnd,
+///     Token::Str("released"),
+///     Token::Seq { len: Some(0) },
+///     Token::SeqEnd,
+///     Token::StructEnd,
+/// ]);
+/// ```
+impl Serialize for MacAddrPool {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let (blocks, next, allocated, released) = self.state();
+
+        let mut state = serializer.serialize_struct("MacAddrPool", FIELDS.len())?;
+        state.serialize_field("blocks", blocks)?;
+        state.serialize_field("next", &next)?;
+        state.serialize_field("allocated", &Set(allocated))?;
+        state.serialize_field("released", &Set(released))?;
+        state.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for MacAddrPool {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_struct("MacAddrPool", FIELDS, PoolVisitor)
+    }
+}
+
+/// Serializes the set of the addresses as a sequence.
+struct Set<'a>(&'a BTreeSet<MacAddr6>);
+
+impl ser::Serialize for Set<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.0)
+    }
+}
+
+/// Deserializes a sequence into the collection, as the serde implementations for them require its `alloc` feature.
+struct Collect<C, T>(C, PhantomData<T>);
+
+impl<'de, C: Default + Extend<T>, T: Deserialize<'de>> Deserialize<'de> for Collect<C, T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(CollectVisitor(PhantomData))
+    }
+}
+
+struct CollectVisitor<C, T>(PhantomData<(C, T)>);
+
+impl<'de, C: Default + Extend<T>, T: Deserialize<'de>> de::Visitor<'de> for CollectVisitor<C, T> {
+    type Value = Collect<C, T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a sequence")
+    }
+
+    fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut collection = C::default();
+        while let Some(item) = seq.next_element()? {
+            collection.extend(Some(item));
+        }
+
+        Ok(Collect(collection, PhantomData))
+    }
+}
+
+enum Field {
+    Blocks,
+    Next,
+    Allocated,
+    Released,
+}
+
+impl<'de> Deserialize<'de> for Field {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_identifier(FieldVisitor)
+    }
+}
+
+struct FieldVisitor;
+
+impl<'de> de::Visitor<'de> for FieldVisitor {
+    type Value = Field;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("MAC address pool field")
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Field, E> {
+        match v {
+            0 => Ok(Field::Blocks),
+            1 => Ok(Field::Next),
+            2 => Ok(Field::Allocated),
+            3 => Ok(Field::Released),
+            _ => Err(E::invalid_value(de::Unexpected::Unsigned(v), &self)),
+        }
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Field, E> {
+        match v {
+            "blocks" => Ok(Field::Blocks),
+            "next" => Ok(Field::Next),
+            "allocated" => Ok(Field::Allocated),
+            "released" => Ok(Field::Released),
+            _ => Err(E::unknown_field(v, FIELDS)),
+        }
+    }
+}
+
+type Blocks = Collect<Vec<MacPrefix>, MacPrefix>;
+type Addrs = Collect<BTreeSet<MacAddr6>, MacAddr6>;
+
+struct PoolVisitor;
+
+impl PoolVisitor {
+    fn restore<E: de::Error>(
+        blocks: Blocks,
+        next: Option<MacAddr6>,
+        allocated: Addrs,
+        released: Addrs,
+    ) -> Result<MacAddrPool, E> {
+        MacAddrPool::from_state(blocks.0, next, allocated.0, released.0)
+            .ok_or_else(|| E::custom("inconsistent MAC address pool state"))
+    }
+}
+
+impl<'de> de::Visitor<'de> for PoolVisitor {
+    type Value = MacAddrPool;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("MAC address pool")
+    }
+
+    fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<MacAddrPool, A::Error> {
+        let blocks = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let next = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        let allocated = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(2, &self))?;
+        let released = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(3, &self))?;
+
+        PoolVisitor::restore(blocks, next, allocated, released)
+    }
+
+    fn visit_map<A: de::MapAccess<'de>>(self, mut map: A) -> Result<MacAddrPool, A::Error> {
+        let (mut blocks, mut next, mut allocated, mut released) = (None, None, None, None);
+
+        while let Some(field) = map.next_key()? {
+            match field {
+                Field::Blocks if blocks.is_none() => blocks = Some(map.next_value()?),
+                Field::Next if next.is_none() => next = Some(map.next_value()?),
+                Field::Allocated if allocated.is_none() => allocated = Some(map.next_value()?),
+                Field::Released if released.is_none() => released = Some(map.next_value()?),
+                Field::Blocks => return Err(de::Error::duplicate_field("blocks")),
+                Field::Next => return Err(de::Error::duplicate_field("next")),
+                Field::Allocated => return Err(de::Error::duplicate_field("allocated")),
+                Field::Released => return Err(de::Error::duplicate_field("released")),
+            }
+        }
+
+        PoolVisitor::restore(
+            blocks.ok_or_else(|| de::Error::missing_field("blocks"))?,
+            next.ok_or_else(|| de::Error::missing_field("next"))?,
+            allocated.ok_or_else(|| de::Error::missing_field("allocated"))?,
+            released.ok_or_else(|| de::Error::missing_field("released"))?,
+        )
+    }
+}
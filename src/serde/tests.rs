@@ -0,0 +1,288 @@
+use serde::{Deserialize, Serialize};
+use serde_test::{assert_de_tokens, assert_de_tokens_error, assert_tokens, Compact, Configure, Readable, Token};
+
+use crate::{MacAddr, MacAddr6, MacAddr8};
+
+#[test]
+fn test_serde_v6_readable() {
+    let addr = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22);
+
+    assert_tokens(&addr.readable(), &[Token::Str("AC-DE-48-00-11-22")]);
+}
+
+#[test]
+fn test_serde_v8_readable() {
+    let addr = MacAddr8::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44);
+
+    assert_tokens(&addr.readable(), &[Token::Str("AC-DE-48-00-11-22-33-44")]);
+}
+
+#[test]
+fn test_serde_v6_compact() {
+    let addr = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22);
+
+    assert_tokens(&addr.compact(), &[Token::Bytes(&[0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22])]);
+}
+
+#[test]
+fn test_serde_v8_compact() {
+    let addr = MacAddr8::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44);
+
+    assert_tokens(
+        &addr.compact(),
+        &[Token::Bytes(&[0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44])],
+    );
+}
+
+#[test]
+fn test_serde_v6_deserialize_any_notation() {
+    let addr = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22);
+
+    for s in ["ac:de:48:00:11:22", "AC-DE-48-00-11-22", "acde.4800.1122", "ACDE48001122"] {
+        assert_de_tokens(&addr.readable(), &[Token::Str(s)]);
+        assert_de_tokens(&addr.readable(), &[Token::String(s)]);
+        assert_de_tokens(&addr.compact(), &[Token::Str(s)]);
+        assert_de_tokens(&addr.compact(), &[Token::Bytes(s.as_bytes())]);
+    }
+}
+
+#[test]
+fn test_serde_v8_deserialize_any_notation() {
+    let addr = MacAddr8::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44);
+
+    for s in ["ac:de:48:00:11:22:33:44", "AC-DE-48-00-11-22-33-44", "acde.4800.1122.3344", "ACDE480011223344"] {
+        assert_de_tokens(&addr.readable(), &[Token::BorrowedStr(s)]);
+        assert_de_tokens(&addr.compact(), &[Token::ByteBuf(s.as_bytes())]);
+    }
+}
+
+#[test]
+fn test_serde_readable() {
+    let addr = MacAddr::from([0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22]);
+    assert_tokens(&addr.readable(), &[Token::Str("AC-DE-48-00-11-22")]);
+
+    let addr = MacAddr::from([0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44]);
+    assert_tokens(&addr.readable(), &[Token::Str("AC-DE-48-00-11-22-33-44")]);
+    assert_de_tokens(&addr.readable(), &[Token::Str("acde.4800.1122.3344")]);
+}
+
+#[test]
+fn test_serde_compact() {
+    let addr = MacAddr::from([0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22]);
+    assert_tokens(&addr.compact(), &[Token::Bytes(&[0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22])]);
+
+    let addr = MacAddr::from([0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44]);
+    assert_tokens(
+        &addr.compact(),
+        &[Token::Bytes(&[0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44])],
+    );
+    assert_de_tokens(
+        &addr.compact(),
+        &[
+            Token::Seq { len: Some(8) },
+            Token::U8(0xAC),
+            Token::U8(0xDE),
+            Token::U8(0x48),
+            Token::U8(0x00),
+            Token::U8(0x11),
+            Token::U8(0x22),
+            Token::U8(0x33),
+            Token::U8(0x44),
+            Token::SeqEnd,
+        ],
+    );
+}
+
+#[test]
+fn test_serde_deserialize_invalid() {
+    assert_de_tokens_error::<Compact<MacAddr>>(
+        &[Token::Bytes(&[0xAC, 0xDE, 0x48, 0x00, 0x11])],
+        "invalid length 5, expected EUI-48 or EUI-64 MAC address",
+    );
+    assert_de_tokens_error::<Readable<MacAddr>>(
+        &[Token::Str("AC-DE-48-00-11-22-33")],
+        "Invalid length of 20 characters",
+    );
+}
+
+#[test]
+fn test_serde_v6_deserialize_seq() {
+    let addr = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22);
+
+    assert_de_tokens(
+        &addr.compact(),
+        &[
+            Token::Tuple { len: 6 },
+            Token::U8(0xAC),
+            Token::U8(0xDE),
+            Token::U8(0x48),
+            Token::U8(0x00),
+            Token::U8(0x11),
+            Token::U8(0x22),
+            Token::TupleEnd,
+        ],
+    );
+}
+
+#[test]
+fn test_serde_json_legacy_array() {
+    let addr = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22);
+    assert_eq!(serde_json::from_str::<MacAddr6>("[172,222,72,0,17,34]").unwrap(), addr);
+    assert_eq!(serde_json::from_str::<MacAddr6>("\"AC-DE-48-00-11-22\"").unwrap(), addr);
+    assert_eq!(serde_json::to_string(&addr).unwrap(), "\"AC-DE-48-00-11-22\"");
+
+    let addr = MacAddr8::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44);
+    assert_eq!(serde_json::from_str::<MacAddr8>("[172,222,72,0,17,34,51,68]").unwrap(), addr);
+    assert_eq!(serde_json::from_str::<MacAddr8>("\"AC-DE-48-00-11-22-33-44\"").unwrap(), addr);
+
+    assert!(serde_json::from_str::<MacAddr6>("[172,222,72,0,17]").is_err());
+    assert!(serde_json::from_str::<MacAddr6>("172").is_err());
+}
+
+#[test]
+fn test_serde_v6_deserialize_invalid() {
+    assert_de_tokens_error::<Readable<MacAddr6>>(
+        &[Token::Str("AC-DE-48-00-11")],
+        "Invalid length of 14 characters",
+    );
+    assert_de_tokens_error::<Compact<MacAddr6>>(
+        &[Token::Bytes(&[0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44])],
+        "invalid length 8, expected EUI-48 MAC address",
+    );
+    assert_de_tokens_error::<Compact<MacAddr6>>(
+        &[Token::Bytes(b"AC-DE-48-00-11-2Z")],
+        "Unexpected character 'Z' at position 16",
+    );
+    assert_de_tokens_error::<Compact<MacAddr8>>(
+        &[Token::Bytes(&[0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22])],
+        "invalid length 6, expected EUI-64 MAC address",
+    );
+}
+
+#[test]
+fn test_serde_postcard_bytes() {
+    let addr = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22);
+
+    // Raw bytes are prefixed with their length.
+    let mut buf = [0; 16];
+    let bytes = postcard::to_slice(&addr, &mut buf).unwrap();
+    assert_eq!(bytes, [0x06, 0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22]);
+    assert_eq!(postcard::from_bytes::<MacAddr6>(bytes), Ok(addr));
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Frame {
+    #[serde(with = "crate::serde::array")]
+    v6: MacAddr6,
+    #[serde(with = "crate::serde::array")]
+    v8: MacAddr8,
+}
+
+#[test]
+fn test_serde_postcard_array() {
+    let frame = Frame {
+        v6: MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22),
+        v8: MacAddr8::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44),
+    };
+
+    let mut buf = [0; 16];
+    let bytes = postcard::to_slice(&frame, &mut buf).unwrap();
+    assert_eq!(
+        bytes,
+        [0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44]
+    );
+    assert_eq!(postcard::from_bytes::<Frame>(bytes), Ok(frame));
+    assert!(postcard::from_bytes::<Frame>(&bytes[..13]).is_err());
+}
+
+#[test]
+fn test_serde_array_tokens() {
+    assert_tokens(
+        &Frame {
+            v6: MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22),
+            v8: MacAddr8::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44),
+        },
+        &[
+            Token::Struct {
+                name: "Frame",
+                len: 2,
+            },
+            Token::Str("v6"),
+            Token::Tuple { len: 6 },
+            Token::U8(0xAC),
+            Token::U8(0xDE),
+            Token::U8(0x48),
+            Token::U8(0x00),
+            Token::U8(0x11),
+            Token::U8(0x22),
+            Token::TupleEnd,
+            Token::Str("v8"),
+            Token::Tuple { len: 8 },
+            Token::U8(0xAC),
+            Token::U8(0xDE),
+            Token::U8(0x48),
+            Token::U8(0x00),
+            Token::U8(0x11),
+            Token::U8(0x22),
+            Token::U8(0x33),
+            Token::U8(0x44),
+            Token::TupleEnd,
+            Token::StructEnd,
+        ],
+    );
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Addresses {
+    #[serde(with = "crate::serde::string")]
+    string: MacAddr6,
+    #[serde(with = "crate::serde::bytes")]
+    bytes: MacAddr,
+    #[serde(with = "crate::serde::u64")]
+    u64: MacAddr6,
+}
+
+#[test]
+fn test_serde_helper_modules() {
+    let addrs = Addresses {
+        string: MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22),
+        bytes: MacAddr::from([0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44]),
+        u64: MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22),
+    };
+
+    assert_tokens(
+        &addrs,
+        &[
+            Token::Struct {
+                name: "Addresses",
+                len: 3,
+            },
+            Token::Str("string"),
+            Token::Str("AC-DE-48-00-11-22"),
+            Token::Str("bytes"),
+            Token::Bytes(&[0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44]),
+            Token::Str("u64"),
+            Token::U64(0xACDE48001122),
+            Token::StructEnd,
+        ],
+    );
+}
+
+#[test]
+fn test_serde_u64_out_of_range() {
+    assert_de_tokens_error::<Addresses>(
+        &[
+            Token::Struct {
+                name: "Addresses",
+                len: 3,
+            },
+            Token::Str("string"),
+            Token::Str("AC-DE-48-00-11-22"),
+            Token::Str("bytes"),
+            Token::Bytes(&[0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44]),
+            Token::Str("u64"),
+            Token::U64(0x01_0000_0000_0000),
+        ],
+        "invalid value: integer `281474976710656`, expected EUI-48 MAC address as an unsigned integer",
+    );
+}
@@ -0,0 +1,245 @@
+use alloc::vec::Vec;
+use core::{convert::TryFrom, fmt, iter::FusedIterator};
+
+use crate::{MacAddr6, MacPrefix};
+
+/// Set of the `MacAddr6` addresses within the fixed [MacPrefix] block, stored as a bitmap
+/// with one bit per address of the block.
+///
+/// Bitmap size is determined by the prefix length, so a /36 block takes 512 bytes,
+/// a /28 one takes 128 KiB and a /24 one takes 2 MiB regardless of the number of the addresses in the set.
+///
+/// ## Example
+///
+/// ```rust
+/// # use macaddr::{MacAddr6, MacSet};
+/// let mut seen = MacSet::new("AC:DE:48:00:00:00/24".parse().unwrap());
+///
+/// assert!(seen.insert(MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22)));
+/// assert!(!seen.insert(MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22)));
+/// assert!(seen.insert(MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x00, 0x01)));
+///
+/// assert_eq!(seen.len(), 2);
+/// assert_eq!(seen.iter().next(), Some(MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x00, 0x01)));
+/// ```
+///
+/// [MacPrefix]: struct.MacPrefix.html
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub struct MacSet {
+    prefix: MacPrefix,
+    bits: Vec<u64>,
+}
+
+impl MacSet {
+    /// Creates a new empty `MacSet` for the addresses of the block.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the bitmap of the block does not fit into the address space,
+    /// which is the case for the prefixes shorter than 10 bits on the 32-bit targets.
+    pub fn new(prefix: MacPrefix) -> MacSet {
+        let words = (1u64 << (48 - prefix.prefix_len())).div_ceil(u64::from(u64::BITS));
+        let words = usize::try_from(words).expect("MacSet block is too large for the target");
+
+        MacSet {
+            prefix,
+            bits: alloc::vec![0; words],
+        }
+    }
+
+    /// Returns the block the set addresses belong to.
+    pub fn prefix(&self) -> MacPrefix {
+        self.prefix
+    }
+
+    /// Returns the number of the addresses in the set.
+    ///
+    /// It is computed by counting the bitmap bits, so it takes time proportional to the block size.
+    pub fn len(&self) -> usize {
+        self.bits.iter().map(|word| word.count_ones() as usize).sum()
+    }
+
+    /// Returns `true` if the set contains no addresses.
+    pub fn is_empty(&self) -> bool {
+        self.bits.iter().all(|word| *word == 0)
+    }
+
+    /// Removes all the addresses from the set.
+    pub fn clear(&mut self) {
+        self.bits.iter_mut().for_each(|word| *word = 0);
+    }
+
+    /// Returns `true` if the set contains the address.
+    pub fn contains(&self, addr: &MacAddr6) -> bool {
+        match self.position(addr) {
+            Some((idx, bit)) => self.bits[idx] & bit != 0,
+            None => false,
+        }
+    }
+
+    /// Adds the address to the set.
+    ///
+    /// Returns `false` if the set already contained the address or if it is outside of the set block.
+    pub fn insert(&mut self, addr: MacAddr6) -> bool {
+        let (idx, bit) = match self.position(&addr) {
+            Some(position) => position,
+            None => return false,
+        };
+
+        let inserted = self.bits[idx] & bit == 0;
+        self.bits[idx] |= bit;
+        inserted
+    }
+
+    /// Removes the address from the set, returning `true` if it was present.
+    pub fn remove(&mut self, addr: &MacAddr6) -> bool {
+        let (idx, bit) = match self.position(addr) {
+            Some(position) => position,
+            None => return false,
+        };
+
+        let removed = self.bits[idx] & bit != 0;
+        self.bits[idx] &= !bit;
+        removed
+    }
+
+    /// Adds the addresses of the other set which are within this set block.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::{MacAddr6, MacSet};
+    /// let mut left = MacSet::new("AC:DE:48:00:00:00/40".parse().unwrap());
+    /// left.insert(MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x00, 0x01));
+    ///
+    /// let mut right = MacSet::new("AC:DE:48:00:00:00/32".parse().unwrap());
+    /// right.insert(MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x00, 0x02));
+    /// right.insert(MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22));
+    ///
+    /// left.union_with(&right);
+    /// assert_eq!(left.len(), 2);
+    /// assert!(left.contains(&MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x00, 0x02)));
+    /// ```
+    pub fn union_with(&mut self, other: &MacSet) {
+        if self.prefix == other.prefix {
+            self.bits.iter_mut().zip(&other.bits).for_each(|(word, other)| *word |= other);
+        } else {
+            self.extend(other);
+        }
+    }
+
+    /// Keeps only the addresses which are also contained in the other set.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::{MacAddr6, MacSet};
+    /// let mut left = MacSet::new("AC:DE:48:00:00:00/40".parse().unwrap());
+    /// left.insert(MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x00, 0x01));
+    /// left.insert(MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x00, 0x02));
+    ///
+    /// let mut right = MacSet::new("AC:DE:48:00:00:00/40".parse().unwrap());
+    /// right.insert(MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x00, 0x02));
+    ///
+    /// left.intersect_with(&right);
+    /// assert_eq!(left.iter().collect::<Vec<_>>(), [MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x00, 0x02)]);
+    /// ```
+    pub fn intersect_with(&mut self, other: &MacSet) {
+        if self.prefix == other.prefix {
+            self.bits.iter_mut().zip(&other.bits).for_each(|(word, other)| *word &= other);
+            return;
+        }
+
+        let first = self.prefix.first().to_u64();
+        for (idx, word) in self.bits.iter_mut().enumerate() {
+            let mut rest = *word;
+            while rest != 0 {
+                let bit = rest & rest.wrapping_neg();
+                rest ^= bit;
+
+                let offset = idx as u64 * u64::from(u64::BITS) + u64::from(bit.trailing_zeros());
+                if !MacAddr6::from_u64(first + offset).is_some_and(|addr| other.contains(&addr)) {
+                    *word ^= bit;
+                }
+            }
+        }
+    }
+
+    /// Returns an iterator over the set addresses, in the ascending order.
+    pub fn iter(&self) -> MacSetIter<'_> {
+        MacSetIter {
+            first: self.prefix.first().to_u64(),
+            bits: &self.bits,
+            idx: 0,
+            word: self.bits[0],
+        }
+    }
+
+    /// Returns the bitmap word index and the bit mask of the address, or `None` if it is outside of the block.
+    fn position(&self, addr: &MacAddr6) -> Option<(usize, u64)> {
+        if !self.prefix.contains(addr) {
+            return None;
+        }
+
+        let offset = addr.to_u64() - self.prefix.first().to_u64();
+        Some(((offset / u64::from(u64::BITS)) as usize, 1 << (offset % u64::from(u64::BITS))))
+    }
+}
+
+/// `MacSet` is formatted as the set of its addresses.
+impl fmt::Debug for MacSet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+/// Extends the set with the addresses which are within its block, ignoring the other ones.
+impl Extend<MacAddr6> for MacSet {
+    fn extend<T: IntoIterator<Item = MacAddr6>>(&mut self, iter: T) {
+        iter.into_iter().for_each(|addr| {
+            self.insert(addr);
+        });
+    }
+}
+
+impl<'a> IntoIterator for &'a MacSet {
+    type Item = MacAddr6;
+    type IntoIter = MacSetIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the addresses of the [MacSet].
+///
+/// It is returned by the [MacSet::iter] method.
+///
+/// [MacSet]: struct.MacSet.html
+/// [MacSet::iter]: struct.MacSet.html#method.iter
+#[derive(Debug, Clone)]
+pub struct MacSetIter<'a> {
+    first: u64,
+    bits: &'a [u64],
+    idx: usize,
+    /// Remaining bits of the current word.
+    word: u64,
+}
+
+impl Iterator for MacSetIter<'_> {
+    type Item = MacAddr6;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.word == 0 {
+            self.idx += 1;
+            self.word = *self.bits.get(self.idx)?;
+        }
+
+        let offset = self.idx as u64 * u64::from(u64::BITS) + u64::from(self.word.trailing_zeros());
+        self.word &= self.word - 1;
+
+        MacAddr6::from_u64(self.first + offset)
+    }
+}
+
+impl FusedIterator for MacSetIter<'_> {}
@@ -0,0 +1,154 @@
+//! [sqlx] `Type`, `Encode` and `Decode` implementations.
+//!
+//! Addresses are stored in the native `MACADDR` and `MACADDR8` columns with PostgreSQL,
+//! and as the text in the colon-separated notation with MySQL and SQLite,
+//! where any notation accepted by the `FromStr` implementations or the raw bytes blobs
+//! are decoded too.
+//!
+//! [sqlx]: https://docs.rs/sqlx
+
+#[cfg(any(feature = "sqlx-mysql", feature = "sqlx-sqlite"))]
+use core::convert::TryFrom;
+
+#[cfg(any(feature = "sqlx-mysql", feature = "sqlx-sqlite"))]
+use ::sqlx::{encode::IsNull, error::BoxDynError, Database, Decode, Encode, Type};
+
+#[cfg(any(feature = "sqlx-mysql", feature = "sqlx-sqlite"))]
+use crate::{MacAddr, MacAddr6, MacAddr8};
+
+#[cfg(feature = "sqlx-postgres")]
+mod postgres {
+    use core::convert::TryFrom;
+
+    use ::sqlx::{
+        encode::IsNull,
+        error::BoxDynError,
+        postgres::{types::Oid, PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueFormat, PgValueRef, Postgres},
+        Decode, Encode, Type,
+    };
+
+    use crate::{MacAddr, MacAddr6, MacAddr8};
+
+    const MACADDR: PgTypeInfo = PgTypeInfo::with_oid(Oid(829));
+    const MACADDR_ARRAY: PgTypeInfo = PgTypeInfo::with_oid(Oid(1040));
+    const MACADDR8: PgTypeInfo = PgTypeInfo::with_oid(Oid(774));
+    const MACADDR8_ARRAY: PgTypeInfo = PgTypeInfo::with_oid(Oid(775));
+
+    macro_rules! impl_postgres {
+        ($name:ident, $type_info:expr, $array_type_info:expr) => {
+            impl Type<Postgres> for $name {
+                fn type_info() -> PgTypeInfo {
+                    $type_info
+                }
+            }
+
+            impl PgHasArrayType for $name {
+                fn array_type_info() -> PgTypeInfo {
+                    $array_type_info
+                }
+            }
+
+            impl Encode<'_, Postgres> for $name {
+                fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+                    buf.extend_from_slice(self.as_bytes());
+
+                    Ok(IsNull::No)
+                }
+            }
+
+            impl Decode<'_, Postgres> for $name {
+                fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+                    match value.format() {
+                        PgValueFormat::Binary => Ok($name::try_from(value.as_bytes()?)?),
+                        PgValueFormat::Text => Ok(value.as_str()?.parse()?),
+                    }
+                }
+            }
+        };
+    }
+
+    impl_postgres!(MacAddr6, MACADDR, MACADDR_ARRAY);
+    impl_postgres!(MacAddr8, MACADDR8, MACADDR8_ARRAY);
+
+    /// `MacAddr` is declared as the `MACADDR8` type, but the `V6` addresses are bound as the `MACADDR` values.
+    impl Type<Postgres> for MacAddr {
+        fn type_info() -> PgTypeInfo {
+            MACADDR8
+        }
+
+        fn compatible(ty: &PgTypeInfo) -> bool {
+            *ty == MACADDR || *ty == MACADDR8
+        }
+    }
+
+    impl Encode<'_, Postgres> for MacAddr {
+        fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+            buf.extend_from_slice(self.as_bytes());
+
+            Ok(IsNull::No)
+        }
+
+        fn produces(&self) -> Option<PgTypeInfo> {
+            match self {
+                MacAddr::V6(..) => Some(MACADDR),
+                MacAddr::V8(..) => Some(MACADDR8),
+            }
+        }
+    }
+
+    impl Decode<'_, Postgres> for MacAddr {
+        fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+            // Variant is detected from the value length, as with the byte slices.
+            match value.format() {
+                PgValueFormat::Binary => Ok(MacAddr::try_from(value.as_bytes()?)?),
+                PgValueFormat::Text => Ok(value.as_str()?.parse()?),
+            }
+        }
+    }
+}
+
+/// Implements the text representation of the address for the database.
+#[cfg(any(feature = "sqlx-mysql", feature = "sqlx-sqlite"))]
+macro_rules! impl_text {
+    ($db:ty, $name:ident) => {
+        impl Type<$db> for $name {
+            fn type_info() -> <$db as Database>::TypeInfo {
+                <str as Type<$db>>::type_info()
+            }
+
+            fn compatible(ty: &<$db as Database>::TypeInfo) -> bool {
+                <str as Type<$db>>::compatible(ty) || <[u8] as Type<$db>>::compatible(ty)
+            }
+        }
+
+        impl<'q> Encode<'q, $db> for $name {
+            fn encode_by_ref(&self, buf: &mut <$db as Database>::ArgumentBuffer<'q>) -> Result<IsNull, BoxDynError> {
+                <String as Encode<'q, $db>>::encode(self.to_string(), buf)
+            }
+        }
+
+        impl<'r> Decode<'r, $db> for $name {
+            fn decode(value: <$db as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+                // Text values are decodable as the bytes too, and no textual notation is long enough
+                // to be confused with the raw address bytes.
+                let bytes = <&[u8] as Decode<'r, $db>>::decode(value)?;
+
+                Ok($name::try_from(bytes).or_else(|_| $name::parse_ascii(bytes))?)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "sqlx-mysql")]
+impl_text!(::sqlx::MySql, MacAddr6);
+#[cfg(feature = "sqlx-mysql")]
+impl_text!(::sqlx::MySql, MacAddr8);
+#[cfg(feature = "sqlx-mysql")]
+impl_text!(::sqlx::MySql, MacAddr);
+
+#[cfg(feature = "sqlx-sqlite")]
+impl_text!(::sqlx::Sqlite, MacAddr6);
+#[cfg(feature = "sqlx-sqlite")]
+impl_text!(::sqlx::Sqlite, MacAddr8);
+#[cfg(feature = "sqlx-sqlite")]
+impl_text!(::sqlx::Sqlite, MacAddr);
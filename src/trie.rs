@@ -0,0 +1,299 @@
+use alloc::{boxed::Box, vec::Vec};
+use core::{
+    fmt,
+    iter::{FromIterator, FusedIterator},
+};
+
+use crate::{MacAddr6, MacPrefix};
+
+/// Map keyed by the [MacPrefix] blocks, stored as a binary trie of the prefix bits,
+/// which finds the most specific block containing the address, as the IP routing tables do.
+///
+/// ## Example
+///
+/// ```rust
+/// # use macaddr::{MacAddr6, MacPrefix, MacTrie};
+/// let mut rules = MacTrie::new();
+/// rules.insert("AC:DE:48:00:00:00/24".parse().unwrap(), "vendor");
+/// rules.insert("AC:DE:48:10:00:00/28".parse().unwrap(), "lab");
+///
+/// let addr = MacAddr6::new(0xAC, 0xDE, 0x48, 0x10, 0x11, 0x22);
+/// assert_eq!(rules.longest_match(&addr), Some(("AC:DE:48:10:00:00/28".parse().unwrap(), &"lab")));
+///
+/// let addr = MacAddr6::new(0xAC, 0xDE, 0x48, 0x20, 0x11, 0x22);
+/// assert_eq!(rules.longest_match(&addr).map(|(_, rule)| *rule), Some("vendor"));
+/// assert_eq!(rules.longest_match(&MacAddr6::broadcast()), None);
+/// ```
+///
+/// [MacPrefix]: struct.MacPrefix.html
+#[derive(Clone, Eq, PartialEq)]
+pub struct MacTrie<V> {
+    root: Node<V>,
+    len: usize,
+}
+
+#[derive(Clone, Eq, PartialEq)]
+struct Node<V> {
+    value: Option<V>,
+    children: [Option<Box<Node<V>>>; 2],
+}
+
+impl<V> Node<V> {
+    const fn new() -> Node<V> {
+        Node {
+            value: None,
+            children: [None, None],
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.value.is_none() && self.children.iter().all(Option::is_none)
+    }
+
+    /// Removes the value of the prefix below this node, pruning the emptied nodes.
+    fn remove(&mut self, prefix: &MacPrefix, depth: u8) -> Option<V> {
+        if depth == prefix.prefix_len() {
+            return self.value.take();
+        }
+
+        let branch = &mut self.children[bit(&prefix.first(), depth)];
+        let value = branch.as_mut()?.remove(prefix, depth + 1);
+        if branch.as_ref().is_some_and(|child| child.is_empty()) {
+            *branch = None;
+        }
+
+        value
+    }
+}
+
+impl<V> MacTrie<V> {
+    /// Creates an empty `MacTrie`.
+    pub const fn new() -> MacTrie<V> {
+        MacTrie {
+            root: Node::new(),
+            len: 0,
+        }
+    }
+
+    /// Returns the number of the prefixes in the map.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the map contains no prefixes.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Removes all the prefixes from the map.
+    pub fn clear(&mut self) {
+        *self = MacTrie::new();
+    }
+
+    /// Inserts the value of the prefix, returning the previous one if the prefix was already present.
+    pub fn insert(&mut self, prefix: MacPrefix, value: V) -> Option<V> {
+        let first = prefix.first();
+        let mut node = &mut self.root;
+        for depth in 0..prefix.prefix_len() {
+            node = node.children[bit(&first, depth)].get_or_insert_with(|| Box::new(Node::new()));
+        }
+
+        let previous = node.value.replace(value);
+        if previous.is_none() {
+            self.len += 1;
+        }
+
+        previous
+    }
+
+    /// Returns the value of the exact prefix.
+    pub fn get(&self, prefix: &MacPrefix) -> Option<&V> {
+        let first = prefix.first();
+        let mut node = &self.root;
+        for depth in 0..prefix.prefix_len() {
+            node = node.children[bit(&first, depth)].as_deref()?;
+        }
+
+        node.value.as_ref()
+    }
+
+    /// Returns the mutable reference to the value of the exact prefix.
+    pub fn get_mut(&mut self, prefix: &MacPrefix) -> Option<&mut V> {
+        let first = prefix.first();
+        let mut node = &mut self.root;
+        for depth in 0..prefix.prefix_len() {
+            node = node.children[bit(&first, depth)].as_deref_mut()?;
+        }
+
+        node.value.as_mut()
+    }
+
+    /// Returns `true` if the map contains the exact prefix.
+    pub fn contains_prefix(&self, prefix: &MacPrefix) -> bool {
+        self.get(prefix).is_some()
+    }
+
+    /// Removes the exact prefix from the map, returning its value.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::{MacAddr6, MacPrefix, MacTrie};
+    /// let prefix: MacPrefix = "AC:DE:48:00:00:00/24".parse().unwrap();
+    /// let mut trie = MacTrie::new();
+    /// trie.insert(prefix, 1);
+    ///
+    /// assert_eq!(trie.remove(&"AC:DE:48:00:00:00/28".parse().unwrap()), None);
+    /// assert_eq!(trie.remove(&prefix), Some(1));
+    /// assert!(trie.is_empty());
+    /// ```
+    pub fn remove(&mut self, prefix: &MacPrefix) -> Option<V> {
+        let value = self.root.remove(prefix, 0);
+        if value.is_some() {
+            self.len -= 1;
+        }
+
+        value
+    }
+
+    /// Returns the longest prefix containing the address along with its value,
+    /// or `None` if none of the prefixes contains it.
+    pub fn longest_match(&self, addr: &MacAddr6) -> Option<(MacPrefix, &V)> {
+        let mut node = &self.root;
+        let mut found = node.value.as_ref().map(|value| (0, value));
+
+        for depth in 0..48 {
+            node = match node.children[bit(addr, depth)].as_deref() {
+                Some(child) => child,
+                None => break,
+            };
+            if let Some(value) = &node.value {
+                found = Some((depth + 1, value));
+            }
+        }
+
+        found.and_then(|(len, value)| Some((MacPrefix::new(*addr, len)?, value)))
+    }
+
+    /// Returns an iterator over the prefixes and their values, in the ascending prefixes order.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::{MacPrefix, MacTrie};
+    /// let trie = ["AC:DE:48:00:00:00/28", "AC:DE:48:00:00:00/24", "12:34:56:00:00:00/24"]
+    ///     .iter()
+    ///     .map(|prefix| (prefix.parse::<MacPrefix>().unwrap(), ()))
+    ///     .collect::<MacTrie<_>>();
+    ///
+    /// let prefixes = trie.iter().map(|(prefix, _)| prefix.to_string()).collect::<Vec<_>>();
+    /// assert_eq!(prefixes, ["12:34:56:00:00:00/24", "AC:DE:48:00:00:00/24", "AC:DE:48:00:00:00/28"]);
+    /// ```
+    pub fn iter(&self) -> MacTrieIter<'_, V> {
+        MacTrieIter {
+            stack: alloc::vec![(&self.root, 0, 0)],
+            len: self.len,
+        }
+    }
+}
+
+/// Returns the address bit at the depth, counting from the most significant one.
+fn bit(addr: &MacAddr6, depth: u8) -> usize {
+    (addr.to_u64() >> (47 - depth) & 1) as usize
+}
+
+impl<V> Default for MacTrie<V> {
+    fn default() -> Self {
+        MacTrie::new()
+    }
+}
+
+/// `MacTrie` is formatted as the map of its prefixes.
+impl<V: fmt::Debug> fmt::Debug for MacTrie<V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<V> Extend<(MacPrefix, V)> for MacTrie<V> {
+    fn extend<T: IntoIterator<Item = (MacPrefix, V)>>(&mut self, iter: T) {
+        iter.into_iter().for_each(|(prefix, value)| {
+            self.insert(prefix, value);
+        });
+    }
+}
+
+impl<V> FromIterator<(MacPrefix, V)> for MacTrie<V> {
+    fn from_iter<T: IntoIterator<Item = (MacPrefix, V)>>(iter: T) -> Self {
+        let mut trie = MacTrie::new();
+        trie.extend(iter);
+        trie
+    }
+}
+
+impl<'a, V> IntoIterator for &'a MacTrie<V> {
+    type Item = (MacPrefix, &'a V);
+    type IntoIter = MacTrieIter<'a, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the prefixes and the values of the [MacTrie].
+///
+/// It is returned by the [MacTrie::iter] method.
+///
+/// [MacTrie]: struct.MacTrie.html
+/// [MacTrie::iter]: struct.MacTrie.html#method.iter
+pub struct MacTrieIter<'a, V> {
+    /// Nodes to visit along with their prefix bits and depth, the next one on the top.
+    stack: Vec<(&'a Node<V>, u64, u8)>,
+    len: usize,
+}
+
+impl<'a, V> Iterator for MacTrieIter<'a, V> {
+    type Item = (MacPrefix, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((node, bits, depth)) = self.stack.pop() {
+            // Children are visited after the node itself, the zero bit branch first.
+            for (branch, child) in node.children.iter().enumerate().rev() {
+                if let Some(child) = child {
+                    self.stack.push((child, bits | (branch as u64) << (47 - depth), depth + 1));
+                }
+            }
+
+            let prefix = MacAddr6::from_u64(bits).and_then(|addr| MacPrefix::new(addr, depth));
+            if let (Some(prefix), Some(value)) = (prefix, &node.value) {
+                self.len -= 1;
+                return Some((prefix, value));
+            }
+        }
+
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<V> ExactSizeIterator for MacTrieIter<'_, V> {}
+
+impl<V> FusedIterator for MacTrieIter<'_, V> {}
+
+impl<V> Clone for MacTrieIter<'_, V> {
+    fn clone(&self) -> Self {
+        MacTrieIter {
+            stack: self.stack.clone(),
+            len: self.len,
+        }
+    }
+}
+
+impl<V> fmt::Debug for MacTrieIter<'_, V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("MacTrieIter").field("len", &self.len).finish()
+    }
+}
@@ -0,0 +1,125 @@
+//! IEEE 802.11 randomized address policies.
+//!
+//! Wi-Fi stations of the modern operating systems hide their hardware addresses
+//! behind the locally administered unicast ones, which are either:
+//!
+//! * generated randomly for every connection or on a schedule, as the iOS rotating Private Wi-Fi Address,
+//!   the Android non-persistent randomization and the Windows random hardware addresses changing daily;
+//!   see the `MacAddr6::random_local_unicast` method with the `"rand"` feature;
+//! * derived from the device secret and the network name, so the station keeps the same address
+//!   for the same network but can't be tracked across the networks, as the Android persistent randomization,
+//!   the iOS fixed Private Wi-Fi Address and the Windows per-network random addresses;
+//!   see the [`derive_per_ssid`] function.
+//!
+//! Some older drivers and tools were randomizing only the NIC-specific part of the address,
+//! see the `MacAddr6::randomize_preserving_oui` method with the `"rand"` feature.
+//!
+//! ## Example
+//!
+//! ```rust
+//! # use macaddr::{wifi, MacAddr6};
+//! let secret = [0x5A; 16];
+//! let addr = wifi::derive_per_ssid(&secret, b"CoffeeShop");
+//!
+//! assert!(wifi::is_randomized(&addr));
+//! assert_eq!(addr, wifi::derive_per_ssid(&secret, b"CoffeeShop"));
+//! assert_ne!(addr, wifi::derive_per_ssid(&secret, b"Airport"));
+//! assert!(!wifi::is_randomized(&MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22)));
+//! ```
+//!
+//! [`derive_per_ssid`]: fn.derive_per_ssid.html
+
+use crate::MacAddr6;
+
+/// Returns `true` if the address could be a randomized station address, which is the case
+/// for all the locally administered unicast addresses, same as the [`MacAddr6::is_randomized`] method.
+///
+/// None of the operating systems mark the randomized addresses in any other way, so the address
+/// assigned by the network administrator is indistinguishable from the randomized one.
+///
+/// [`MacAddr6::is_randomized`]: ../struct.MacAddr6.html#method.is_randomized
+#[allow(clippy::trivially_copy_pass_by_ref)]
+pub const fn is_randomized(addr: &MacAddr6) -> bool {
+    addr.is_randomized()
+}
+
+/// Derives the stable locally administered unicast station address for the network with the `ssid` name
+/// from the 128-bit device `secret`.
+///
+/// The SSID is hashed with the SipHash-2-4 keyed function, so the addresses of the different networks
+/// can't be correlated without the secret, and the same inputs always result in the same address,
+/// across the platforms and the crate versions.
+///
+/// ## Example
+///
+/// ```rust
+/// # use macaddr::{wifi, MacAddr6};
+/// let addr = wifi::derive_per_ssid(&[0; 16], b"CoffeeShop");
+///
+/// assert_eq!(addr, MacAddr6::new(0xB2, 0xB5, 0x55, 0x1A, 0x3D, 0x12));
+/// assert_ne!(addr, wifi::derive_per_ssid(&[1; 16], b"CoffeeShop"));
+/// ```
+pub const fn derive_per_ssid(secret: &[u8; 16], ssid: &[u8]) -> MacAddr6 {
+    let [a, b, c, d, e, f, _, _] = siphash24(secret, ssid).to_be_bytes();
+
+    MacAddr6::new(a & !1 | 1 << 1, b, c, d, e, f)
+}
+
+/// Computes the SipHash-2-4 of the message with the key.
+const fn siphash24(key: &[u8; 16], msg: &[u8]) -> u64 {
+    let k0 = read_u64(key, 0, 8);
+    let k1 = read_u64(key, 8, 8);
+    let mut v = [
+        k0 ^ 0x736F_6D65_7073_6575,
+        k1 ^ 0x646F_7261_6E64_6F6D,
+        k0 ^ 0x6C79_6765_6E65_7261,
+        k1 ^ 0x7465_6462_7974_6573,
+    ];
+
+    let mut pos = 0;
+    while pos + 8 <= msg.len() {
+        let word = read_u64(msg, pos, 8);
+        v[3] ^= word;
+        v = sip_round(sip_round(v));
+        v[0] ^= word;
+        pos += 8;
+    }
+
+    // Last word holds the remaining bytes and the message length in its high byte.
+    let word = read_u64(msg, pos, msg.len() - pos) | (msg.len() as u64) << 56;
+    v[3] ^= word;
+    v = sip_round(sip_round(v));
+    v[0] ^= word;
+
+    v[2] ^= 0xFF;
+    v = sip_round(sip_round(sip_round(sip_round(v))));
+
+    v[0] ^ v[1] ^ v[2] ^ v[3]
+}
+
+/// Reads up to 8 bytes at the position as the little-endian integer.
+const fn read_u64(bytes: &[u8], pos: usize, len: usize) -> u64 {
+    let mut value = 0;
+    let mut idx = 0;
+    while idx < len {
+        value |= (bytes[pos + idx] as u64) << (8 * idx);
+        idx += 1;
+    }
+
+    value
+}
+
+const fn sip_round([mut v0, mut v1, mut v2, mut v3]: [u64; 4]) -> [u64; 4] {
+    v0 = v0.wrapping_add(v1);
+    v1 = v1.rotate_left(13) ^ v0;
+    v0 = v0.rotate_left(32);
+    v2 = v2.wrapping_add(v3);
+    v3 = v3.rotate_left(16) ^ v2;
+    v0 = v0.wrapping_add(v3);
+    v3 = v3.rotate_left(21) ^ v0;
+    v2 = v2.wrapping_add(v1);
+    v1 = v1.rotate_left(17) ^ v2;
+    v2 = v2.rotate_left(32);
+
+    [v0, v1, v2, v3]
+}
@@ -0,0 +1,150 @@
+//! Wake-on-LAN magic packets.
+//!
+//! The magic packet is made of six `FF` bytes followed by sixteen repetitions of the target address,
+//! optionally followed by the six bytes of the SecureOn password. It is usually sent as the payload
+//! of the UDP datagram to the broadcast address and the [`PORT`] 9, or as the payload of the Ethernet frame
+//! with the `0x0842` EtherType.
+//!
+//! ## Example
+//!
+//! ```rust
+//! # use macaddr::{wol::MagicPacket, MacAddr6};
+//! let addr = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22);
+//! let packet = MagicPacket::new(addr);
+//!
+//! assert_eq!(packet.as_bytes().len(), 102);
+//! assert_eq!(&packet.as_bytes()[..6], &[0xFF; 6]);
+//! assert_eq!(&packet.as_bytes()[96..], addr.as_bytes());
+//! ```
+//!
+//! [`PORT`]: constant.PORT.html
+
+#[cfg(feature = "std")]
+use std::{
+    io,
+    net::{ToSocketAddrs, UdpSocket},
+};
+
+use crate::MacAddr6;
+
+/// Discard UDP port the magic packets are conventionally sent to.
+pub const PORT: u16 = 9;
+
+/// Number of the target address repetitions in the magic packet.
+const REPETITIONS: usize = 16;
+
+/// Length of the magic packet without the password.
+const LEN: usize = 6 + 6 * REPETITIONS;
+
+/// Wake-on-LAN magic packet waking up the network interface with the target address.
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
+pub struct MagicPacket {
+    bytes: [u8; LEN + 6],
+    has_password: bool,
+}
+
+impl MagicPacket {
+    /// Creates a new `MagicPacket` for the target address.
+    pub const fn new(target: MacAddr6) -> MagicPacket {
+        let mut bytes = [0xFF; LEN + 6];
+        let addr = target.into_array();
+
+        let mut idx = 6;
+        while idx < LEN {
+            bytes[idx] = addr[(idx - 6) % 6];
+            idx += 1;
+        }
+
+        MagicPacket {
+            bytes,
+            has_password: false,
+        }
+    }
+
+    /// Appends the SecureOn password, which is usually written in the address notation,
+    /// as for the `ethtool -s eth0 wol s sopass 01:02:03:04:05:06` command.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use macaddr::{wol::MagicPacket, MacAddr6};
+    /// let password = MacAddr6::new(0x01, 0x02, 0x03, 0x04, 0x05, 0x06);
+    /// let packet = MagicPacket::new(MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22)).with_password(password);
+    ///
+    /// assert_eq!(packet.as_bytes().len(), 108);
+    /// assert_eq!(&packet.as_bytes()[102..], password.as_bytes());
+    /// assert_eq!(packet.password(), Some(password));
+    /// ```
+    pub const fn with_password(mut self, password: MacAddr6) -> MagicPacket {
+        let password = password.into_array();
+
+        let mut idx = 0;
+        while idx < password.len() {
+            self.bytes[LEN + idx] = password[idx];
+            idx += 1;
+        }
+
+        self.has_password = true;
+        self
+    }
+
+    /// Returns the address of the interface the packet wakes up.
+    pub const fn target(&self) -> MacAddr6 {
+        let [_, _, _, _, _, _, a, b, c, d, e, f, ..] = self.bytes;
+
+        MacAddr6::new(a, b, c, d, e, f)
+    }
+
+    /// Returns the SecureOn password, if any.
+    pub const fn password(&self) -> Option<MacAddr6> {
+        if !self.has_password {
+            return None;
+        }
+
+        let [.., a, b, c, d, e, f] = self.bytes;
+        Some(MacAddr6::new(a, b, c, d, e, f))
+    }
+
+    /// Returns the packet payload, which is 102 bytes long, or 108 bytes long with the password.
+    pub fn as_bytes(&self) -> &[u8] {
+        if self.has_password {
+            &self.bytes
+        } else {
+            &self.bytes[..LEN]
+        }
+    }
+
+    /// Sends the packet as the UDP datagram payload from the socket to the address,
+    /// returning the number of the bytes sent.
+    ///
+    /// Broadcast addresses, such as `255.255.255.255:9`, require the socket broadcast option to be enabled.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// # use macaddr::{wol::{self, MagicPacket}, MacAddr6};
+    /// # use std::net::{Ipv4Addr, UdpSocket};
+    /// let socket = UdpSocket::bind((Ipv4Addr::UNSPECIFIED, 0))?;
+    /// socket.set_broadcast(true)?;
+    ///
+    /// let packet = MagicPacket::new(MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22));
+    /// packet.send_to(&socket, (Ipv4Addr::BROADCAST, wol::PORT))?;
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn send_to<A: ToSocketAddrs>(&self, socket: &UdpSocket, addr: A) -> io::Result<usize> {
+        socket.send_to(self.as_bytes(), addr)
+    }
+}
+
+impl From<MacAddr6> for MagicPacket {
+    fn from(target: MacAddr6) -> Self {
+        MagicPacket::new(target)
+    }
+}
+
+impl AsRef<[u8]> for MagicPacket {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
@@ -0,0 +1,38 @@
+#![cfg(feature = "arbitrary")]
+
+use arbitrary::{Arbitrary, Unstructured};
+use macaddr::{MacAddr, MacAddr6, MacAddr8};
+
+#[test]
+fn test_arbitrary_v6() {
+    let mut u = Unstructured::new(&[0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33]);
+
+    assert_eq!(MacAddr6::arbitrary(&mut u).unwrap(), MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22));
+    assert_eq!(u.len(), 1);
+}
+
+#[test]
+fn test_arbitrary_v8() {
+    let mut u = Unstructured::new(&[0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44]);
+
+    assert_eq!(
+        MacAddr8::arbitrary(&mut u).unwrap(),
+        MacAddr8::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44)
+    );
+    assert!(u.is_empty());
+}
+
+#[test]
+fn test_arbitrary() {
+    let mut u = Unstructured::new(&[0x00, 0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22]);
+    assert_eq!(
+        MacAddr::arbitrary(&mut u).unwrap(),
+        MacAddr::from([0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22])
+    );
+
+    let mut u = Unstructured::new(&[0x01, 0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44]);
+    assert_eq!(
+        MacAddr::arbitrary(&mut u).unwrap(),
+        MacAddr::from([0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44])
+    );
+}
@@ -0,0 +1,54 @@
+#![cfg(feature = "arrow")]
+
+use arrow_array::{Array, FixedSizeBinaryArray};
+use arrow_schema::DataType;
+use macaddr::{MacAddr6, MacAddr8, arrow};
+
+#[test]
+fn test_arrow_v6_roundtrip() {
+    let addrs = [MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22), MacAddr6::broadcast()];
+    let array = arrow::to_array(addrs.iter().copied());
+
+    assert_eq!(array.data_type(), &DataType::FixedSizeBinary(6));
+    assert_eq!(array.value(0), &[0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22]);
+    assert_eq!(array.null_count(), 0);
+
+    let decoded = arrow::from_array::<MacAddr6>(&array).unwrap().collect::<Vec<_>>();
+    assert_eq!(decoded, vec![Some(addrs[0]), Some(addrs[1])]);
+}
+
+#[test]
+fn test_arrow_v8_nullable() {
+    let addr = MacAddr8::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44);
+    let array = arrow::to_nullable_array(vec![None, Some(addr), None]);
+
+    assert_eq!(array.data_type(), &DataType::FixedSizeBinary(8));
+    assert_eq!(array.len(), 3);
+    assert_eq!(array.null_count(), 2);
+
+    let decoded = arrow::from_array::<MacAddr8>(&array).unwrap().collect::<Vec<_>>();
+    assert_eq!(decoded, vec![None, Some(addr), None]);
+}
+
+#[test]
+fn test_arrow_width_mismatch() {
+    let array = arrow::to_array(vec![MacAddr8::nil()]);
+
+    assert!(arrow::from_array::<MacAddr6>(&array).is_err());
+
+    let array = FixedSizeBinaryArray::try_from_iter(vec![[0u8; 4]].into_iter()).unwrap();
+    assert!(arrow::from_array::<MacAddr8>(&array).is_err());
+}
+
+#[test]
+fn test_arrow_field() {
+    let field = arrow::field::<MacAddr8>("addr", true);
+
+    assert_eq!(field.name(), "addr");
+    assert_eq!(field.data_type(), &arrow::data_type::<MacAddr8>());
+    assert!(field.is_nullable());
+    assert_eq!(
+        field.metadata().get(arrow::EXTENSION_NAME_KEY).map(String::as_str),
+        Some("macaddr.eui64")
+    );
+}
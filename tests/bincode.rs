@@ -0,0 +1,52 @@
+#![cfg(feature = "bincode")]
+
+use macaddr::{MacAddr, MacAddr6, MacAddr8};
+
+fn encode<T: bincode::Encode>(value: &T, buf: &mut [u8]) -> usize {
+    bincode::encode_into_slice(value, buf, bincode::config::standard()).unwrap()
+}
+
+fn decode<T: bincode::Decode<()>>(buf: &[u8]) -> Result<T, bincode::error::DecodeError> {
+    bincode::decode_from_slice(buf, bincode::config::standard()).map(|(value, _)| value)
+}
+
+#[test]
+fn test_bincode_v6() {
+    let addr = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22);
+
+    let mut buf = [0; 16];
+    let len = encode(&addr, &mut buf);
+    assert_eq!(&buf[..len], [0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22]);
+    assert_eq!(decode::<MacAddr6>(&buf[..len]).unwrap(), addr);
+}
+
+#[test]
+fn test_bincode_v8() {
+    let addr = MacAddr8::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44);
+
+    let mut buf = [0; 16];
+    let len = encode(&addr, &mut buf);
+    assert_eq!(&buf[..len], [0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44]);
+    assert_eq!(decode::<MacAddr8>(&buf[..len]).unwrap(), addr);
+}
+
+#[test]
+fn test_bincode() {
+    let mut buf = [0; 16];
+
+    let addr = MacAddr::from([0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22]);
+    let len = encode(&addr, &mut buf);
+    assert_eq!(&buf[..len], [0x00, 0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22]);
+    assert_eq!(decode::<MacAddr>(&buf[..len]).unwrap(), addr);
+
+    let addr = MacAddr::from([0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44]);
+    let len = encode(&addr, &mut buf);
+    assert_eq!(&buf[..len], [0x01, 0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44]);
+    assert_eq!(decode::<MacAddr>(&buf[..len]).unwrap(), addr);
+}
+
+#[test]
+fn test_bincode_invalid() {
+    assert!(decode::<MacAddr6>(&[0xAC, 0xDE, 0x48, 0x00, 0x11]).is_err());
+    assert!(decode::<MacAddr>(&[0x02, 0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22]).is_err());
+}
@@ -0,0 +1,41 @@
+#![cfg(feature = "borsh")]
+
+use macaddr::{MacAddr, MacAddr6, MacAddr8};
+
+#[test]
+fn test_borsh_v6() {
+    let addr = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22);
+
+    let bytes = borsh::to_vec(&addr).unwrap();
+    assert_eq!(bytes, [0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22]);
+    assert_eq!(borsh::from_slice::<MacAddr6>(&bytes).unwrap(), addr);
+}
+
+#[test]
+fn test_borsh_v8() {
+    let addr = MacAddr8::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44);
+
+    let bytes = borsh::to_vec(&addr).unwrap();
+    assert_eq!(bytes, [0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44]);
+    assert_eq!(borsh::from_slice::<MacAddr8>(&bytes).unwrap(), addr);
+}
+
+#[test]
+fn test_borsh() {
+    let addr = MacAddr::from([0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22]);
+    let bytes = borsh::to_vec(&addr).unwrap();
+    assert_eq!(bytes, [0x00, 0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22]);
+    assert_eq!(borsh::from_slice::<MacAddr>(&bytes).unwrap(), addr);
+
+    let addr = MacAddr::from([0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44]);
+    let bytes = borsh::to_vec(&addr).unwrap();
+    assert_eq!(bytes, [0x01, 0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44]);
+    assert_eq!(borsh::from_slice::<MacAddr>(&bytes).unwrap(), addr);
+}
+
+#[test]
+fn test_borsh_invalid() {
+    assert!(borsh::from_slice::<MacAddr6>(&[0xAC, 0xDE, 0x48, 0x00, 0x11]).is_err());
+    assert!(borsh::from_slice::<MacAddr6>(&[0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33]).is_err());
+    assert!(borsh::from_slice::<MacAddr>(&[0x02, 0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22]).is_err());
+}
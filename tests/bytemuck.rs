@@ -0,0 +1,37 @@
+#![cfg(feature = "bytemuck")]
+
+use bytemuck::{Pod, Zeroable};
+use macaddr::{MacAddr6, MacAddr8};
+
+#[derive(Copy, Clone, Pod, Zeroable)]
+#[repr(C)]
+struct Descriptor {
+    addr: MacAddr6,
+    flags: [u8; 2],
+}
+
+#[test]
+fn test_bytemuck_cast_slice() {
+    let bytes = [0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0xAC, 0xDE, 0x48, 0x00, 0x11, 0x23];
+
+    let addrs: &[MacAddr6] = bytemuck::cast_slice(&bytes);
+    assert_eq!(
+        addrs,
+        [
+            MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22),
+            MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x23),
+        ]
+    );
+    assert_eq!(bytemuck::cast_slice::<MacAddr6, u8>(addrs), bytes);
+
+    assert!(bytemuck::try_cast_slice::<u8, MacAddr8>(&bytes).is_err());
+}
+
+#[test]
+fn test_bytemuck_struct() {
+    let descriptor = bytemuck::cast::<[u8; 8], Descriptor>([0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x80, 0x00]);
+    assert_eq!(descriptor.addr, MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22));
+    assert_eq!(descriptor.flags, [0x80, 0x00]);
+
+    assert_eq!(MacAddr8::zeroed(), MacAddr8::nil());
+}
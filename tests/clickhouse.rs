@@ -0,0 +1,70 @@
+#![cfg(feature = "clickhouse")]
+
+use macaddr::MacAddr6;
+use serde::{Deserialize, Serialize};
+use serde_test::{Token, assert_de_tokens_error, assert_tokens};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Flow {
+    #[serde(with = "macaddr::clickhouse::uint64")]
+    src: MacAddr6,
+    #[serde(with = "macaddr::clickhouse::fixed_string")]
+    dst: MacAddr6,
+    #[serde(with = "macaddr::clickhouse::uint64::option")]
+    gateway: Option<MacAddr6>,
+    #[serde(with = "macaddr::clickhouse::fixed_string::option")]
+    relay: Option<MacAddr6>,
+}
+
+#[test]
+fn test_clickhouse_row() {
+    let flow = Flow {
+        src: MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22),
+        dst: MacAddr6::new(0x01, 0x23, 0x45, 0x67, 0x89, 0xAB),
+        gateway: None,
+        relay: Some(MacAddr6::broadcast()),
+    };
+
+    let mut tokens = vec![
+        Token::Struct {
+            name: "Flow",
+            len: 4,
+        },
+        Token::Str("src"),
+        Token::U64(0xACDE48001122),
+        Token::Str("dst"),
+        Token::Tuple {
+            len: 6,
+        },
+    ];
+    tokens.extend([0x01, 0x23, 0x45, 0x67, 0x89, 0xAB].iter().map(|&byte| Token::U8(byte)));
+    tokens.extend(&[
+        Token::TupleEnd,
+        Token::Str("gateway"),
+        Token::None,
+        Token::Str("relay"),
+        Token::Some,
+        Token::Tuple {
+            len: 6,
+        },
+    ]);
+    tokens.extend([0xFF; 6].iter().map(|&byte| Token::U8(byte)));
+    tokens.extend(&[Token::TupleEnd, Token::StructEnd]);
+
+    assert_tokens(&flow, &tokens);
+}
+
+#[test]
+fn test_clickhouse_uint64_overflow() {
+    assert_de_tokens_error::<Flow>(
+        &[
+            Token::Struct {
+                name: "Flow",
+                len: 4,
+            },
+            Token::Str("src"),
+            Token::U64(0x01_0000_0000_0000),
+        ],
+        "invalid value: integer `281474976710656`, expected EUI-48 MAC address as an unsigned integer",
+    );
+}
@@ -0,0 +1,41 @@
+#![cfg(feature = "diesel")]
+
+use diesel::{debug_query, pg::Pg, prelude::*};
+use macaddr::MacAddr6;
+
+diesel::table! {
+    interfaces (id) {
+        id -> Integer,
+        addr -> MacAddr,
+    }
+}
+
+#[derive(Queryable, Insertable)]
+#[diesel(table_name = interfaces)]
+struct Interface {
+    id: i32,
+    addr: MacAddr6,
+}
+
+#[test]
+fn test_diesel_bind() {
+    let addr = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22);
+    let query = interfaces::table.filter(interfaces::addr.eq(addr)).select(interfaces::id);
+
+    assert_eq!(
+        debug_query::<Pg, _>(&query).to_string(),
+        "SELECT \"interfaces\".\"id\" FROM \"interfaces\" WHERE (\"interfaces\".\"addr\" = $1) \
+         -- binds: [MacAddr6(AC-DE-48-00-11-22)]"
+    );
+}
+
+#[test]
+fn test_diesel_insert() {
+    let interface = Interface {
+        id: 1,
+        addr: MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22),
+    };
+    let query = diesel::insert_into(interfaces::table).values(&interface);
+
+    assert!(debug_query::<Pg, _>(&query).to_string().ends_with("-- binds: [1, MacAddr6(AC-DE-48-00-11-22)]"));
+}
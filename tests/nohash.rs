@@ -0,0 +1,28 @@
+#![cfg(feature = "nohash-hasher")]
+
+use macaddr::{MacAddr6, MacAddr8};
+use nohash_hasher::{IntMap, IntSet};
+
+#[test]
+fn test_nohash_int_map_v6() {
+    let mut map = IntMap::default();
+    for idx in 0..1024 {
+        map.insert(MacAddr6::from_u64(0xACDE_4800_0000 + idx).unwrap(), idx);
+    }
+
+    assert_eq!(map.len(), 1024);
+    assert_eq!(
+        map.get(&MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x01, 0x00)),
+        Some(&0x100)
+    );
+    assert_eq!(map.get(&MacAddr6::broadcast()), None);
+}
+
+#[test]
+fn test_nohash_int_set_v8() {
+    let addr = MacAddr8::new(0xAC, 0xDE, 0x48, 0xFF, 0xFE, 0x00, 0x11, 0x22);
+    let set = [addr, addr, MacAddr8::nil()].iter().copied().collect::<IntSet<_>>();
+
+    assert_eq!(set.len(), 2);
+    assert!(set.contains(&addr));
+}
@@ -0,0 +1,125 @@
+#![cfg(feature = "alloc")]
+
+use macaddr::{MacAddr6, MacAddrPool};
+
+#[test]
+fn test_pool_allocate_until_exhausted() {
+    let mut pool = MacAddrPool::new("02:00:5E:10:00:00/44".parse().unwrap());
+
+    let addrs = core::iter::from_fn(|| pool.allocate()).collect::<Vec<_>>();
+    assert_eq!(addrs.len(), 16);
+    assert!(addrs.windows(2).all(|pair| pair[0] < pair[1]));
+    assert_eq!(pool.len(), 16);
+    assert_eq!(pool.allocate(), None);
+}
+
+#[test]
+fn test_pool_add_block() {
+    let mut pool = MacAddrPool::new("02:00:5E:10:00:00/48".parse().unwrap());
+    assert!(pool.allocate().is_some());
+    assert_eq!(pool.allocate(), None);
+
+    assert!(pool.add_block("02:00:5E:20:00:00/47".parse().unwrap()));
+    assert_eq!(pool.allocate(), Some(MacAddr6::new(0x02, 0x00, 0x5E, 0x20, 0x00, 0x00)));
+    assert_eq!(pool.allocate(), Some(MacAddr6::new(0x02, 0x00, 0x5E, 0x20, 0x00, 0x01)));
+    assert_eq!(pool.allocate(), None);
+}
+
+#[test]
+fn test_pool_release_and_reserve() {
+    let mut pool = MacAddrPool::new("02:00:5E:10:00:00/46".parse().unwrap());
+    let reserved = MacAddr6::new(0x02, 0x00, 0x5E, 0x10, 0x00, 0x01);
+    assert!(pool.reserve(reserved));
+    assert!(!pool.reserve(reserved));
+
+    let first = pool.allocate().unwrap();
+    let third = pool.allocate().unwrap();
+    assert_eq!(third, MacAddr6::new(0x02, 0x00, 0x5E, 0x10, 0x00, 0x02));
+
+    assert!(pool.release(&third));
+    assert!(pool.release(&first));
+    assert!(!pool.release(&first));
+    assert_eq!(pool.allocate(), Some(first));
+    assert_eq!(pool.allocate(), Some(third));
+    assert_eq!(pool.allocated().collect::<Vec<_>>(), [first, reserved, third]);
+}
+
+#[cfg(feature = "serde")]
+mod serde {
+    use macaddr::MacAddrPool;
+    use serde_test::{Configure, Readable, Token, assert_de_tokens_error, assert_tokens};
+
+    #[test]
+    fn test_pool_serde_roundtrip() {
+        let mut pool = MacAddrPool::new("02:00:5E:10:00:00/46".parse().unwrap());
+        let addr = pool.allocate().unwrap();
+        pool.allocate();
+        pool.release(&addr);
+
+        assert_tokens(
+            &pool.clone().readable(),
+            &[
+                Token::Struct {
+                    name: "MacAddrPool",
+                    len: 4,
+                },
+                Token::Str("blocks"),
+                Token::Seq {
+                    len: Some(1),
+                },
+                Token::Str("02:00:5E:10:00:00/46"),
+                Token::SeqEnd,
+                Token::Str("next"),
+                Token::Some,
+                Token::Str("02-00-5E-10-00-02"),
+                Token::Str("allocated"),
+                Token::Seq {
+                    len: Some(1),
+                },
+                Token::Str("02-00-5E-10-00-01"),
+                Token::SeqEnd,
+                Token::Str("released"),
+                Token::Seq {
+                    len: Some(1),
+                },
+                Token::Str("02-00-5E-10-00-00"),
+                Token::SeqEnd,
+                Token::StructEnd,
+            ],
+        );
+        assert_eq!(pool.allocate(), Some(addr));
+    }
+
+    #[test]
+    fn test_pool_serde_inconsistent_state() {
+        assert_de_tokens_error::<Readable<MacAddrPool>>(
+            &[
+                Token::Struct {
+                    name: "MacAddrPool",
+                    len: 4,
+                },
+                Token::Str("blocks"),
+                Token::Seq {
+                    len: Some(1),
+                },
+                Token::Str("02:00:5E:10:00:00/46"),
+                Token::SeqEnd,
+                Token::Str("next"),
+                Token::None,
+                Token::Str("allocated"),
+                Token::Seq {
+                    len: Some(1),
+                },
+                Token::Str("FF-FF-FF-FF-FF-FF"),
+                Token::SeqEnd,
+                Token::Str("released"),
+                Token::Seq {
+                    len: Some(0),
+                },
+                Token::SeqEnd,
+                Token::StructEnd,
+            ],
+            "inconsistent MAC address pool state",
+        );
+    }
+}
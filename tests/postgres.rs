@@ -0,0 +1,47 @@
+#![cfg(feature = "postgres")]
+
+use bytes::BytesMut;
+use macaddr::{MacAddr, MacAddr6, MacAddr8};
+use postgres_types::{FromSql, IsNull, ToSql, Type};
+
+#[test]
+fn test_postgres_v6() {
+    let addr = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22);
+    assert!(<MacAddr6 as ToSql>::accepts(&Type::MACADDR));
+    assert!(!<MacAddr6 as ToSql>::accepts(&Type::MACADDR8));
+
+    let mut buf = BytesMut::new();
+    assert!(matches!(addr.to_sql_checked(&Type::MACADDR, &mut buf), Ok(IsNull::No)));
+    assert_eq!(&buf[..], [0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22]);
+    assert_eq!(MacAddr6::from_sql(&Type::MACADDR, &buf).unwrap(), addr);
+
+    assert!(addr.to_sql_checked(&Type::TEXT, &mut buf).is_err());
+    assert!(MacAddr6::from_sql(&Type::MACADDR, &buf[..5]).is_err());
+}
+
+#[test]
+fn test_postgres_v8() {
+    let addr = MacAddr8::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44);
+    assert!(<MacAddr8 as FromSql>::accepts(&Type::MACADDR8));
+
+    let mut buf = BytesMut::new();
+    assert!(matches!(addr.to_sql_checked(&Type::MACADDR8, &mut buf), Ok(IsNull::No)));
+    assert_eq!(&buf[..], [0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44]);
+    assert_eq!(MacAddr8::from_sql(&Type::MACADDR8, &buf).unwrap(), addr);
+}
+
+#[test]
+fn test_postgres() {
+    let mut buf = BytesMut::new();
+
+    let addr = MacAddr::from([0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22]);
+    assert!(addr.to_sql_checked(&Type::MACADDR, &mut buf).is_ok());
+    assert_eq!(MacAddr::from_sql(&Type::MACADDR, &buf).unwrap(), addr);
+    assert!(addr.to_sql_checked(&Type::MACADDR8, &mut buf).is_err());
+
+    let addr = MacAddr::from([0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44]);
+    buf.clear();
+    assert!(addr.to_sql_checked(&Type::MACADDR8, &mut buf).is_ok());
+    assert_eq!(MacAddr::from_sql(&Type::MACADDR8, &buf).unwrap(), addr);
+    assert!(addr.to_sql_checked(&Type::MACADDR, &mut buf).is_err());
+}
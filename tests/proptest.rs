@@ -0,0 +1,32 @@
+#![cfg(feature = "proptest")]
+
+use macaddr::proptest::{any_mac, locally_administered, multicast_mac6, unicast_mac6, universal_unicast_mac6};
+use macaddr::MacAddr;
+use proptest::prelude::*;
+
+proptest! {
+    #[test]
+    fn test_unicast_mac6(addr in unicast_mac6()) {
+        prop_assert!(addr.is_unicast());
+    }
+
+    #[test]
+    fn test_multicast_mac6(addr in multicast_mac6()) {
+        prop_assert!(addr.is_multicast());
+    }
+
+    #[test]
+    fn test_locally_administered(addr in locally_administered()) {
+        prop_assert!(addr.is_local());
+    }
+
+    #[test]
+    fn test_universal_unicast_mac6(addr in universal_unicast_mac6()) {
+        prop_assert!(addr.is_unicast() && addr.is_universal());
+    }
+
+    #[test]
+    fn test_any_mac_roundtrip(addr in any_mac()) {
+        prop_assert_eq!(addr.to_string().parse::<MacAddr>(), Ok(addr));
+    }
+}
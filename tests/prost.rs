@@ -0,0 +1,43 @@
+#![cfg(feature = "prost")]
+
+use std::convert::TryFrom;
+
+use bytes::Bytes;
+use macaddr::{MacAddr, MacAddr6, MacAddr8, ParseError};
+
+#[test]
+fn test_bytes_v6() {
+    let addr = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22);
+
+    let bytes = Bytes::from(addr);
+    assert_eq!(bytes, &[0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22][..]);
+    assert_eq!(MacAddr6::try_from(bytes), Ok(addr));
+}
+
+#[test]
+fn test_bytes_v8() {
+    let addr = MacAddr8::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44);
+
+    let bytes = Bytes::from(addr);
+    assert_eq!(bytes, &[0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44][..]);
+    assert_eq!(MacAddr8::try_from(bytes), Ok(addr));
+}
+
+#[test]
+fn test_bytes() {
+    let addr = MacAddr::from([0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22]);
+    assert_eq!(MacAddr::try_from(Bytes::from(addr)), Ok(addr));
+
+    let addr = MacAddr::from([0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44]);
+    assert_eq!(MacAddr::try_from(Bytes::from(addr)), Ok(addr));
+}
+
+#[test]
+fn test_bytes_invalid_length() {
+    let bytes = Bytes::from_static(&[0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33]);
+
+    assert_eq!(MacAddr6::try_from(bytes.clone()), Err(ParseError::InvalidLength(7)));
+    assert_eq!(MacAddr8::try_from(bytes.clone()), Err(ParseError::InvalidLength(7)));
+    assert_eq!(MacAddr::try_from(bytes), Err(ParseError::InvalidLength(7)));
+    assert_eq!(MacAddr::try_from(Bytes::new()), Err(ParseError::InvalidLength(0)));
+}
@@ -0,0 +1,24 @@
+#![cfg(feature = "quickcheck")]
+
+use macaddr::{MacAddr, MacAddr6, MacAddr8};
+use quickcheck::{Arbitrary, QuickCheck};
+
+#[test]
+fn test_quickcheck_roundtrip() {
+    fn prop(addr: MacAddr) -> bool {
+        addr.to_string().parse::<MacAddr>() == Ok(addr)
+    }
+
+    QuickCheck::new().quickcheck(prop as fn(MacAddr) -> bool);
+}
+
+#[test]
+fn test_quickcheck_shrink() {
+    let addr = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22);
+    assert!(addr.shrink().any(|addr| addr.is_nil()));
+    assert_eq!(MacAddr6::nil().shrink().count(), 0);
+
+    let addr = MacAddr::from(MacAddr8::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44));
+    assert!(addr.shrink().all(|addr| addr.is_v8()));
+    assert!(addr.shrink().any(|addr| addr == MacAddr::from(MacAddr8::nil())));
+}
@@ -0,0 +1,38 @@
+#![cfg(feature = "rand")]
+
+use macaddr::{MacAddr6, MacAddr8};
+use rand::{rngs::mock::StepRng, Rng};
+
+#[test]
+fn test_random() {
+    let mut rng = StepRng::new(0x2211_0048_DEAC, 0);
+    assert_eq!(MacAddr6::random(&mut rng), MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22));
+
+    let mut rng = StepRng::new(0x4433_2211_0048_DEAC, 0);
+    assert_eq!(
+        MacAddr8::random(&mut rng),
+        MacAddr8::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44)
+    );
+}
+
+#[test]
+fn test_random_local_unicast() {
+    let mut rng = StepRng::new(0x2211_0048_DEAC, 0);
+    assert_eq!(
+        MacAddr6::random_local_unicast(&mut rng),
+        MacAddr6::new(0xAE, 0xDE, 0x48, 0x00, 0x11, 0x22)
+    );
+
+    let mut rng = StepRng::new(u64::MAX, 0);
+    let addr = MacAddr8::random_local_unicast(&mut rng);
+    assert!(addr.is_local() && addr.is_unicast());
+    assert_eq!(addr, MacAddr8::new(0xFE, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF));
+}
+
+#[test]
+fn test_standard_distribution() {
+    let mut rng = StepRng::new(0x2211_0048_DEAC, 0);
+
+    let addr: MacAddr6 = rng.gen();
+    assert_eq!(addr, MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22));
+}
@@ -0,0 +1,112 @@
+#![cfg(feature = "std")]
+
+use macaddr::{
+    MacAddr6, MacAddr8, MacPrefix, Oui,
+    vendor::{Registry, VendorDatabase},
+};
+
+const CSV: &str = "\
+Registry,Assignment,Organization Name,Organization Address
+MA-L,00000C,\"Cisco Systems, Inc\",170 WEST TASMAN DRIVE SAN JOSE CA US 95134-1706 
+MA-L,70B3D5,IEEE Registration Authority,445 Hoes Lane Piscataway NJ US 08554 
+MA-S,70B3D5060,RCH SPA,Via Cendon 39 Silea  IT 31057 
+
+MA-M,0055DA2,\"Beijing Connected Information Technology Co.,Ltd.\",\"F15 Block 7 \"\"Jing Xi\"\" Beijing CN 100041 \"
+";
+
+const TXT: &str = "\
+OUI/MA-L                                                    Organization                                 
+company_id                                                  Organization                                 
+                                                            Address                                      
+
+00-00-0C   (hex)\t\tCisco Systems, Inc
+00000C     (base 16)\t\tCisco Systems, Inc
+\t\t\t\t170 WEST TASMAN DRIVE
+\t\t\t\tSAN JOSE CA 95134-1706
+\t\t\t\tUS
+
+70-B3-D5   (hex)\t\tRCH SPA
+060000-060FFF     (base 16)\t\tRCH SPA
+\t\t\t\tVia Cendon 39
+\t\t\t\tSilea    31057
+\t\t\t\tIT
+
+00-55-DA   (hex)\t\tBeijing Connected Information Technology Co.,Ltd.
+200000-2FFFFF     (base 16)\t\tBeijing Connected Information Technology Co.,Ltd.
+";
+
+fn assert_sample(registry: &Registry) {
+    let cisco = MacAddr6::new(0x00, 0x00, 0x0C, 0x12, 0x34, 0x56);
+    assert_eq!(registry.lookup(cisco.as_bytes()), Some("Cisco Systems, Inc"));
+    assert_eq!(
+        registry.lookup(MacAddr8::new(0x00, 0x00, 0x0C, 0x12, 0x34, 0x56, 0x78, 0x9A).as_bytes()),
+        Some("Cisco Systems, Inc")
+    );
+
+    let rch = MacAddr6::new(0x70, 0xB3, 0xD5, 0x06, 0x01, 0x23);
+    assert_eq!(registry.lookup(rch.as_bytes()), Some("RCH SPA"));
+
+    let beijing = MacAddr6::new(0x00, 0x55, 0xDA, 0x2F, 0xFF, 0xFF);
+    assert_eq!(
+        registry.lookup(beijing.as_bytes()),
+        Some("Beijing Connected Information Technology Co.,Ltd.")
+    );
+    assert_eq!(
+        registry.lookup(MacAddr6::new(0x00, 0x55, 0xDA, 0x30, 0x00, 0x00).as_bytes()),
+        None
+    );
+    assert_eq!(registry.lookup(Oui::from(beijing).as_bytes()), None);
+    assert_eq!(registry.lookup(MacAddr6::broadcast().as_bytes()), None);
+}
+
+#[test]
+fn test_registry_csv() {
+    let mut registry = Registry::new();
+    registry.read_csv(CSV.as_bytes()).unwrap();
+
+    assert_eq!(registry.len(), 4);
+    assert_sample(&registry);
+    assert_eq!(
+        registry.lookup(Oui::new(0x70, 0xB3, 0xD5).as_bytes()),
+        Some("IEEE Registration Authority")
+    );
+}
+
+#[test]
+fn test_registry_txt() {
+    let mut registry = Registry::new();
+    registry.read_txt(TXT.as_bytes()).unwrap();
+
+    assert_eq!(registry.len(), 3);
+    assert_sample(&registry);
+}
+
+#[test]
+fn test_registry_invalid() {
+    let mut registry = Registry::new();
+
+    assert!(registry.read_csv("MA-L,00000X,Broken\n".as_bytes()).is_err());
+    assert!(registry.read_csv("MA-L\n".as_bytes()).is_err());
+    assert!(
+        registry
+            .read_txt("060000-060FFF     (base 16)\t\tNo OUI\n".as_bytes())
+            .is_err()
+    );
+    assert!(
+        registry
+            .read_txt("70-B3-D5   (hex)\t\tX\n060000-060FFE     (base 16)\t\tX\n".as_bytes())
+            .is_err()
+    );
+    assert!(registry.is_empty());
+}
+
+#[test]
+fn test_registry_dyn() {
+    let registry: Registry = vec![(MacPrefix::new(MacAddr6::nil(), 0).unwrap(), "Everyone".to_string())]
+        .into_iter()
+        .collect();
+    let db: &dyn VendorDatabase = &registry;
+
+    assert_eq!(db.lookup(MacAddr6::broadcast().as_bytes()), Some("Everyone"));
+    assert_eq!(db.lookup(&[]), Some("Everyone"));
+}
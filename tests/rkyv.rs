@@ -0,0 +1,49 @@
+#![cfg(feature = "rkyv")]
+
+use macaddr::{ArchivedMacAddr, ArchivedMacAddr6, ArchivedMacAddr8, MacAddr, MacAddr6, MacAddr8};
+use rkyv::api::low::{access, deserialize, to_bytes_in_with_alloc};
+use rkyv::rancor::Failure;
+use rkyv::ser::{allocator::SubAllocator, writer::Buffer};
+use rkyv::util::Align;
+
+#[test]
+fn test_rkyv_v6_roundtrip() {
+    let addr = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22);
+
+    let mut buf = Align([0u8; 16]);
+    let bytes = to_bytes_in_with_alloc::<_, _, Failure>(&addr, Buffer::from(&mut *buf), SubAllocator::empty()).unwrap();
+    let archived = access::<ArchivedMacAddr6, Failure>(&bytes).unwrap();
+
+    assert_eq!(archived, &addr);
+    assert_eq!(deserialize::<MacAddr6, Failure>(archived).unwrap(), addr);
+}
+
+#[test]
+fn test_rkyv_v8_roundtrip() {
+    let addr = MacAddr8::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44);
+
+    let mut buf = Align([0u8; 16]);
+    let bytes = to_bytes_in_with_alloc::<_, _, Failure>(&addr, Buffer::from(&mut *buf), SubAllocator::empty()).unwrap();
+    let archived = access::<ArchivedMacAddr8, Failure>(&bytes).unwrap();
+
+    assert_eq!(archived, &addr);
+    assert_eq!(deserialize::<MacAddr8, Failure>(archived).unwrap(), addr);
+}
+
+#[test]
+fn test_rkyv_roundtrip() {
+    let addrs = [
+        MacAddr::from([0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22]),
+        MacAddr::from([0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44]),
+    ];
+
+    for addr in addrs {
+        let mut buf = Align([0u8; 16]);
+        let bytes =
+            to_bytes_in_with_alloc::<_, _, Failure>(&addr, Buffer::from(&mut *buf), SubAllocator::empty()).unwrap();
+        let archived = access::<ArchivedMacAddr, Failure>(&bytes).unwrap();
+
+        assert_eq!(archived, &addr);
+        assert_eq!(deserialize::<MacAddr, Failure>(archived).unwrap(), addr);
+    }
+}
@@ -0,0 +1,59 @@
+#![cfg(feature = "rusqlite")]
+
+use macaddr::{MacAddr, MacAddr6, MacAddr8};
+use rusqlite::{Connection, Error};
+
+fn connection() -> Connection {
+    let conn = Connection::open_in_memory().unwrap();
+    conn.execute("CREATE TABLE interfaces (addr)", ()).unwrap();
+
+    conn
+}
+
+#[test]
+fn test_rusqlite_blob() {
+    let conn = connection();
+    let v6 = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22);
+    let v8 = MacAddr8::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44);
+
+    conn.execute("INSERT INTO interfaces VALUES (?1), (?2)", (v6, MacAddr::from(v8))).unwrap();
+
+    let mut stmt = conn.prepare("SELECT addr, typeof(addr) FROM interfaces").unwrap();
+    let mut rows = stmt.query(()).unwrap();
+
+    let row = rows.next().unwrap().unwrap();
+    assert_eq!(row.get::<_, MacAddr6>(0).unwrap(), v6);
+    assert_eq!(row.get::<_, MacAddr>(0).unwrap(), MacAddr::from(v6));
+    assert_eq!(row.get::<_, String>(1).unwrap(), "blob");
+    assert!(matches!(row.get::<_, MacAddr8>(0), Err(Error::FromSqlConversionFailure(..))));
+
+    let row = rows.next().unwrap().unwrap();
+    assert_eq!(row.get::<_, MacAddr8>(0).unwrap(), v8);
+    assert_eq!(row.get::<_, MacAddr>(0).unwrap(), MacAddr::from(v8));
+}
+
+#[test]
+fn test_rusqlite_text() {
+    let conn = connection();
+    conn.execute("INSERT INTO interfaces VALUES ('ac:de:48:00:11:22'), ('invalid'), (42)", ()).unwrap();
+
+    let mut stmt = conn.prepare("SELECT addr FROM interfaces").unwrap();
+    let addrs = stmt.query_map((), |row| row.get::<_, MacAddr6>(0)).unwrap().collect::<Vec<_>>();
+
+    assert_eq!(addrs[0].as_ref().unwrap(), &MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22));
+    assert!(addrs[1].is_err());
+    assert!(matches!(addrs[2], Err(Error::InvalidColumnType(..))));
+}
+
+#[test]
+fn test_rusqlite_invalid_blob_size() {
+    let conn = connection();
+    conn.execute("INSERT INTO interfaces VALUES (x'ACDE48')", ()).unwrap();
+
+    let err = conn.query_row("SELECT addr FROM interfaces", (), |row| row.get::<_, MacAddr6>(0)).unwrap_err();
+    assert!(matches!(err, Error::FromSqlConversionFailure(..)));
+    assert!(err.to_string().ends_with("Cannot read 6 byte value out of 3 byte blob"));
+
+    let err = conn.query_row("SELECT addr FROM interfaces", (), |row| row.get::<_, MacAddr>(0)).unwrap_err();
+    assert!(err.to_string().ends_with("Cannot read 6 byte value out of 3 byte blob"));
+}
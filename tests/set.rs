@@ -0,0 +1,66 @@
+#![cfg(feature = "alloc")]
+
+use macaddr::{MacAddr6, MacPrefix, MacSet};
+
+#[test]
+fn test_set_membership() {
+    let block: MacPrefix = "AC:DE:48:00:00:00/36".parse().unwrap();
+    let mut set = MacSet::new(block);
+    assert!(set.is_empty());
+
+    assert!(set.insert(block.first()));
+    assert!(set.insert(block.last()));
+    assert!(!set.insert(MacAddr6::broadcast()));
+    assert!(set.contains(&block.last()));
+    assert!(!set.contains(&MacAddr6::broadcast()));
+    assert_eq!(set.len(), 2);
+
+    assert!(set.remove(&block.first()));
+    assert!(!set.remove(&block.first()));
+    assert_eq!(set.iter().collect::<Vec<_>>(), [block.last()]);
+
+    set.clear();
+    assert!(set.is_empty());
+}
+
+#[test]
+fn test_set_iter_order() {
+    let block: MacPrefix = "AC:DE:48:00:00:00/40".parse().unwrap();
+    let mut set = MacSet::new(block);
+    let addrs = [0xFF, 0x00, 0x40, 0x3F, 0x41];
+    set.extend(addrs.iter().map(|offset| block.first().offset(*offset).unwrap()));
+
+    let offsets = set
+        .iter()
+        .map(|addr| addr.offset_from(&block.first()))
+        .collect::<Vec<_>>();
+    assert_eq!(offsets, [0x00, 0x3F, 0x40, 0x41, 0xFF]);
+}
+
+#[test]
+fn test_set_smallest_block() {
+    let addr = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22);
+    let mut set = MacSet::new(MacPrefix::new(addr, 48).unwrap());
+
+    assert!(set.insert(addr));
+    assert!(!set.insert(MacAddr6::nil()));
+    assert_eq!(set.iter().collect::<Vec<_>>(), [addr]);
+}
+
+#[test]
+fn test_set_intersect_different_blocks() {
+    let mut left = MacSet::new("AC:DE:48:00:00:00/32".parse().unwrap());
+    let mut right = MacSet::new("AC:DE:48:00:11:00/40".parse().unwrap());
+
+    let inside = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22);
+    let outside = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x22, 0x11);
+    left.extend([inside, outside].iter().copied());
+    right.insert(inside);
+
+    let mut union = right.clone();
+    union.union_with(&left);
+    assert_eq!(union, right);
+
+    left.intersect_with(&right);
+    assert_eq!(left.iter().collect::<Vec<_>>(), [inside]);
+}
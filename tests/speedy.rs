@@ -0,0 +1,56 @@
+#![cfg(feature = "speedy")]
+
+use macaddr::{MacAddr, MacAddr6, MacAddr8};
+use speedy::{LittleEndian, Readable, Writable};
+
+#[test]
+fn test_speedy_v6() {
+    let addr = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22);
+
+    let bytes = addr.write_to_vec_with_ctx(LittleEndian::default()).unwrap();
+    assert_eq!(bytes, [0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22]);
+    assert_eq!(
+        MacAddr6::read_from_buffer_with_ctx(LittleEndian::default(), &bytes).unwrap(),
+        addr
+    );
+}
+
+#[test]
+fn test_speedy_v8() {
+    let addr = MacAddr8::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44);
+
+    let bytes = addr.write_to_vec_with_ctx(LittleEndian::default()).unwrap();
+    assert_eq!(bytes, [0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44]);
+    assert_eq!(
+        MacAddr8::read_from_buffer_with_ctx(LittleEndian::default(), &bytes).unwrap(),
+        addr
+    );
+}
+
+#[test]
+fn test_speedy() {
+    let addr = MacAddr::from([0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22]);
+    let bytes = addr.write_to_vec_with_ctx(LittleEndian::default()).unwrap();
+    assert_eq!(bytes, [0x00, 0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22]);
+    assert_eq!(
+        MacAddr::read_from_buffer_with_ctx(LittleEndian::default(), &bytes).unwrap(),
+        addr
+    );
+
+    let addr = MacAddr::from([0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44]);
+    let bytes = addr.write_to_vec_with_ctx(LittleEndian::default()).unwrap();
+    assert_eq!(bytes, [0x01, 0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44]);
+    assert_eq!(
+        MacAddr::read_from_buffer_with_ctx(LittleEndian::default(), &bytes).unwrap(),
+        addr
+    );
+}
+
+#[test]
+fn test_speedy_invalid() {
+    assert!(MacAddr6::read_from_buffer_with_ctx(LittleEndian::default(), &[0xAC, 0xDE, 0x48, 0x00, 0x11]).is_err());
+    assert!(
+        MacAddr::read_from_buffer_with_ctx(LittleEndian::default(), &[0x02, 0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22])
+            .is_err()
+    );
+}
@@ -0,0 +1,52 @@
+#![cfg(any(feature = "sqlx-postgres", feature = "sqlx-mysql", feature = "sqlx-sqlite"))]
+
+use macaddr::{MacAddr, MacAddr6, MacAddr8};
+use sqlx::{Encode, Type};
+
+#[cfg(feature = "sqlx-postgres")]
+#[test]
+fn test_sqlx_postgres() {
+    use sqlx::postgres::{PgArgumentBuffer, PgHasArrayType, Postgres};
+
+    assert_eq!(
+        <MacAddr6 as Type<Postgres>>::type_info(),
+        <MacAddr as Encode<Postgres>>::produces(&MacAddr::from(MacAddr6::nil())).unwrap()
+    );
+    assert!(<MacAddr as Type<Postgres>>::compatible(&<MacAddr6 as Type<Postgres>>::type_info()));
+    assert!(<MacAddr as Type<Postgres>>::compatible(&<MacAddr8 as Type<Postgres>>::type_info()));
+    assert!(!<MacAddr6 as Type<Postgres>>::compatible(&<String as Type<Postgres>>::type_info()));
+    assert_ne!(<MacAddr6 as PgHasArrayType>::array_type_info(), <MacAddr8 as PgHasArrayType>::array_type_info());
+
+    let mut buf = PgArgumentBuffer::default();
+    let addr = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22);
+    assert!(<MacAddr6 as Encode<Postgres>>::encode_by_ref(&addr, &mut buf).is_ok());
+    assert_eq!(&buf[..], [0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22]);
+}
+
+#[cfg(feature = "sqlx-mysql")]
+#[test]
+fn test_sqlx_mysql() {
+    use sqlx::MySql;
+
+    assert!(<MacAddr6 as Type<MySql>>::compatible(&<String as Type<MySql>>::type_info()));
+    assert!(<MacAddr as Type<MySql>>::compatible(&<Vec<u8> as Type<MySql>>::type_info()));
+
+    let mut buf = Vec::new();
+    let addr = MacAddr8::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44);
+    assert!(<MacAddr8 as Encode<MySql>>::encode_by_ref(&addr, &mut buf).is_ok());
+    assert_eq!(&buf[1..], b"AC:DE:48:00:11:22:33:44");
+}
+
+#[cfg(feature = "sqlx-sqlite")]
+#[test]
+fn test_sqlx_sqlite() {
+    use sqlx::sqlite::{Sqlite, SqliteArgumentValue};
+
+    assert!(<MacAddr6 as Type<Sqlite>>::compatible(&<String as Type<Sqlite>>::type_info()));
+    assert!(<MacAddr8 as Type<Sqlite>>::compatible(&<Vec<u8> as Type<Sqlite>>::type_info()));
+
+    let mut buf = Vec::new();
+    let addr = MacAddr::from([0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22]);
+    assert!(<MacAddr as Encode<Sqlite>>::encode_by_ref(&addr, &mut buf).is_ok());
+    assert!(matches!(&buf[..], [SqliteArgumentValue::Text(text)] if text.as_ref() == "AC:DE:48:00:11:22"));
+}
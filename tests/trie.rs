@@ -0,0 +1,49 @@
+#![cfg(feature = "alloc")]
+
+use macaddr::{MacAddr6, MacPrefix, MacTrie};
+
+#[test]
+fn test_trie_longest_match() {
+    let mut trie = MacTrie::new();
+    assert_eq!(trie.insert("00:00:00:00:00:00/0".parse().unwrap(), 0), None);
+    assert_eq!(trie.insert("AC:DE:48:00:00:00/24".parse().unwrap(), 24), None);
+    assert_eq!(trie.insert("AC:DE:48:00:11:22/48".parse().unwrap(), 48), None);
+    assert_eq!(trie.insert("AC:DE:48:00:00:00/24".parse().unwrap(), 25), Some(24));
+    assert_eq!(trie.len(), 3);
+
+    let exact = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22);
+    assert_eq!(trie.longest_match(&exact), Some(("AC:DE:48:00:11:22/48".parse().unwrap(), &48)));
+
+    let vendor = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x23);
+    assert_eq!(trie.longest_match(&vendor), Some(("AC:DE:48:00:00:00/24".parse().unwrap(), &25)));
+    assert_eq!(
+        trie.longest_match(&MacAddr6::broadcast()),
+        Some(("00:00:00:00:00:00/0".parse().unwrap(), &0))
+    );
+}
+
+#[test]
+fn test_trie_remove() {
+    let mut trie = [
+        ("AC:DE:48:00:00:00/24", 1),
+        ("AC:DE:48:00:00:00/32", 2),
+        ("AC:DE:48:80:00:00/25", 3),
+    ]
+    .iter()
+    .map(|(s, value)| (s.parse::<MacPrefix>().unwrap(), *value))
+    .collect::<MacTrie<_>>();
+
+    assert_eq!(trie.remove(&"AC:DE:48:00:00:00/32".parse().unwrap()), Some(2));
+    assert_eq!(trie.remove(&"AC:DE:48:00:00:00/32".parse().unwrap()), None);
+    assert_eq!(trie.get(&"AC:DE:48:00:00:00/24".parse().unwrap()), Some(&1));
+    *trie.get_mut(&"AC:DE:48:80:00:00/25".parse().unwrap()).unwrap() += 1;
+
+    let addr = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x00, 0x01);
+    assert_eq!(trie.longest_match(&addr).map(|(_, value)| *value), Some(1));
+    assert_eq!(trie.iter().len(), 2);
+    assert_eq!(trie.iter().map(|(_, value)| *value).collect::<Vec<_>>(), [1, 4]);
+
+    trie.clear();
+    assert!(trie.is_empty());
+    assert_eq!(trie.longest_match(&addr), None);
+}
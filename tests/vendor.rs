@@ -0,0 +1,61 @@
+#![cfg(feature = "oui-db")]
+
+use macaddr::{AssignmentBlock, MacAddr6, MacAddr8, Oui, vendor};
+
+#[test]
+fn test_vendor_ma_l() {
+    let addr = MacAddr6::new(0x00, 0x00, 0x0C, 0x12, 0x34, 0x56);
+
+    assert_eq!(vendor::lookup(&addr), Some("Cisco Systems, Inc"));
+    assert_eq!(
+        vendor::lookup(&MacAddr8::new(0x00, 0x00, 0x0C, 0x12, 0x34, 0x56, 0x78, 0x9A)),
+        Some("Cisco Systems, Inc")
+    );
+    assert_eq!(Oui::from(addr).vendor(), Some("Cisco Systems, Inc"));
+}
+
+#[test]
+fn test_vendor_longest_prefix() {
+    // MA-M block of the IEEE Registration Authority OUI.
+    let addr = MacAddr6::new(0x00, 0x55, 0xDA, 0x20, 0x00, 0x01);
+    assert_eq!(
+        vendor::lookup(&addr),
+        Some("Beijing Connected Information Technology Co.,Ltd.")
+    );
+    assert_eq!(Oui::from(addr).vendor(), Some("IEEE Registration Authority"));
+
+    // IAB block.
+    let addr = MacAddr6::new(0x00, 0x50, 0xC2, 0x00, 0x10, 0x00);
+    assert_eq!(vendor::lookup(&addr), Some("JMBS Developpements"));
+}
+
+#[test]
+fn test_vendor_assignment_block() {
+    assert_eq!(
+        MacAddr6::new(0x00, 0x55, 0xDA, 0x20, 0x00, 0x01).assignment_block(),
+        Some(AssignmentBlock::MaM)
+    );
+    assert_eq!(
+        MacAddr6::new(0x70, 0xB3, 0xD5, 0x06, 0x01, 0x23).assignment_block(),
+        Some(AssignmentBlock::MaS)
+    );
+    assert_eq!(
+        MacAddr6::new(0x00, 0x00, 0x0C, 0x12, 0x34, 0x56).assignment_block(),
+        Some(AssignmentBlock::MaL)
+    );
+    assert_eq!(MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22).assignment_block(), Some(AssignmentBlock::MaL));
+}
+
+#[test]
+fn test_vendor_cid() {
+    let addr = MacAddr6::new(0x0A, 0xE9, 0x1B, 0x00, 0x00, 0x01);
+
+    assert_eq!(vendor::lookup(&addr), Some("PADL Software Pty Ltd"));
+}
+
+#[test]
+fn test_vendor_unknown() {
+    assert_eq!(vendor::lookup(&MacAddr6::broadcast()), None);
+    assert_eq!(vendor::lookup(&[0x00u8, 0x00]), None);
+    assert_eq!(vendor::lookup(&[0u8; 0]), None);
+}
@@ -0,0 +1,39 @@
+use macaddr::{MacAddr6, wifi};
+
+#[test]
+#[allow(deprecated)]
+fn test_wifi_derive_per_ssid_siphash24() {
+    use std::hash::{Hasher, SipHasher};
+
+    let secret = [
+        0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F,
+    ];
+    for ssid in [&b""[..], b"Airport", b"CoffeeShop", b"guest-network-5ghz", &[0xFF; 32]].iter() {
+        let mut hasher = SipHasher::new_with_keys(0x0706_0504_0302_0100, 0x0F0E_0D0C_0B0A_0908);
+        hasher.write(ssid);
+        let [a, b, c, d, e, f, _, _] = hasher.finish().to_be_bytes();
+
+        let addr = wifi::derive_per_ssid(&secret, ssid);
+        assert_eq!(addr, MacAddr6::new(a & !1 | 1 << 1, b, c, d, e, f));
+        assert!(wifi::is_randomized(&addr));
+    }
+}
+
+#[test]
+fn test_wifi_derive_per_ssid_const() {
+    const ADDR: MacAddr6 = wifi::derive_per_ssid(&[0; 16], b"CoffeeShop");
+
+    assert_eq!(ADDR, MacAddr6::new(0xB2, 0xB5, 0x55, 0x1A, 0x3D, 0x12));
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn test_wifi_randomize_preserving_oui() {
+    let mut rng = rand::rngs::mock::StepRng::new(0x0102_0304_0506_0708, 1);
+    let addr = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22);
+
+    let randomized = addr.randomize_preserving_oui(&mut rng);
+    assert_eq!(randomized.oui(), addr.oui());
+    assert_ne!(randomized, addr);
+    assert!(!wifi::is_randomized(&randomized));
+}
@@ -0,0 +1,42 @@
+use macaddr::{MacAddr6, wol::MagicPacket};
+
+#[test]
+fn test_wol_payload_layout() {
+    let addr = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22);
+    let packet = MagicPacket::from(addr);
+
+    let bytes = packet.as_bytes();
+    assert_eq!(bytes.len(), 102);
+    assert!(bytes[..6].iter().all(|byte| *byte == 0xFF));
+    assert!(bytes[6..].chunks(6).all(|chunk| chunk == addr.as_bytes()));
+    assert_eq!(packet.target(), addr);
+    assert_eq!(packet.password(), None);
+}
+
+#[test]
+fn test_wol_password() {
+    let addr = MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22);
+    let packet = MagicPacket::new(addr).with_password(MacAddr6::nil());
+
+    assert_eq!(packet.as_ref().len(), 108);
+    assert_eq!(&packet.as_bytes()[..102], MagicPacket::new(addr).as_bytes());
+    assert_eq!(&packet.as_bytes()[102..], &[0; 6]);
+    assert_eq!(packet.target(), addr);
+    assert_eq!(packet.password(), Some(MacAddr6::nil()));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_wol_send_to() {
+    use std::net::{Ipv4Addr, UdpSocket};
+
+    let receiver = UdpSocket::bind((Ipv4Addr::LOCALHOST, 0)).unwrap();
+    let sender = UdpSocket::bind((Ipv4Addr::LOCALHOST, 0)).unwrap();
+    let packet = MagicPacket::new(MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22));
+
+    assert_eq!(packet.send_to(&sender, receiver.local_addr().unwrap()).unwrap(), 102);
+
+    let mut buf = [0; 128];
+    let len = receiver.recv(&mut buf).unwrap();
+    assert_eq!(&buf[..len], packet.as_bytes());
+}
@@ -0,0 +1,37 @@
+#![cfg(feature = "zerocopy")]
+
+use macaddr::{MacAddr6, MacAddr8};
+use zerocopy::{FromBytes, Immutable, IntoBytes, KnownLayout, Unaligned};
+
+#[derive(FromBytes, IntoBytes, KnownLayout, Immutable, Unaligned)]
+#[repr(C)]
+struct EthernetHeader {
+    destination: MacAddr6,
+    source: MacAddr6,
+    ether_type: [u8; 2],
+}
+
+#[test]
+fn test_zerocopy_header() {
+    let frame = [
+        0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x08, 0x06, 0x00, 0x01,
+    ];
+
+    let (header, payload) = EthernetHeader::ref_from_prefix(&frame[..]).unwrap();
+    assert_eq!(header.destination, MacAddr6::broadcast());
+    assert_eq!(header.source, MacAddr6::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22));
+    assert_eq!(header.ether_type, [0x08, 0x06]);
+    assert_eq!(payload, [0x00, 0x01]);
+    assert_eq!(header.as_bytes(), &frame[..14]);
+}
+
+#[test]
+fn test_zerocopy_unaligned() {
+    let bytes = [0x00, 0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44];
+
+    let addr = MacAddr8::ref_from_bytes(&bytes[1..]).unwrap();
+    assert_eq!(addr, &MacAddr8::new(0xAC, 0xDE, 0x48, 0x00, 0x11, 0x22, 0x33, 0x44));
+    assert_eq!(addr.as_bytes(), &bytes[1..]);
+
+    assert!(MacAddr6::ref_from_bytes(&bytes[..]).is_err());
+}